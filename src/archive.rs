@@ -0,0 +1,97 @@
+// Zero-copy archives of FSUID's without depending on rkyv: FSUID's are stored as contiguous little-endian i64 values (the layout rkyv archives i64 with),
+// so snapshots holding millions of FSUID's can be memory-mapped and read in place without deserialization
+
+use std::cmp::Ordering;
+
+use crate::{FsdkUid, FsdkUidError};
+
+const FSDK_FSUID_ARCHIVE_ITEM_LENGTH: usize = 8; // Number of bytes of each archived FSUID
+
+pub fn fsdkuid_archive(fsuids: &[FsdkUid]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(fsuids.len() * FSDK_FSUID_ARCHIVE_ITEM_LENGTH);
+
+    for fsuid in fsuids {
+        bytes.extend_from_slice(&fsuid.to_le_bytes());
+    }
+
+    bytes
+}
+
+// Borrowed view of archived FSUID's, reading each FSUID from the bytes when accessed
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FsdkUidArchive<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> FsdkUidArchive<'a> {
+    // Only validates the length, every 8 bytes are a valid FSUID
+    pub fn new(bytes: &'a [u8]) -> Result<Self, FsdkUidError> {
+        if !bytes.len().is_multiple_of(FSDK_FSUID_ARCHIVE_ITEM_LENGTH) {
+            return Err(FsdkUidError::InvalidLength);
+        }
+
+        Ok(FsdkUidArchive { bytes })
+    }
+
+    pub fn len(&self) -> usize {
+        self.bytes.len() / FSDK_FSUID_ARCHIVE_ITEM_LENGTH
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> Option<FsdkUid> {
+        let start = index.checked_mul(FSDK_FSUID_ARCHIVE_ITEM_LENGTH)?;
+        let bytes = self.bytes.get(start..start + FSDK_FSUID_ARCHIVE_ITEM_LENGTH)?;
+
+        Some(FsdkUid::from_le_bytes(bytes.try_into().unwrap()))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = FsdkUid> + 'a {
+        self.bytes.chunks_exact(FSDK_FSUID_ARCHIVE_ITEM_LENGTH).map(|bytes| FsdkUid::from_le_bytes(bytes.try_into().unwrap()))
+    }
+
+    // Same contract of slice::binary_search(), the archive must be sorted
+    pub fn binary_search(&self, fsuid: &FsdkUid) -> Result<usize, usize> {
+        let (mut low, mut high) = (0, self.len());
+
+        while low < high {
+            let middle = low + (high - low) / 2;
+
+            match self.get(middle).unwrap().cmp(fsuid) {
+                Ordering::Less => low = middle + 1,
+                Ordering::Greater => high = middle,
+                Ordering::Equal => return Ok(middle),
+            }
+        }
+
+        Err(low)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fsdkuid_archive() {
+        let fsuids: Vec<FsdkUid> = (0..1000).map(|index| FsdkUid::from_parts(1726257270642 + index, 1, 0)).collect();
+        let bytes = fsdkuid_archive(&fsuids);
+        let archive = FsdkUidArchive::new(&bytes).unwrap();
+
+        assert_eq!(bytes.len(), 8000);
+        assert_eq!(&bytes[..8], 113131996488794368i64.to_le_bytes(), "[fsdkuid_archive() Error] FSUID's must be archived as little-endian i64");
+        assert_eq!((archive.len(), archive.is_empty()), (1000, false));
+        assert_eq!(archive.get(999), Some(fsuids[999]));
+        assert_eq!(archive.get(1000), None);
+        assert_eq!(archive.iter().collect::<Vec<FsdkUid>>(), fsuids);
+
+        assert_eq!(archive.binary_search(&fsuids[421]), Ok(421), "[FsdkUidArchive.binary_search() Error] Archived FSUID must be found in place");
+        assert_eq!(archive.binary_search(&FsdkUid::new(0)), Err(0));
+        assert_eq!(archive.binary_search(&FsdkUid::new(i64::MAX)), Err(1000));
+
+        assert_eq!(FsdkUidArchive::new(&bytes[..7]), Err(FsdkUidError::InvalidLength));
+        assert!(FsdkUidArchive::new(&[]).unwrap().is_empty());
+    }
+}
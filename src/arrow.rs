@@ -0,0 +1,70 @@
+// Apache Arrow / Parquet interoperability without depending on the arrow crates: FSUID columns are Int64Array values tagged with the fsdk.fsuid extension type
+// ex: Int64Array::from(fsdkuid_to_i64_column(&fsuids)) and Field::new("id", DataType::Int64, false).with_metadata(fsdkuid_arrow_extension_metadata())
+
+use std::collections::HashMap;
+
+use crate::{FsdkUid, FsdkUidLayout};
+
+pub const FSDK_FSUID_ARROW_EXTENSION_NAME: &str = "fsdk.fsuid"; // Arrow extension type name of FSUID columns, stored in the field metadata and kept by Parquet writers
+pub const FSDK_FSUID_ARROW_EXTENSION_NAME_KEY: &str = "ARROW:extension:name"; // Field metadata key of the Arrow extension type name
+pub const FSDK_FSUID_ARROW_EXTENSION_METADATA_KEY: &str = "ARROW:extension:metadata"; // Field metadata key of the Arrow extension type metadata, the layout descriptor of the FSUID's
+
+pub fn fsdkuid_to_i64_column(fsuids: &[FsdkUid]) -> Vec<i64> {
+    fsuids.iter().map(FsdkUid::i64).collect()
+}
+
+// Accepts the values of an Int64Array (Int64Array.values()), null slots hold arbitrary values and must be filtered with the array validity
+pub fn fsdkuid_from_i64_column(values: &[i64]) -> Vec<FsdkUid> {
+    values.iter().map(|&value| FsdkUid::new(value)).collect()
+}
+
+// Field metadata of FSUID columns generated with the default layout
+pub fn fsdkuid_arrow_extension_metadata() -> HashMap<String, String> {
+    fsdkuid_arrow_extension_metadata_with_layout(&FsdkUidLayout::FSUID)
+}
+
+pub fn fsdkuid_arrow_extension_metadata_with_layout(layout: &FsdkUidLayout) -> HashMap<String, String> {
+    HashMap::from([
+        (FSDK_FSUID_ARROW_EXTENSION_NAME_KEY.to_string(), FSDK_FSUID_ARROW_EXTENSION_NAME.to_string()),
+        (FSDK_FSUID_ARROW_EXTENSION_METADATA_KEY.to_string(), layout.to_descriptor_json()),
+    ])
+}
+
+// Whether the field metadata (Field.metadata()) tags an FSUID column of the layout, fields without extension metadata are trusted to use the default layout
+pub fn fsdkuid_is_arrow_extension(metadata: &HashMap<String, String>, layout: &FsdkUidLayout) -> bool {
+    metadata.get(FSDK_FSUID_ARROW_EXTENSION_NAME_KEY).map(String::as_str) == Some(FSDK_FSUID_ARROW_EXTENSION_NAME)
+        && match metadata.get(FSDK_FSUID_ARROW_EXTENSION_METADATA_KEY) {
+            Some(descriptor) => *descriptor == layout.to_descriptor_json(),
+            None => *layout == FsdkUidLayout::FSUID,
+        }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fsdkuid_arrow_column() {
+        let fsuids = [FsdkUid::new(113131996488794368), FsdkUid::new(-1), FsdkUid::new(0)];
+        let values = fsdkuid_to_i64_column(&fsuids);
+
+        assert_eq!(values, [113131996488794368, -1, 0], "[fsdkuid_to_i64_column() Error] FSUID's must be stored as their i64 value");
+        assert_eq!(fsdkuid_from_i64_column(&values), fsuids);
+    }
+
+    #[test]
+    fn test_fsdkuid_arrow_extension_metadata() {
+        let metadata = fsdkuid_arrow_extension_metadata();
+
+        assert_eq!(metadata[FSDK_FSUID_ARROW_EXTENSION_NAME_KEY], "fsdk.fsuid");
+        assert_eq!(metadata[FSDK_FSUID_ARROW_EXTENSION_METADATA_KEY], FsdkUidLayout::FSUID.to_descriptor_json());
+        assert!(fsdkuid_is_arrow_extension(&metadata, &FsdkUidLayout::FSUID));
+        assert!(!fsdkuid_is_arrow_extension(&metadata, &FsdkUidLayout::JS_SAFE), "[fsdkuid_is_arrow_extension() Error] Columns of another layout must not match");
+        assert!(fsdkuid_is_arrow_extension(&fsdkuid_arrow_extension_metadata_with_layout(&FsdkUidLayout::JS_SAFE), &FsdkUidLayout::JS_SAFE));
+
+        let name_only = HashMap::from([(FSDK_FSUID_ARROW_EXTENSION_NAME_KEY.to_string(), FSDK_FSUID_ARROW_EXTENSION_NAME.to_string())]);
+        assert!(fsdkuid_is_arrow_extension(&name_only, &FsdkUidLayout::FSUID));
+        assert!(!fsdkuid_is_arrow_extension(&name_only, &FsdkUidLayout::SHORT_CODE));
+        assert!(!fsdkuid_is_arrow_extension(&HashMap::new(), &FsdkUidLayout::FSUID), "[fsdkuid_is_arrow_extension() Error] Untagged Int64 columns must not match");
+    }
+}
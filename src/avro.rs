@@ -0,0 +1,44 @@
+// Apache Avro interoperability without depending on apache-avro: FSUID's are Avro longs annotated with the fsdk-fsuid logical type.
+// Readers not knowing the logical type fall back to the plain long, as required by the Avro specification
+
+use crate::{FsdkUid, FsdkUidError, FsdkUidVarintMode, FSDK_FSUID_VARINT_MAX_LENGTH};
+
+pub const FSDK_FSUID_AVRO_LOGICAL_TYPE: &str = "fsdk-fsuid"; // Avro logical type name of FSUID's, registered on apache-avro schemas with the schema below
+pub const FSDK_FSUID_AVRO_SCHEMA: &str = "{\"type\":\"long\",\"logicalType\":\"fsdk-fsuid\"}"; // Avro schema of a FSUID field type
+
+impl FsdkUid {
+    // Avro binary encoding of longs: zig-zag varint, so the bytes can be written directly in an Avro record
+    pub fn encode_avro<'a>(&self, buffer: &'a mut [u8; FSDK_FSUID_VARINT_MAX_LENGTH]) -> &'a [u8] {
+        self.encode_varint(FsdkUidVarintMode::ZigZag, buffer)
+    }
+
+    // Returns the number of bytes read so the following fields of the record can be decoded
+    pub fn decode_avro(bytes: &[u8]) -> Result<(FsdkUid, usize), FsdkUidError> {
+        FsdkUid::decode_varint(bytes, FsdkUidVarintMode::ZigZag)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fsdkuid_avro() {
+        let mut buffer = [0u8; FSDK_FSUID_VARINT_MAX_LENGTH];
+
+        assert_eq!(FsdkUid::new(1).encode_avro(&mut buffer), [0x02], "[fsuid.encode_avro() Error] FSUID's must be Avro zig-zag longs");
+        assert_eq!(FsdkUid::new(-1).encode_avro(&mut buffer), [0x01]);
+        assert_eq!(FsdkUid::new(-65).encode_avro(&mut buffer), [0x81, 0x01]);
+
+        for fsuid in [FsdkUid::new(113131996488794368), FsdkUid::new(i64::MIN), FsdkUid::new(i64::MAX)] {
+            let mut record = fsuid.encode_avro(&mut buffer).to_vec();
+            let length = record.len();
+            record.push(0x2a);
+
+            assert_eq!(FsdkUid::decode_avro(&record), Ok((fsuid, length)), "[FsdkUid::decode_avro() Error] FSUID {} didn't round-trip", fsuid.i64());
+        }
+
+        assert!(FsdkUid::decode_avro(&[0x80]).is_err());
+        assert!(FSDK_FSUID_AVRO_SCHEMA.contains(FSDK_FSUID_AVRO_LOGICAL_TYPE));
+    }
+}
@@ -0,0 +1,550 @@
+// Command line tool generating and reading FSUID's for operators and scripts, built with the cli feature (cargo install fsdk_uid --features cli)
+
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::env;
+use std::fmt;
+use std::io::{self, BufRead, BufWriter, Write};
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+use std::str::FromStr;
+
+use chrono::{DateTime, SecondsFormat, Utc};
+
+use fsdk_uid::{FsdkUid, FsdkUidEra, FsdkUidEraConfidence, FsdkUidEraDetector, FsdkUidGenerator, FsdkUidLayout, FsdkUidScheme, NodeIdSource};
+
+const FSDK_FSUID_CLI_REPORT_INTERVAL: Duration = Duration::from_secs(1); // Time between two throughput reports of bulk
+const FSDK_FSUID_CLI_REPORT_CHECK_ROWS: u64 = 4096; // Rows written by bulk between two checks of the report interval
+
+const FSDK_FSUID_CLI_USAGE: &str = "Usage:
+    fsuid generate [--node <0-255>] [--count <count>] [--format <format>]
+    fsuid inspect <fsuid>
+    fsuid bulk [--node <0-255>] [--count <count>] [--format <csv|jsonl>] [--encoding <encoding>]
+    fsuid audit < fsuids.txt
+    fsuid decode [--era <era>]... < identifiers.txt
+
+Options:
+    --node      FSUID Node Identifier, read from the FSDK_NODE_ID environment variable when omitted
+    --count     Number of FSUID's to generate, one per line, with an optional K, M or G suffix (default: 1)
+    --format    decimal (default), hex, base62, base58, base32, base64url, sortable or uri
+                Rows of bulk are written as csv (with a header) or jsonl (default)
+    --encoding  Format of the FSUID's written by bulk, same values as --format of generate
+    --era       Era of decode, repeatable: <label>,<scheme>[,<from>[,<until>[,<min>[,<max>]]]] where scheme is fsuid, sequential or
+                snowflake/<epoch milliseconds>/<timestamp shift> and from/until are RFC 3339 cutover dates (default: fsuid)
+
+FSUID's to inspect may be written in decimal, hexadecimal (0x prefix), Base62, prefixed (<prefix>_<base62>) or URI format,
+audit reads them from stdin (one per line) and exits with status 1 when duplicates, out of order FSUID's or counter gaps are found.
+decode labels identifiers read from stdin with their era, scheme, confidence and embedded datetime, tab separated";
+
+enum FsdkUidCliError {
+    Usage(String), // Invalid command line, reported with the usage
+    Invalid(String), // Invalid input (ex: a FSUID that can't be parsed)
+    Io(io::Error),
+}
+
+impl fmt::Display for FsdkUidCliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FsdkUidCliError::Usage(message) | FsdkUidCliError::Invalid(message) => write!(f, "{}", message),
+            FsdkUidCliError::Io(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl From<io::Error> for FsdkUidCliError {
+    fn from(error: io::Error) -> Self {
+        FsdkUidCliError::Io(error)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum FsdkUidCliFormat {
+    Decimal,
+    Hex,
+    Base62,
+    Base58,
+    Base32,
+    Base64Url,
+    Sortable,
+    Uri,
+}
+
+impl FsdkUidCliFormat {
+    fn parse(name: &str) -> Result<Self, FsdkUidCliError> {
+        match name {
+            "decimal" => Ok(FsdkUidCliFormat::Decimal),
+            "hex" => Ok(FsdkUidCliFormat::Hex),
+            "base62" => Ok(FsdkUidCliFormat::Base62),
+            "base58" => Ok(FsdkUidCliFormat::Base58),
+            "base32" => Ok(FsdkUidCliFormat::Base32),
+            "base64url" => Ok(FsdkUidCliFormat::Base64Url),
+            "sortable" => Ok(FsdkUidCliFormat::Sortable),
+            "uri" => Ok(FsdkUidCliFormat::Uri),
+            _ => Err(FsdkUidCliError::Usage(format!("Unknown format {:?}", name))),
+        }
+    }
+
+    // Hexadecimal is prefixed by 0x so every format is read back by FsdkUid::from_str()
+    fn encode(&self, fsuid: FsdkUid) -> String {
+        match self {
+            FsdkUidCliFormat::Decimal => fsuid.i64().to_string(),
+            FsdkUidCliFormat::Hex => format!("0x{:016x}", fsuid),
+            FsdkUidCliFormat::Base62 => fsuid.to_base62(),
+            FsdkUidCliFormat::Base58 => fsuid.to_base58(),
+            FsdkUidCliFormat::Base32 => fsuid.to_crockford_base32(),
+            FsdkUidCliFormat::Base64Url => fsuid.to_base64url(),
+            FsdkUidCliFormat::Sortable => fsuid.to_sortable(),
+            FsdkUidCliFormat::Uri => fsuid.to_uri(),
+        }
+    }
+}
+
+// Options (--name value or --name=value) of a subcommand, rejecting unknown ones and repeated ones that aren't repeatable
+struct FsdkUidCliOptions {
+    values: HashMap<String, Vec<String>>,
+}
+
+impl FsdkUidCliOptions {
+    fn parse(arguments: &[String], allowed: &[&str], repeatable: &[&str]) -> Result<Self, FsdkUidCliError> {
+        let mut values = HashMap::new();
+        let mut arguments = arguments.iter();
+
+        while let Some(argument) = arguments.next() {
+            let option = argument.strip_prefix("--").ok_or_else(|| FsdkUidCliError::Usage(format!("Unexpected argument {:?}", argument)))?;
+            let (name, value) = match option.split_once('=') {
+                Some((name, value)) => (name, value.to_string()),
+                None => (option, arguments.next().ok_or_else(|| FsdkUidCliError::Usage(format!("Missing value of --{}", option)))?.clone()),
+            };
+
+            if !allowed.contains(&name) && !repeatable.contains(&name) {
+                return Err(FsdkUidCliError::Usage(format!("Unknown option --{}", name)));
+            }
+
+            let occurrences: &mut Vec<String> = values.entry(name.to_string()).or_default();
+            if !occurrences.is_empty() && !repeatable.contains(&name) {
+                return Err(FsdkUidCliError::Usage(format!("Option --{} is repeated", name)));
+            }
+
+            occurrences.push(value);
+        }
+
+        Ok(FsdkUidCliOptions { values })
+    }
+
+    fn get(&self, name: &str) -> Option<&str> {
+        self.values.get(name).and_then(|occurrences| occurrences.first()).map(String::as_str)
+    }
+
+    fn all(&self, name: &str) -> &[String] {
+        self.values.get(name).map_or(&[], Vec::as_slice)
+    }
+
+    fn number(&self, name: &str, default: u64) -> Result<u64, FsdkUidCliError> {
+        match self.get(name) {
+            Some(value) => {
+                let (digits, multiplier) = match value.char_indices().last() {
+                    Some((position, 'K' | 'k')) => (&value[..position], 1_000),
+                    Some((position, 'M' | 'm')) => (&value[..position], 1_000_000),
+                    Some((position, 'G' | 'g')) => (&value[..position], 1_000_000_000),
+                    _ => (value, 1),
+                };
+
+                digits
+                    .parse::<u64>()
+                    .ok()
+                    .and_then(|number| number.checked_mul(multiplier))
+                    .ok_or_else(|| FsdkUidCliError::Usage(format!("Invalid --{} {:?}", name, value)))
+            }
+            None => Ok(default),
+        }
+    }
+
+    fn format(&self, name: &str) -> Result<FsdkUidCliFormat, FsdkUidCliError> {
+        self.get(name).map_or(Ok(FsdkUidCliFormat::Decimal), FsdkUidCliFormat::parse)
+    }
+}
+
+// Data is read from the input (stdin), written to the output and progress to the report (stderr), so the output can be redirected to a file
+fn fsdkuid_cli_run(arguments: &[String], input: &mut impl BufRead, output: &mut impl Write, report: &mut impl Write) -> Result<(), FsdkUidCliError> {
+    match arguments.split_first() {
+        Some((subcommand, options)) if subcommand == "generate" => fsdkuid_cli_generate(&FsdkUidCliOptions::parse(options, &["node", "count", "format"], &[])?, output),
+        Some((subcommand, arguments)) if subcommand == "inspect" => match arguments {
+            [fsuid] => fsdkuid_cli_inspect(fsuid, output),
+            _ => Err(FsdkUidCliError::Usage("Subcommand inspect expects one FSUID".to_string())),
+        },
+        Some((subcommand, options)) if subcommand == "bulk" => fsdkuid_cli_bulk(&FsdkUidCliOptions::parse(options, &["node", "count", "format", "encoding"], &[])?, output, report),
+        Some((subcommand, options)) if subcommand == "audit" => FsdkUidCliOptions::parse(options, &[], &[]).and_then(|_| fsdkuid_cli_audit(input, output)),
+        Some((subcommand, options)) if subcommand == "decode" => fsdkuid_cli_decode(&FsdkUidCliOptions::parse(options, &[], &["era"])?, input, output),
+        Some((subcommand, _)) => Err(FsdkUidCliError::Usage(format!("Unknown subcommand {:?}", subcommand))),
+        None => Err(FsdkUidCliError::Usage("Missing subcommand".to_string())),
+    }
+}
+
+fn fsdkuid_cli_node_identifier(options: &FsdkUidCliOptions) -> Result<u8, FsdkUidCliError> {
+    let layout = FsdkUidLayout::FSUID;
+
+    match options.get("node") {
+        Some(node) => node.parse().map_err(|_| FsdkUidCliError::Usage(format!("Invalid --node {:?}, it should be between 0 and {}", node, layout.max_node_identifier()))),
+        None => NodeIdSource::default().resolve_required(&layout).map_err(|_| FsdkUidCliError::Usage("Missing --node and FSDK_NODE_ID environment variable".to_string())),
+    }
+}
+
+fn fsdkuid_cli_generate(options: &FsdkUidCliOptions, output: &mut impl Write) -> Result<(), FsdkUidCliError> {
+    let generator = FsdkUidGenerator::new(fsdkuid_cli_node_identifier(options)?);
+    let count = options.number("count", 1)?;
+    let format = options.format("format")?;
+
+    for _ in 0..count {
+        writeln!(output, "{}", format.encode(generator.generate_fsuid()))?;
+    }
+
+    Ok(())
+}
+
+fn fsdkuid_cli_inspect(text: &str, output: &mut impl Write) -> Result<(), FsdkUidCliError> {
+    let fsuid = FsdkUid::from_str(text.trim()).map_err(|error| FsdkUidCliError::Invalid(format!("Invalid FSUID {:?}: {}", text, error)))?;
+
+    writeln!(output, "decimal:    {}", fsuid.i64())?;
+    writeln!(output, "hex:        0x{:016x}", fsuid)?;
+    writeln!(output, "base62:     {}", fsuid.to_base62())?;
+    writeln!(output, "timestamp:  {}", fsuid.timestamp_delta())?;
+    writeln!(output, "datetime:   {}", fsuid.utc_datetime().to_rfc3339_opts(SecondsFormat::Millis, true))?;
+    writeln!(output, "node:       {}", fsuid.node_identifier())?;
+    writeln!(output, "counter:    {}", fsuid.node_counter())?;
+
+    Ok(())
+}
+
+// Streams rows of FSUID's with their UTC DateTime, node identifier and node counter, ex: to seed test databases or feed load tests
+fn fsdkuid_cli_bulk(options: &FsdkUidCliOptions, output: &mut impl Write, report: &mut impl Write) -> Result<(), FsdkUidCliError> {
+    let generator = FsdkUidGenerator::new(fsdkuid_cli_node_identifier(options)?);
+    let count = options.number("count", 1)?;
+    let encoding = options.format("encoding")?;
+    let csv = match options.get("format") {
+        Some("csv") => true,
+        Some("jsonl") | None => false,
+        Some(format) => return Err(FsdkUidCliError::Usage(format!("Unknown bulk format {:?}", format))),
+    };
+
+    if csv {
+        writeln!(output, "fsuid,datetime,node,counter")?;
+    }
+
+    let started = Instant::now();
+    let mut reported = started;
+
+    for row in 1..=count {
+        let fsuid = generator.generate_fsuid();
+        let datetime = fsuid.utc_datetime().to_rfc3339_opts(SecondsFormat::Millis, true);
+
+        // Every encoding is written as a JSON string, decimal FSUID's don't all fit in a JavaScript number
+        if csv {
+            writeln!(output, "{},{},{},{}", encoding.encode(fsuid), datetime, fsuid.node_identifier(), fsuid.node_counter())?;
+        } else {
+            writeln!(output, "{{\"fsuid\":\"{}\",\"datetime\":\"{}\",\"node\":{},\"counter\":{}}}", encoding.encode(fsuid), datetime, fsuid.node_identifier(), fsuid.node_counter())?;
+        }
+
+        if row % FSDK_FSUID_CLI_REPORT_CHECK_ROWS == 0 && reported.elapsed() >= FSDK_FSUID_CLI_REPORT_INTERVAL {
+            reported = Instant::now();
+            fsdkuid_cli_report_throughput(report, row, count, started.elapsed())?;
+        }
+    }
+
+    output.flush()?;
+    fsdkuid_cli_report_throughput(report, count, count, started.elapsed())
+}
+
+fn fsdkuid_cli_report_throughput(report: &mut impl Write, written: u64, count: u64, elapsed: Duration) -> Result<(), FsdkUidCliError> {
+    let throughput = written as f64 / elapsed.as_secs_f64().max(f64::EPSILON);
+    writeln!(report, "fsuid: {}/{} FSUID's written in {:.3}s ({:.0} FSUID's/s)", written, count, elapsed.as_secs_f64(), throughput)?;
+    Ok(())
+}
+
+// Checks FSUID's of an export dump (one per line) for duplicates, FSUID's lower than the previous one of the same node and node counters skipped within
+// a timestamp tick. Node counters aren't checked across timestamp ticks since generators don't all restart them at 0 on a new tick
+fn fsdkuid_cli_audit(input: &mut impl BufRead, output: &mut impl Write) -> Result<(), FsdkUidCliError> {
+    let mut first_lines: HashMap<FsdkUid, usize> = HashMap::new();
+    let mut last_of_node: [Option<(FsdkUid, usize)>; 256] = [None; 256];
+    let (mut audited, mut invalid, mut duplicates, mut out_of_order, mut gaps) = (0u64, 0u64, 0u64, 0u64, 0u64);
+
+    for (index, line) in input.lines().enumerate() {
+        let (line, number) = (line?, index + 1);
+        let text = line.trim();
+
+        if text.is_empty() {
+            continue;
+        }
+
+        let fsuid = match FsdkUid::from_str(text) {
+            Ok(fsuid) => fsuid,
+            Err(error) => {
+                invalid += 1;
+                writeln!(output, "line {}: invalid FSUID {:?}: {}", number, text, error)?;
+                continue;
+            }
+        };
+        audited += 1;
+
+        match first_lines.entry(fsuid) {
+            Entry::Occupied(first) => {
+                duplicates += 1;
+                writeln!(output, "line {}: duplicate FSUID {} (first seen on line {})", number, fsuid.i64(), first.get())?;
+                continue;
+            }
+            Entry::Vacant(first) => {
+                first.insert(number);
+            }
+        }
+
+        let node_identifier = fsuid.node_identifier() as usize;
+
+        if let Some((last, last_number)) = last_of_node[node_identifier] {
+            if fsuid < last {
+                out_of_order += 1;
+                writeln!(output, "line {}: FSUID {} of node {} is lower than FSUID {} on line {}", number, fsuid.i64(), node_identifier, last.i64(), last_number)?;
+            } else if fsuid.timestamp_delta() == last.timestamp_delta() && fsuid.node_counter() - last.node_counter() > 1 {
+                gaps += 1;
+                writeln!(output, "line {}: node {} skipped counters {} to {} at timestamp {}", number, node_identifier, last.node_counter() + 1, fsuid.node_counter() - 1, fsuid.timestamp_delta())?;
+            }
+        }
+
+        if last_of_node[node_identifier].is_none_or(|(last, _)| fsuid > last) {
+            last_of_node[node_identifier] = Some((fsuid, number));
+        }
+    }
+
+    writeln!(output, "{} FSUID's audited: {} invalid, {} duplicates, {} out of order, {} counter gaps", audited, invalid, duplicates, out_of_order, gaps)?;
+
+    match invalid + duplicates + out_of_order + gaps {
+        0 => Ok(()),
+        anomalies => Err(FsdkUidCliError::Invalid(format!("Audit found {} anomalies", anomalies))),
+    }
+}
+
+fn fsdkuid_cli_era(specification: &str) -> Result<FsdkUidEra, FsdkUidCliError> {
+    let invalid = |field: &str| FsdkUidCliError::Usage(format!("Invalid {} of --era {:?}", field, specification));
+    let cutover = |text: Option<&str>, field: &str| -> Result<Option<DateTime<Utc>>, FsdkUidCliError> {
+        match text.filter(|text| !text.is_empty()) {
+            Some(text) => DateTime::parse_from_rfc3339(text).map(|datetime| Some(datetime.with_timezone(&Utc))).map_err(|_| invalid(field)),
+            None => Ok(None),
+        }
+    };
+    let value = |text: Option<&str>, default: i64, field: &str| text.filter(|text| !text.is_empty()).map_or(Ok(default), |text| text.parse().map_err(|_| invalid(field)));
+
+    let fields: Vec<&str> = specification.split(',').collect();
+    let label = fields.first().filter(|label| !label.is_empty()).ok_or_else(|| invalid("label"))?;
+
+    let scheme = match fields.get(1).map(|scheme| scheme.split('/').collect::<Vec<&str>>()).as_deref() {
+        Some(["fsuid"]) => FsdkUidScheme::Fsuid,
+        Some(["sequential"]) => FsdkUidScheme::Sequential,
+        Some(["snowflake", epoch_milliseconds, timestamp_shift]) => FsdkUidScheme::Snowflake {
+            epoch_milliseconds: epoch_milliseconds.parse().map_err(|_| invalid("scheme"))?,
+            timestamp_shift: timestamp_shift.parse().ok().filter(|shift| *shift < 64).ok_or_else(|| invalid("scheme"))?,
+        },
+        _ => return Err(invalid("scheme")),
+    };
+
+    if fields.len() > 6 {
+        return Err(invalid("fields"));
+    }
+
+    Ok(FsdkUidEra {
+        label: label.to_string(),
+        scheme,
+        from: cutover(fields.get(2).copied(), "from")?,
+        until: cutover(fields.get(3).copied(), "until")?,
+        min_value: value(fields.get(4).copied(), 0, "min")?,
+        max_value: value(fields.get(5).copied(), i64::MAX, "max")?,
+    })
+}
+
+// Labels identifiers of mixed sources (ex: log files spanning a migration) with the era detected by FsdkUidEraDetector, one tab separated row per line:
+// identifier, era, scheme, confidence and embedded datetime, "-" standing for missing values. Identifiers that can't be parsed are rows without era
+fn fsdkuid_cli_decode(options: &FsdkUidCliOptions, input: &mut impl BufRead, output: &mut impl Write) -> Result<(), FsdkUidCliError> {
+    let mut eras = options.all("era").iter().map(|specification| fsdkuid_cli_era(specification)).collect::<Result<Vec<FsdkUidEra>, FsdkUidCliError>>()?;
+
+    if eras.is_empty() {
+        eras.push(FsdkUidEra { label: "fsuid".to_string(), scheme: FsdkUidScheme::Fsuid, min_value: 0, max_value: i64::MAX, from: None, until: None });
+    }
+
+    let detector = FsdkUidEraDetector::new(eras);
+
+    for line in input.lines() {
+        let line = line?;
+        let text = line.trim();
+
+        if text.is_empty() {
+            continue;
+        }
+
+        let detection = FsdkUid::from_str(text).ok().map(|fsuid| detector.detect(fsuid.i64()));
+        let era = detection.as_ref().and_then(|detection| detector.eras().iter().find(|era| Some(&era.label) == detection.era.as_ref()));
+        let scheme = match era.map(|era| era.scheme) {
+            Some(FsdkUidScheme::Fsuid) => "fsuid",
+            Some(FsdkUidScheme::Snowflake { .. }) => "snowflake",
+            Some(FsdkUidScheme::Sequential) => "sequential",
+            None => "-",
+        };
+
+        writeln!(
+            output,
+            "{}\t{}\t{}\t{:?}\t{}",
+            text,
+            era.map_or("-", |era| era.label.as_str()),
+            scheme,
+            detection.as_ref().map_or(FsdkUidEraConfidence::None, |detection| detection.confidence),
+            detection.and_then(|detection| detection.utc_datetime).map_or("-".to_string(), |datetime| datetime.to_rfc3339_opts(SecondsFormat::Millis, true)),
+        )?;
+    }
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let arguments: Vec<String> = env::args().skip(1).collect();
+    let mut output = BufWriter::new(io::stdout().lock());
+
+    match fsdkuid_cli_run(&arguments, &mut io::stdin().lock(), &mut output, &mut io::stderr()).and_then(|()| output.flush().map_err(FsdkUidCliError::from)) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(FsdkUidCliError::Io(error)) if error.kind() == io::ErrorKind::BrokenPipe => ExitCode::SUCCESS, // Output piped to a command that stopped reading (ex: head)
+        Err(FsdkUidCliError::Usage(message)) => {
+            eprintln!("fsuid: {}\n\n{}", message, FSDK_FSUID_CLI_USAGE);
+            ExitCode::from(2)
+        }
+        Err(error) => {
+            eprintln!("fsuid: {}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(arguments: &[&str]) -> Result<String, String> {
+        let arguments: Vec<String> = arguments.iter().map(|argument| argument.to_string()).collect();
+        let mut output = Vec::new();
+
+        fsdkuid_cli_run(&arguments, &mut io::empty(), &mut output, &mut io::sink()).map_err(|error| error.to_string())?;
+        Ok(String::from_utf8(output).unwrap())
+    }
+
+    #[test]
+    fn test_fsdkuid_cli_generate() {
+        let output = run(&["generate", "--node", "42", "--count=3", "--format", "base62"]).unwrap();
+        let fsuids: Vec<FsdkUid> = output.lines().map(|line| FsdkUid::from_base62(line).unwrap()).collect();
+
+        assert_eq!(fsuids.len(), 3, "[fsuid generate Error] One FSUID must be written per line");
+        assert!(fsuids.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(fsuids.iter().all(|fsuid| fsuid.node_identifier() == 42));
+
+        for format in ["decimal", "hex", "base62", "uri"] {
+            let line = run(&["generate", "--node", "1", "--format", format]).unwrap();
+            assert_eq!(FsdkUid::from_str(line.trim()).map(|fsuid| fsuid.node_identifier()), Ok(1), "[fsuid generate Error] FSUID's must be written in the requested format");
+        }
+    }
+
+    #[test]
+    fn test_fsdkuid_cli_rejects_invalid_arguments() {
+        assert_eq!(run(&[]), Err("Missing subcommand".to_string()));
+        assert_eq!(run(&["mint"]), Err("Unknown subcommand \"mint\"".to_string()));
+        assert_eq!(run(&["generate", "--node", "1", "--size", "2"]), Err("Unknown option --size".to_string()));
+        assert_eq!(run(&["generate", "--node", "256"]), Err("Invalid --node \"256\", it should be between 0 and 255".to_string()));
+        assert_eq!(run(&["generate", "--node", "1", "--format", "octal"]), Err("Unknown format \"octal\"".to_string()));
+        assert_eq!(run(&["generate", "--node"]), Err("Missing value of --node".to_string()));
+    }
+
+    #[test]
+    fn test_fsdkuid_cli_inspect() {
+        let expected = "decimal:    113131996488794368\nhex:        0x0191ecf38f720100\nbase62:     8M92EuiIHw\ntimestamp:  1726257270642\ndatetime:   2024-09-13T19:54:30.642Z\nnode:       1\ncounter:    0\n";
+
+        for text in ["113131996488794368", "0x0191ecf38f720100", "8M92EuiIHw", "usr_8M92EuiIHw", "fsuid:base62:8M92EuiIHw"] {
+            assert_eq!(run(&["inspect", text]), Ok(expected.to_string()), "[fsuid inspect Error] FSUID {} must be decoded", text);
+        }
+
+        assert_eq!(run(&["inspect"]), Err("Subcommand inspect expects one FSUID".to_string()));
+        assert_eq!(run(&["inspect", "8M92-uiIHw"]), Err("Invalid FSUID \"8M92-uiIHw\": FSUID text contains the invalid character '-' at position 4".to_string()));
+    }
+
+    #[test]
+    fn test_fsdkuid_cli_bulk() {
+        let arguments: Vec<String> = ["bulk", "--node", "7", "--count", "2K", "--format", "csv", "--encoding", "base62"].iter().map(|argument| argument.to_string()).collect();
+        let (mut output, mut report) = (Vec::new(), Vec::new());
+        fsdkuid_cli_run(&arguments, &mut io::empty(), &mut output, &mut report).map_err(|error| error.to_string()).unwrap();
+
+        let output = String::from_utf8(output).unwrap();
+        let mut lines = output.lines();
+        assert_eq!(lines.next(), Some("fsuid,datetime,node,counter"), "[fsuid bulk Error] CSV output must start with a header");
+
+        let fsuids: Vec<FsdkUid> = lines.map(|line| FsdkUid::from_base62(line.split(',').next().unwrap()).unwrap()).collect();
+        assert_eq!(fsuids.len(), 2000, "[fsuid bulk Error] Count suffix K must multiply the count by 1000");
+        assert!(fsuids.windows(2).all(|pair| pair[0] < pair[1]) && fsuids.iter().all(|fsuid| fsuid.node_identifier() == 7));
+        assert!(String::from_utf8(report).unwrap().starts_with("fsuid: 2000/2000 FSUID's written in "), "[fsuid bulk Error] Throughput must be reported");
+
+        let line = run(&["bulk", "--node", "7"]).unwrap();
+        let fsuid = line.strip_prefix("{\"fsuid\":\"").and_then(|rest| rest.split('"').next()).unwrap().parse::<FsdkUid>().unwrap();
+        assert_eq!(line, format!("{{\"fsuid\":\"{}\",\"datetime\":\"{}\",\"node\":7,\"counter\":{}}}\n", fsuid.i64(), fsuid.utc_datetime().to_rfc3339_opts(SecondsFormat::Millis, true), fsuid.node_counter()));
+
+        assert_eq!(run(&["bulk", "--node", "7", "--format", "xml"]), Err("Unknown bulk format \"xml\"".to_string()));
+        assert_eq!(run(&["bulk", "--node", "7", "--count", "1T"]), Err("Invalid --count \"1T\"".to_string()));
+    }
+
+    #[test]
+    fn test_fsdkuid_cli_audit() {
+        let audit = |input: &str| {
+            let mut output = Vec::new();
+            let result = fsdkuid_cli_run(&["audit".to_string()], &mut input.as_bytes(), &mut output, &mut io::sink()).map_err(|error| error.to_string());
+            (String::from_utf8(output).unwrap(), result)
+        };
+
+        let (a, b, c, e) = (FsdkUid::from_parts(1000, 3, 0), FsdkUid::from_parts(1000, 3, 1), FsdkUid::from_parts(1000, 3, 2), FsdkUid::from_parts(1001, 3, 3));
+        let (output, result) = audit(&format!("{}\n{}\n\n{}\n{}\n", a.i64(), b.to_base62(), c.i64(), e.i64()));
+        assert_eq!(output, "4 FSUID's audited: 0 invalid, 0 duplicates, 0 out of order, 0 counter gaps\n", "[fsuid audit Error] Increasing FSUID's must pass the audit");
+        assert_eq!(result, Ok(()));
+
+        let (output, result) = audit(&format!("{}\n{}\n{}\n{}\n{}\nusr-1\n", a.i64(), c.i64(), b.i64(), c.i64(), FsdkUid::from_parts(999, 4, 0).i64()));
+        assert_eq!(
+            output,
+            format!(
+                "line 2: node 3 skipped counters 1 to 1 at timestamp 1000\nline 3: FSUID {} of node 3 is lower than FSUID {} on line 2\nline 4: duplicate FSUID {} (first seen on line 2)\nline 6: invalid FSUID \"usr-1\": FSUID text contains the invalid character '-' at position 3\n5 FSUID's audited: 1 invalid, 1 duplicates, 1 out of order, 1 counter gaps\n",
+                b.i64(),
+                c.i64(),
+                c.i64()
+            ),
+            "[fsuid audit Error] Duplicates, out of order FSUID's, counter gaps and invalid lines must be reported"
+        );
+        assert_eq!(result, Err("Audit found 4 anomalies".to_string()));
+    }
+
+    #[test]
+    fn test_fsdkuid_cli_decode() {
+        let decode = |arguments: &[&str], input: &str| {
+            let arguments: Vec<String> = arguments.iter().map(|argument| argument.to_string()).collect();
+            let mut output = Vec::new();
+            fsdkuid_cli_run(&arguments, &mut input.as_bytes(), &mut output, &mut io::sink()).map(|()| String::from_utf8(output).unwrap()).map_err(|error| error.to_string())
+        };
+        let eras = [
+            "decode",
+            "--era",
+            "legacy-autoincrement,sequential,,,1,99999999",
+            "--era=discord-snowflake,snowflake/1420070400000/22,2016-01-01T00:00:00Z,2024-01-01T00:00:00Z,1",
+            "--era",
+            "fsuid,fsuid,2024-01-01T00:00:00Z,,1",
+        ];
+
+        assert_eq!(
+            decode(&eras, "113131996488794368\n175928847299117063\n\n4242\nusr-1\n"),
+            Ok("113131996488794368\tfsuid\tfsuid\tHigh\t2024-09-13T19:54:30.642Z\n\
+                175928847299117063\tdiscord-snowflake\tsnowflake\tHigh\t2016-04-30T11:18:25.796Z\n\
+                4242\tlegacy-autoincrement\tsequential\tMedium\t-\n\
+                usr-1\t-\t-\tNone\t-\n"
+                .to_string()),
+            "[fsuid decode Error] Identifiers must be labelled with their era, scheme, confidence and embedded datetime"
+        );
+
+        assert_eq!(decode(&["decode"], "8M92EuiIHw\n"), Ok("8M92EuiIHw\tfsuid\tfsuid\tHigh\t2024-09-13T19:54:30.642Z\n".to_string()), "[fsuid decode Error] FSUID era must be used when no era is given");
+        assert_eq!(decode(&["decode", "--era", "legacy,uuid"], ""), Err("Invalid scheme of --era \"legacy,uuid\"".to_string()));
+        assert_eq!(decode(&["inspect", "--era", "legacy,sequential"], ""), Err("Subcommand inspect expects one FSUID".to_string()));
+    }
+}
@@ -0,0 +1,86 @@
+use std::io;
+use std::time::Duration;
+
+use crate::http::fsdkuid_http_request;
+
+const FSDK_FSUID_CLOUD_METADATA_ADDRESS: &str = "169.254.169.254:80"; // Link-local address of the EC2 and GCE instance metadata services
+const FSDK_FSUID_CLOUD_METADATA_TIMEOUT: Duration = Duration::from_millis(300); // Max time waiting for the metadata service, which isn't reachable outside of the cloud
+const FSDK_FSUID_GCE_METADATA_HOST: &str = "metadata.google.internal";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum CloudMetadataItem<'a> {
+    InstanceId,
+    Tag(&'a str), // EC2 instance tag (requires tags in instance metadata) or GCE custom metadata attribute
+}
+
+// Reads the item from the EC2 instance metadata service (IMDSv2), then from the GCE one, returning None when neither is reachable or has the item
+pub(crate) fn fsdkuid_cloud_metadata(item: CloudMetadataItem) -> io::Result<Option<String>> {
+    fsdkuid_cloud_metadata_from(FSDK_FSUID_CLOUD_METADATA_ADDRESS, item)
+}
+
+fn fsdkuid_cloud_metadata_from(address: &str, item: CloudMetadataItem) -> io::Result<Option<String>> {
+    let token = match fsdkuid_http_request(address, "PUT", "/latest/api/token", &[("X-aws-ec2-metadata-token-ttl-seconds", "60")], b"", FSDK_FSUID_CLOUD_METADATA_TIMEOUT) {
+        Ok(response) => response,
+        Err(error) if matches!(error.kind(), io::ErrorKind::TimedOut | io::ErrorKind::WouldBlock | io::ErrorKind::ConnectionRefused | io::ErrorKind::NetworkUnreachable | io::ErrorKind::HostUnreachable) => return Ok(None),
+        Err(error) => return Err(error),
+    };
+
+    let (path, headers) = match token.is_success() {
+        true => {
+            let token = String::from_utf8(token.body).map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "EC2 metadata token isn't utf-8"))?;
+            let path = match item {
+                CloudMetadataItem::InstanceId => "/latest/meta-data/instance-id".to_string(),
+                CloudMetadataItem::Tag(tag) => format!("/latest/meta-data/tags/instance/{}", tag),
+            };
+
+            (path, vec![("X-aws-ec2-metadata-token".to_string(), token.trim().to_string())])
+        }
+        false => {
+            let path = match item {
+                CloudMetadataItem::InstanceId => "/computeMetadata/v1/instance/id".to_string(),
+                CloudMetadataItem::Tag(tag) => format!("/computeMetadata/v1/instance/attributes/{}", tag),
+            };
+
+            (path, vec![("Host".to_string(), FSDK_FSUID_GCE_METADATA_HOST.to_string()), ("Metadata-Flavor".to_string(), "Google".to_string())])
+        }
+    };
+
+    let headers: Vec<(&str, &str)> = headers.iter().map(|(name, value)| (name.as_str(), value.as_str())).collect();
+    let response = fsdkuid_http_request(address, "GET", &path, &headers, b"", FSDK_FSUID_CLOUD_METADATA_TIMEOUT)?;
+
+    match response.status {
+        200 => String::from_utf8(response.body).map(|value| Some(value.trim().to_string())).map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "Instance metadata isn't utf-8")),
+        404 => Ok(None),
+        status => Err(io::Error::other(format!("Instance metadata service replied with status {}", status))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::fsdkuid_http_test_server;
+
+    #[test]
+    fn test_fsdkuid_cloud_metadata_ec2() {
+        let (address, service) = fsdkuid_http_test_server(vec!["HTTP/1.1 200 OK\r\nContent-Length: 5\r\n\r\ntoken", "HTTP/1.1 200 OK\r\nContent-Length: 1\r\n\r\n7"]);
+
+        assert_eq!(fsdkuid_cloud_metadata_from(&address, CloudMetadataItem::Tag("fsuid-node")).unwrap(), Some("7".to_string()));
+
+        let requests = service.join().unwrap();
+        assert!(requests[0].starts_with("PUT /latest/api/token HTTP/1.0"));
+        assert!(requests[1].starts_with("GET /latest/meta-data/tags/instance/fsuid-node HTTP/1.0") && requests[1].contains("X-aws-ec2-metadata-token: token"), "[fsdkuid_cloud_metadata() Error] EC2 metadata must be read with an IMDSv2 token");
+    }
+
+    #[test]
+    fn test_fsdkuid_cloud_metadata_gce() {
+        let (address, service) = fsdkuid_http_test_server(vec!["HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\n\r\n", "HTTP/1.1 200 OK\r\nContent-Length: 19\r\n\r\n4425710245614823331"]);
+
+        assert_eq!(fsdkuid_cloud_metadata_from(&address, CloudMetadataItem::InstanceId).unwrap(), Some("4425710245614823331".to_string()));
+
+        let requests = service.join().unwrap();
+        assert!(requests[1].starts_with("GET /computeMetadata/v1/instance/id HTTP/1.0") && requests[1].contains("Metadata-Flavor: Google"), "[fsdkuid_cloud_metadata() Error] GCE metadata must be read when EC2 metadata isn't available");
+
+        let (address, _service) = fsdkuid_http_test_server(vec!["HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\n\r\n", "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\n\r\n"]);
+        assert_eq!(fsdkuid_cloud_metadata_from(&address, CloudMetadataItem::Tag("fsuid-node")).unwrap(), None, "[fsdkuid_cloud_metadata() Error] Missing tags must not provide a value");
+    }
+}
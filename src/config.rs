@@ -0,0 +1,157 @@
+use std::env;
+
+use crate::{FsdkUidError, FsdkUidExhaustionPolicy, FsdkUidGenerator, FsdkUidLayout};
+
+const FSDK_FSUID_CONFIG_ENV_PREFIX: &str = "FSDK_FSUID_"; // Prefix of the environment variables read by FsdkUidGeneratorConfig::from_env() (ex: FSDK_FSUID_NODE_IDENTIFIER)
+const FSDK_FSUID_CONFIG_KEYS: &[&str] = &[
+    "node_identifier",
+    "layout",
+    "epoch_milliseconds",
+    "resolution_milliseconds",
+    "timestamp_bits",
+    "node_identifier_bits",
+    "node_counter_bits",
+    "exhaustion_policy",
+]; // Keys accepted by the generator configuration, unknown keys are rejected so typos don't silently fall back to defaults
+
+// Declarative generator configuration, read from TOML or environment variables with the same keys:
+// node_identifier, layout (fsuid, js_safe or short_code, default fsuid), epoch_milliseconds, resolution_milliseconds, timestamp_bits,
+// node_identifier_bits, node_counter_bits (overriding the layout preset) and exhaustion_policy (wait or spin, default wait)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FsdkUidGeneratorConfig {
+    pub node_identifier: u8,
+    pub layout: FsdkUidLayout,
+    pub exhaustion_policy: FsdkUidExhaustionPolicy,
+}
+
+impl FsdkUidGeneratorConfig {
+    // Reads the keys from FSDK_FSUID_<KEY> environment variables (ex: FSDK_FSUID_NODE_IDENTIFIER=3)
+    pub fn from_env() -> Result<Self, FsdkUidError> {
+        FsdkUidGeneratorConfig::from_lookup(|key| env::var(format!("{}{}", FSDK_FSUID_CONFIG_ENV_PREFIX, key.to_ascii_uppercase())).ok())
+    }
+
+    // Reads a flat TOML document of key = value lines, strings quoted and integers optionally separated by underscores
+    pub fn from_toml(text: &str) -> Result<Self, FsdkUidError> {
+        let mut entries = Vec::new();
+
+        for line in text.lines() {
+            let line = line.split_once('#').map_or(line, |(content, _)| content).trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            let (key, value) = line.split_once('=').ok_or(FsdkUidError::InvalidConfiguration)?;
+            let (key, value) = (key.trim(), value.trim());
+
+            if !FSDK_FSUID_CONFIG_KEYS.contains(&key) || entries.iter().any(|(existing, _)| *existing == key) {
+                return Err(FsdkUidError::InvalidConfiguration);
+            }
+
+            let value = match value.strip_prefix('"') {
+                Some(quoted) => quoted.strip_suffix('"').ok_or(FsdkUidError::InvalidConfiguration)?.to_string(),
+                None => value.replace('_', ""),
+            };
+
+            entries.push((key, value));
+        }
+
+        FsdkUidGeneratorConfig::from_lookup(|key| entries.iter().find(|(existing, _)| *existing == key).map(|(_, value)| value.clone()))
+    }
+
+    pub fn build(&self) -> FsdkUidGenerator {
+        FsdkUidGenerator::with_layout(self.node_identifier, self.layout).with_exhaustion_policy(self.exhaustion_policy)
+    }
+
+    fn from_lookup(lookup: impl Fn(&str) -> Option<String>) -> Result<Self, FsdkUidError> {
+        let number = |key: &str| -> Result<Option<u64>, FsdkUidError> {
+            lookup(key).map(|value| value.trim().parse::<u64>().map_err(|_| FsdkUidError::InvalidConfiguration)).transpose()
+        };
+        let bits = |key: &str, default: u8| -> Result<u8, FsdkUidError> {
+            number(key)?.map_or(Ok(default), |value| u8::try_from(value).map_err(|_| FsdkUidError::InvalidConfiguration))
+        };
+
+        let preset = match lookup("layout").as_deref().map(str::trim) {
+            None | Some("fsuid") => FsdkUidLayout::FSUID,
+            Some("js_safe") => FsdkUidLayout::JS_SAFE,
+            Some("short_code") => FsdkUidLayout::SHORT_CODE,
+            Some(_) => return Err(FsdkUidError::InvalidConfiguration),
+        };
+
+        let layout = FsdkUidLayout::try_new(
+            number("epoch_milliseconds")?.unwrap_or(preset.epoch_milliseconds()),
+            number("resolution_milliseconds")?.unwrap_or(preset.resolution_milliseconds()),
+            bits("timestamp_bits", preset.timestamp_bits())?,
+            bits("node_identifier_bits", preset.node_identifier_bits())?,
+            bits("node_counter_bits", preset.node_counter_bits())?,
+        )?;
+
+        let node_identifier = number("node_identifier")?.ok_or(FsdkUidError::InvalidConfiguration)?;
+        if node_identifier > layout.max_node_identifier() as u64 {
+            return Err(FsdkUidError::InvalidConfiguration);
+        }
+
+        let exhaustion_policy = match lookup("exhaustion_policy").as_deref().map(str::trim) {
+            None | Some("wait") => FsdkUidExhaustionPolicy::Wait,
+            Some("spin") => FsdkUidExhaustionPolicy::Spin,
+            Some(_) => return Err(FsdkUidError::InvalidConfiguration),
+        };
+
+        Ok(FsdkUidGeneratorConfig { node_identifier: node_identifier as u8, layout, exhaustion_policy })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fsdkuid_generator_config_from_toml() {
+        let config = FsdkUidGeneratorConfig::from_toml(
+            "# ids service\nnode_identifier = 42\nlayout = \"js_safe\"\nepoch_milliseconds = 1_735_689_600_000 # 2025\nexhaustion_policy = \"spin\"\n",
+        )
+        .unwrap();
+
+        assert_eq!(config.node_identifier, 42);
+        assert_eq!(config.layout, FsdkUidLayout::new(1735689600000, 1, 41, 6, 6), "[FsdkUidGeneratorConfig::from_toml() Error] Epoch must override the layout preset");
+        assert_eq!(config.exhaustion_policy, FsdkUidExhaustionPolicy::Spin);
+
+        let generator = config.build();
+        assert_eq!((generator.layout(), generator.exhaustion_policy()), (config.layout, config.exhaustion_policy));
+        assert_eq!(config.layout.node_identifier(&generator.generate_fsuid()), 42);
+
+        let defaults = FsdkUidGeneratorConfig::from_toml("node_identifier = 7").unwrap();
+        assert_eq!((defaults.layout, defaults.exhaustion_policy), (FsdkUidLayout::FSUID, FsdkUidExhaustionPolicy::Wait));
+    }
+
+    #[test]
+    fn test_fsdkuid_generator_config_rejects_invalid_values() {
+        for text in [
+            "layout = \"fsuid\"",
+            "node_identifier = 64\nlayout = \"js_safe\"",
+            "node_identifier = -1",
+            "node_identifier = 1\nnode_identifer = 2",
+            "node_identifier = 1\nnode_identifier = 2",
+            "node_identifier = 1\nlayout = \"snowflake\"",
+            "node_identifier = 1\ntimestamp_bits = 49",
+            "node_identifier = 1\nexhaustion_policy = \"fail\"",
+            "node_identifier = 1\nlayout = \"fsuid",
+            "node_identifier",
+        ] {
+            assert_eq!(FsdkUidGeneratorConfig::from_toml(text), Err(FsdkUidError::InvalidConfiguration), "[FsdkUidGeneratorConfig::from_toml() Error] {:?} must be rejected", text);
+        }
+    }
+
+    #[test]
+    fn test_fsdkuid_generator_config_from_lookup() {
+        let config = FsdkUidGeneratorConfig::from_lookup(|key| match key {
+            "node_identifier" => Some("5".to_string()),
+            "layout" => Some("short_code".to_string()),
+            "node_counter_bits" => Some("6".to_string()),
+            _ => None,
+        })
+        .unwrap();
+
+        assert_eq!(config.layout, FsdkUidLayout::new(1704067200000, 1000, 32, 3, 6), "[FsdkUidGeneratorConfig::from_env() Error] Bits must override the layout preset");
+        assert_eq!(config.node_identifier, 5);
+    }
+}
@@ -0,0 +1,145 @@
+use std::str;
+
+use chrono::SecondsFormat;
+
+use crate::encoding::FSDK_FSUID_BASE62_ALPHABET;
+use crate::FsdkUidLayout;
+
+const FSDK_FSUID_DESCRIPTOR_VERSION: u8 = 1; // Version of the layout descriptor document, increased on breaking changes of its structure
+
+// Golden FSUID of a layout, generated from the layout itself so non-Rust implementations of custom layouts can be validated against this crate
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FsdkUidConformanceVector {
+    pub description: &'static str,
+    pub fsuid: i64,
+    pub timestamp_ticks: u64,
+    pub node_identifier: u8,
+    pub node_counter: u8,
+    pub utc_datetime: String, // RFC 3339 with milliseconds precision
+    pub base62: String,
+}
+
+impl FsdkUidLayout {
+    // Canonical JSON document of the layout: keys sorted, no whitespace and FSUID fields from the most significant bits, so equal layouts give byte identical documents
+    pub fn to_descriptor_json(&self) -> String {
+        format!(
+            concat!(
+                "{{\"encoding\":{{\"alphabet\":\"{}\",\"canonical\":\"no leading zeros\",\"name\":\"base62\",\"value\":\"unsigned 64 bits\"}},",
+                "\"epoch_milliseconds\":{},",
+                "\"fields\":[{{\"bits\":{},\"name\":\"timestamp_ticks\"}},{{\"bits\":{},\"name\":\"node_identifier\"{}}},{{\"bits\":{},\"name\":\"node_counter\"}}],",
+                "\"resolution_milliseconds\":{},\"total_bits\":{},\"version\":{}}}"
+            ),
+            str::from_utf8(FSDK_FSUID_BASE62_ALPHABET).unwrap(),
+            self.epoch_milliseconds(),
+            self.timestamp_bits(),
+            self.node_identifier_bits(),
+            match self.datacenter_bits() {
+                0 => String::new(),
+                datacenter_bits => format!(",\"subfields\":[{{\"bits\":{},\"name\":\"datacenter_id\"}},{{\"bits\":{},\"name\":\"worker_id\"}}]", datacenter_bits, self.worker_bits()),
+            },
+            self.node_counter_bits(),
+            self.resolution_milliseconds(),
+            self.timestamp_bits() + self.node_identifier_bits() + self.node_counter_bits(),
+            FSDK_FSUID_DESCRIPTOR_VERSION
+        )
+    }
+
+    // Boundaries of each field of the layout, including both sides of the sign bit
+    pub fn conformance_vectors(&self) -> Vec<FsdkUidConformanceVector> {
+        let (max_ticks, max_node, max_counter) = (self.max_timestamp_ticks(), self.max_node_identifier(), self.max_node_counter());
+
+        [
+            ("Epoch start with minimum node identifier and counter", 0, 0, 0),
+            ("Epoch start with maximum node identifier and counter", 0, max_node, max_counter),
+            ("Last timestamp tick of the lower half with maximum node identifier and counter", max_ticks >> 1, max_node, max_counter),
+            ("First timestamp tick of the upper half with minimum node identifier and counter", (max_ticks >> 1) + 1, 0, 0),
+            ("Maximum timestamp tick with maximum node identifier and counter", max_ticks, max_node, max_counter),
+        ]
+        .into_iter()
+        .map(|(description, timestamp_ticks, node_identifier, node_counter)| {
+            let fsuid = self.from_parts(timestamp_ticks, node_identifier, node_counter);
+
+            FsdkUidConformanceVector {
+                description,
+                fsuid: fsuid.i64(),
+                timestamp_ticks,
+                node_identifier,
+                node_counter,
+                utc_datetime: self.utc_datetime(&fsuid).to_rfc3339_opts(SecondsFormat::Millis, true),
+                base62: fsuid.to_base62(),
+            }
+        })
+        .collect()
+    }
+
+    // Descriptor and golden vectors in a single canonical JSON document, FSUID's being strings since they don't all fit in a JavaScript number
+    pub fn to_conformance_json(&self) -> String {
+        let vectors: Vec<String> = self
+            .conformance_vectors()
+            .iter()
+            .map(|vector| {
+                format!(
+                    "{{\"base62\":\"{}\",\"description\":\"{}\",\"fsuid\":\"{}\",\"node_counter\":{},\"node_identifier\":{},\"timestamp_ticks\":{},\"utc_datetime\":\"{}\"}}",
+                    vector.base62, vector.description, vector.fsuid, vector.node_counter, vector.node_identifier, vector.timestamp_ticks, vector.utc_datetime
+                )
+            })
+            .collect();
+
+        format!("{{\"layout\":{},\"vectors\":[{}]}}", self.to_descriptor_json(), vectors.join(","))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FsdkUid, FSDK_FSUID_TEST_VECTORS};
+
+    #[test]
+    fn test_fsdkuid_layout_descriptor_json() {
+        assert_eq!(
+            FsdkUidLayout::FSUID.to_descriptor_json(),
+            concat!(
+                "{\"encoding\":{\"alphabet\":\"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz\",\"canonical\":\"no leading zeros\",\"name\":\"base62\",\"value\":\"unsigned 64 bits\"},",
+                "\"epoch_milliseconds\":0,",
+                "\"fields\":[{\"bits\":48,\"name\":\"timestamp_ticks\"},{\"bits\":8,\"name\":\"node_identifier\"},{\"bits\":8,\"name\":\"node_counter\"}],",
+                "\"resolution_milliseconds\":1,\"total_bits\":64,\"version\":1}"
+            ),
+            "[FsdkUidLayout.to_descriptor_json() Error] Default layout descriptor changed, non-Rust implementations must be updated"
+        );
+
+        assert!(FsdkUidLayout::SHORT_CODE.to_descriptor_json().contains("\"epoch_milliseconds\":1704067200000,"));
+        assert!(FsdkUidLayout::SHORT_CODE.to_descriptor_json().contains("\"resolution_milliseconds\":1000,\"total_bits\":40,"));
+        assert!(FsdkUidLayout::DATACENTER_WORKER.to_descriptor_json().contains("{\"bits\":8,\"name\":\"node_identifier\",\"subfields\":[{\"bits\":3,\"name\":\"datacenter_id\"},{\"bits\":5,\"name\":\"worker_id\"}]}"));
+    }
+
+    #[test]
+    fn test_fsdkuid_layout_conformance_vectors() {
+        let vectors = FsdkUidLayout::FSUID.conformance_vectors();
+
+        for vector in &vectors {
+            let golden = FSDK_FSUID_TEST_VECTORS.iter().find(|golden| golden.fsuid == vector.fsuid).expect("[FsdkUidLayout.conformance_vectors() Error] Default layout vectors must be golden test vectors");
+            assert_eq!((vector.timestamp_ticks as i64, vector.node_identifier, vector.node_counter), (golden.timestamp_delta, golden.node_identifier, golden.node_counter), "[FsdkUidLayout.conformance_vectors() Error] {}", vector.description);
+            assert_eq!((vector.utc_datetime.as_str(), vector.base62.as_str()), (golden.utc_datetime, golden.base62), "[FsdkUidLayout.conformance_vectors() Error] {}", vector.description);
+        }
+
+        for layout in [FsdkUidLayout::JS_SAFE, FsdkUidLayout::SHORT_CODE] {
+            for vector in layout.conformance_vectors() {
+                let fsuid = FsdkUid::from_base62(&vector.base62).unwrap();
+                assert_eq!(fsuid.i64(), vector.fsuid, "[FsdkUidLayout.conformance_vectors() Error] {}", vector.description);
+                assert_eq!((layout.timestamp_ticks(&fsuid), layout.node_identifier(&fsuid), layout.node_counter(&fsuid)), (vector.timestamp_ticks, vector.node_identifier, vector.node_counter));
+            }
+        }
+
+        let largest = FsdkUidLayout::SHORT_CODE.conformance_vectors().pop().unwrap();
+        assert_eq!(largest.utc_datetime, "2160-02-07T06:28:15.000Z");
+    }
+
+    #[test]
+    fn test_fsdkuid_layout_conformance_json() {
+        let json = FsdkUidLayout::FSUID.to_conformance_json();
+
+        assert!(json.starts_with(&format!("{{\"layout\":{},\"vectors\":[", FsdkUidLayout::FSUID.to_descriptor_json())));
+        assert!(json.contains("{\"base62\":\"LygHa16AHYF\",\"description\":\"Maximum timestamp tick with maximum node identifier and counter\",\"fsuid\":\"-1\",\"node_counter\":255,\"node_identifier\":255,\"timestamp_ticks\":281474976710655,\"utc_datetime\":\"+10889-08-02T05:31:50.655Z\"}]}"), "[FsdkUidLayout.to_conformance_json() Error] {}", json);
+        assert_eq!(json.matches("\"description\"").count(), 5);
+    }
+}
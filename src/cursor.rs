@@ -0,0 +1,276 @@
+use crate::encoding::{fsdkuid_decode_base64url_bytes, fsdkuid_encode_base64url_bytes};
+use crate::hmac::{fsdkuid_constant_time_eq, fsdkuid_hmac_sha256, fsdkuid_sha256};
+use crate::{FsdkUid, FsdkUidError};
+
+const FSDK_FSUID_CURSOR_CONTEXT: &[u8] = b"fsdk_uid/cursor/v1"; // Domain separation prefix hashed with every cursor, so a cursor tag can't be reused by other FSUID texts
+const FSDK_FSUID_CURSOR_KIND_AFTER: u8 = 0x01; // Cursor token kind for pages after a FSUID
+const FSDK_FSUID_CURSOR_KIND_BETWEEN: u8 = 0x02; // Cursor token kind for pages between two FSUID's
+const FSDK_FSUID_CURSOR_KIND_SIGNED: u8 = 0x80; // Flag set in the token kind when the tag is a HMAC instead of a checksum
+const FSDK_FSUID_CURSOR_KIND_PAGE_SIZE: u8 = 0x40; // Flag set in the token kind when the page size follows the FSUID's
+const FSDK_FSUID_CURSOR_KIND_BACKWARD: u8 = 0x20; // Flag set in the token kind when pages are read from the greatest FSUID
+const FSDK_FSUID_CURSOR_CHECKSUM_BYTES: usize = 4; // Number of SHA-256 bytes kept as checksum on unsigned cursors
+const FSDK_FSUID_CURSOR_SIGNATURE_BYTES: usize = 16; // Number of HMAC-SHA-256 bytes kept as signature on signed cursors
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum CursorDirection {
+    #[default]
+    Forward, // Pages are read in ascending FSUID order, after cursors continue with FSUID's greater than their FSUID
+    Backward, // Pages are read in descending FSUID order, after cursors continue with FSUID's lower than their FSUID
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Cursor {
+    start: FsdkUid,
+    end: Option<FsdkUid>,
+    direction: CursorDirection,
+    page_size: Option<u32>,
+}
+
+impl Cursor {
+    // Cursor for the page of FSUID's greater than the given one
+    pub fn after(fsuid: FsdkUid) -> Self {
+        Cursor { start: fsuid, end: None, direction: CursorDirection::Forward, page_size: None }
+    }
+
+    // Cursor for the page of FSUID's lower than the given one, read in descending order
+    pub fn before(fsuid: FsdkUid) -> Self {
+        Cursor::after(fsuid).with_direction(CursorDirection::Backward)
+    }
+
+    // Cursor for the page of FSUID's greater than the lowest and lower than the greatest of the given ones
+    pub fn between(first: FsdkUid, second: FsdkUid) -> Self {
+        Cursor {
+            start: FsdkUid::new(first.i64().min(second.i64())),
+            end: Some(FsdkUid::new(first.i64().max(second.i64()))),
+            direction: CursorDirection::Forward,
+            page_size: None,
+        }
+    }
+
+    pub fn with_direction(mut self, direction: CursorDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    // Page size requested when the cursor was created, carried in the token so clients can't change it between pages
+    pub fn with_page_size(mut self, page_size: u32) -> Self {
+        if page_size == 0 {
+            panic!("[ERROR in Cursor.with_page_size()] Cursor Page Size should be at least 1, but 0 was specified!");
+        }
+
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn start(&self) -> FsdkUid {
+        self.start
+    }
+
+    pub fn end(&self) -> Option<FsdkUid> {
+        self.end
+    }
+
+    pub fn direction(&self) -> CursorDirection {
+        self.direction
+    }
+
+    pub fn page_size(&self) -> Option<u32> {
+        self.page_size
+    }
+
+    // Whether the FSUID belongs to the page described by the cursor (both bounds are exclusive), backward after cursors containing the FSUID's lower than their FSUID
+    pub fn contains(&self, fsuid: &FsdkUid) -> bool {
+        match (self.end, self.direction) {
+            (None, CursorDirection::Backward) => fsuid.i64() < self.start.i64(),
+            (end, _) => fsuid.i64() > self.start.i64() && end.is_none_or(|end| fsuid.i64() < end.i64()),
+        }
+    }
+
+    // Opaque token protected by a checksum, detects accidental corruption but can be forged by anyone
+    pub fn encode(&self) -> String {
+        let payload = self.payload(false);
+        let tag = fsdkuid_sha256(&[FSDK_FSUID_CURSOR_CONTEXT, &payload]);
+        Cursor::token(&payload, &tag[..FSDK_FSUID_CURSOR_CHECKSUM_BYTES])
+    }
+
+    // Opaque token protected by a HMAC-SHA-256 of the given key, can only be forged by someone knowing the key
+    pub fn encode_signed(&self, key: &[u8]) -> String {
+        let payload = self.payload(true);
+        let tag = fsdkuid_hmac_sha256(key, &[FSDK_FSUID_CURSOR_CONTEXT, &payload]);
+        Cursor::token(&payload, &tag[..FSDK_FSUID_CURSOR_SIGNATURE_BYTES])
+    }
+
+    pub fn parse(token: &str) -> Result<Self, FsdkUidError> {
+        let bytes = fsdkuid_decode_base64url_bytes(token)?;
+        let (payload, tag) = Cursor::split(&bytes, false)?;
+
+        let expected_tag = fsdkuid_sha256(&[FSDK_FSUID_CURSOR_CONTEXT, payload]);
+        if !fsdkuid_constant_time_eq(tag, &expected_tag[..FSDK_FSUID_CURSOR_CHECKSUM_BYTES]) {
+            return Err(FsdkUidError::ChecksumMismatch);
+        }
+
+        Cursor::from_payload(payload)
+    }
+
+    // Parses a token created by encode_signed(), unsigned tokens are rejected so a forged cursor can't downgrade to a checksum
+    pub fn parse_signed(token: &str, key: &[u8]) -> Result<Self, FsdkUidError> {
+        let bytes = fsdkuid_decode_base64url_bytes(token)?;
+        let (payload, tag) = Cursor::split(&bytes, true)?;
+
+        let expected_tag = fsdkuid_hmac_sha256(key, &[FSDK_FSUID_CURSOR_CONTEXT, payload]);
+        if !fsdkuid_constant_time_eq(tag, &expected_tag[..FSDK_FSUID_CURSOR_SIGNATURE_BYTES]) {
+            return Err(FsdkUidError::SignatureMismatch);
+        }
+
+        Cursor::from_payload(payload)
+    }
+
+    fn payload(&self, signed: bool) -> Vec<u8> {
+        // Flags are only set when needed, so cursors without direction and page size keep the tokens of previous versions
+        let signed_flag = if signed { FSDK_FSUID_CURSOR_KIND_SIGNED } else { 0 };
+        let page_size_flag = if self.page_size.is_some() { FSDK_FSUID_CURSOR_KIND_PAGE_SIZE } else { 0 };
+        let backward_flag = if self.direction == CursorDirection::Backward { FSDK_FSUID_CURSOR_KIND_BACKWARD } else { 0 };
+        let mut payload = Vec::with_capacity(21);
+
+        match self.end {
+            None => payload.push(FSDK_FSUID_CURSOR_KIND_AFTER | signed_flag | page_size_flag | backward_flag),
+            Some(_) => payload.push(FSDK_FSUID_CURSOR_KIND_BETWEEN | signed_flag | page_size_flag | backward_flag),
+        }
+
+        payload.extend_from_slice(&self.start.i64().to_be_bytes());
+        if let Some(end) = self.end {
+            payload.extend_from_slice(&end.i64().to_be_bytes());
+        }
+
+        if let Some(page_size) = self.page_size {
+            payload.extend_from_slice(&page_size.to_be_bytes());
+        }
+
+        payload
+    }
+
+    fn token(payload: &[u8], tag: &[u8]) -> String {
+        fsdkuid_encode_base64url_bytes(&[payload, tag].concat())
+    }
+
+    fn split(bytes: &[u8], signed: bool) -> Result<(&[u8], &[u8]), FsdkUidError> {
+        let tag_length = if signed { FSDK_FSUID_CURSOR_SIGNATURE_BYTES } else { FSDK_FSUID_CURSOR_CHECKSUM_BYTES };
+
+        let flags = FSDK_FSUID_CURSOR_KIND_SIGNED | FSDK_FSUID_CURSOR_KIND_PAGE_SIZE | FSDK_FSUID_CURSOR_KIND_BACKWARD;
+        let page_size_length = match bytes.first() {
+            Some(kind) if kind & FSDK_FSUID_CURSOR_KIND_PAGE_SIZE != 0 => 4,
+            _ => 0,
+        };
+
+        let payload_length = match bytes.first().map(|kind| kind & !flags) {
+            Some(FSDK_FSUID_CURSOR_KIND_AFTER) => 9 + page_size_length,
+            Some(FSDK_FSUID_CURSOR_KIND_BETWEEN) => 17 + page_size_length,
+            _ => return Err(FsdkUidError::InvalidLength),
+        };
+
+        if bytes.len() != payload_length + tag_length {
+            return Err(FsdkUidError::InvalidLength);
+        }
+
+        if (bytes[0] & FSDK_FSUID_CURSOR_KIND_SIGNED != 0) != signed {
+            return Err(if signed { FsdkUidError::SignatureMismatch } else { FsdkUidError::ChecksumMismatch });
+        }
+
+        Ok(bytes.split_at(payload_length))
+    }
+
+    fn from_payload(payload: &[u8]) -> Result<Self, FsdkUidError> {
+        let read = |offset: usize| {
+            let mut bytes = [0u8; 8];
+            bytes.copy_from_slice(&payload[offset..offset + 8]);
+            FsdkUid::new(i64::from_be_bytes(bytes))
+        };
+
+        let kind = payload[0];
+        let start = read(1);
+        let end = match kind & !(FSDK_FSUID_CURSOR_KIND_SIGNED | FSDK_FSUID_CURSOR_KIND_PAGE_SIZE | FSDK_FSUID_CURSOR_KIND_BACKWARD) {
+            FSDK_FSUID_CURSOR_KIND_BETWEEN => Some(read(9)),
+            _ => None,
+        };
+
+        if end.is_some_and(|end| start.i64() > end.i64()) {
+            return Err(FsdkUidError::NonCanonical);
+        }
+
+        let page_size = match kind & FSDK_FSUID_CURSOR_KIND_PAGE_SIZE {
+            0 => None,
+            _ => match u32::from_be_bytes(payload[payload.len() - 4..].try_into().unwrap()) {
+                0 => return Err(FsdkUidError::NonCanonical),
+                page_size => Some(page_size),
+            },
+        };
+
+        let direction = if kind & FSDK_FSUID_CURSOR_KIND_BACKWARD != 0 { CursorDirection::Backward } else { CursorDirection::Forward };
+
+        Ok(Cursor { start, end, direction, page_size })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fsdkuid_cursor_round_trip() {
+        let first = FsdkUid::new(113131996488794368);
+        let second = FsdkUid::new(113131996488794624);
+
+        let after = Cursor::after(first);
+        assert_eq!(Cursor::parse(&after.encode()), Ok(after), "[Cursor::parse() Error] After cursor didn't round-trip");
+
+        let between = Cursor::between(second, first);
+        assert_eq!(between.start(), first, "[Cursor::between() Error] Cursor start must be the lowest FSUID");
+        assert_eq!(between.end(), Some(second), "[Cursor::between() Error] Cursor end must be the greatest FSUID");
+        assert_eq!(Cursor::parse(&between.encode()), Ok(between), "[Cursor::parse() Error] Between cursor didn't round-trip");
+        assert_eq!(Cursor::parse_signed(&between.encode_signed(b"key"), b"key"), Ok(between), "[Cursor::parse_signed() Error] Signed cursor didn't round-trip");
+
+        assert!(between.contains(&FsdkUid::new(113131996488794400)));
+        assert!(!between.contains(&first) && !between.contains(&second));
+        assert!(after.contains(&second) && !after.contains(&first));
+    }
+
+    #[test]
+    fn test_fsdkuid_cursor_direction_and_page_size() {
+        let fsuid = FsdkUid::new(113131996488794368);
+
+        let before = Cursor::before(fsuid).with_page_size(50);
+        assert_eq!((before.direction(), before.page_size()), (CursorDirection::Backward, Some(50)));
+        assert_eq!(Cursor::parse(&before.encode()), Ok(before), "[Cursor::parse() Error] Backward cursor with page size didn't round-trip");
+        assert_eq!(Cursor::parse_signed(&before.encode_signed(b"key"), b"key"), Ok(before));
+        assert!(before.contains(&FsdkUid::new(113131996488794367)) && !before.contains(&fsuid) && !before.contains(&FsdkUid::new(113131996488794369)));
+
+        let between = Cursor::between(fsuid, FsdkUid::new(113131996488794624)).with_direction(CursorDirection::Backward).with_page_size(u32::MAX);
+        assert_eq!(Cursor::parse(&between.encode()), Ok(between));
+        assert!(between.contains(&FsdkUid::new(113131996488794400)), "[Cursor.contains() Error] Direction must not change the bounds of between cursors");
+
+        let after = Cursor::after(fsuid);
+        assert_eq!((after.direction(), after.page_size()), (CursorDirection::Forward, None));
+        assert_eq!(fsdkuid_decode_base64url_bytes(&after.encode()).unwrap().len(), 9 + FSDK_FSUID_CURSOR_CHECKSUM_BYTES, "[Cursor.encode() Error] Cursors without page size must keep the previous token format");
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_fsdkuid_cursor_rejects_zero_page_size() {
+        Cursor::after(FsdkUid::new(0)).with_page_size(0);
+    }
+
+    #[test]
+    fn test_fsdkuid_cursor_tamper_detection() {
+        let cursor = Cursor::after(FsdkUid::new(113131996488794368));
+
+        let mut tampered: Vec<char> = cursor.encode().chars().collect();
+        tampered[4] = if tampered[4] == 'A' { 'B' } else { 'A' };
+        let tampered: String = tampered.into_iter().collect();
+        assert_eq!(Cursor::parse(&tampered), Err(FsdkUidError::ChecksumMismatch), "[Cursor::parse() Error] Tampered cursor must be rejected");
+
+        let signed = cursor.encode_signed(b"key");
+        assert_eq!(Cursor::parse_signed(&signed, b"other key"), Err(FsdkUidError::SignatureMismatch), "[Cursor::parse_signed() Error] Cursor signed with another key must be rejected");
+        assert_eq!(Cursor::parse_signed(&cursor.encode(), b"key"), Err(FsdkUidError::InvalidLength), "[Cursor::parse_signed() Error] Unsigned cursor must be rejected");
+        assert_eq!(Cursor::parse(&signed), Err(FsdkUidError::InvalidLength), "[Cursor::parse() Error] Signed cursor must be rejected");
+    }
+}
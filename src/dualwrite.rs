@@ -0,0 +1,126 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+
+use crate::FsdkUid;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FsdkUidDualWriteAnomaly {
+    TimestampOutsideWindow { uuid: u128, fsuid: FsdkUid, skew_milliseconds: i64 }, // FSUID generation datetime minus the record created_at is greater than the accepted skew
+    DuplicateFsuid { fsuid: FsdkUid, first_uuid: u128, uuid: u128 }, // The same FSUID was issued to two records
+    DuplicateUuid { uuid: u128, first_fsuid: FsdkUid, fsuid: FsdkUid }, // The same UUID was issued to two records
+    UuidNotVersion4 { uuid: u128, fsuid: FsdkUid }, // The legacy identifier isn't a RFC 4122 version 4 UUID
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FsdkUidDualWriteReport {
+    pub records: u64,
+    pub anomalies: Vec<FsdkUidDualWriteAnomaly>,
+}
+
+impl FsdkUidDualWriteReport {
+    pub fn is_consistent(&self) -> bool {
+        self.anomalies.is_empty()
+    }
+}
+
+// Consumes the (UUIDv4, FSUID, created_at) triples of records written with both identifiers during a migration, reporting every inconsistency found
+pub struct FsdkUidDualWriteVerifier {
+    max_skew: Duration,
+    fsuids: HashMap<FsdkUid, u128>,
+    uuids: HashMap<u128, FsdkUid>,
+    report: FsdkUidDualWriteReport,
+}
+
+impl FsdkUidDualWriteVerifier {
+    // max_skew is the max accepted difference between the FSUID generation datetime and the record created_at, in both directions
+    pub fn new(max_skew: Duration) -> Self {
+        FsdkUidDualWriteVerifier {
+            max_skew,
+            fsuids: HashMap::new(),
+            uuids: HashMap::new(),
+            report: FsdkUidDualWriteReport::default(),
+        }
+    }
+
+    // The UUID is given as u128 (ex: uuid::Uuid::as_u128()) so any UUID representation can be verified
+    pub fn check(&mut self, uuid: u128, fsuid: FsdkUid, created_at: DateTime<Utc>) {
+        self.report.records += 1;
+
+        // Version nibble must be 4 and variant bits must be 10 (RFC 4122)
+        if (uuid >> 76) & 0xf != 4 || (uuid >> 62) & 0b11 != 0b10 {
+            self.report.anomalies.push(FsdkUidDualWriteAnomaly::UuidNotVersion4 { uuid, fsuid });
+        }
+
+        let skew_milliseconds = (fsuid.utc_datetime() - created_at).num_milliseconds();
+        if skew_milliseconds.unsigned_abs() as u128 > self.max_skew.as_millis() {
+            self.report.anomalies.push(FsdkUidDualWriteAnomaly::TimestampOutsideWindow { uuid, fsuid, skew_milliseconds });
+        }
+
+        if let Some(&first_uuid) = self.fsuids.get(&fsuid) {
+            self.report.anomalies.push(FsdkUidDualWriteAnomaly::DuplicateFsuid { fsuid, first_uuid, uuid });
+        } else {
+            self.fsuids.insert(fsuid, uuid);
+        }
+
+        if let Some(&first_fsuid) = self.uuids.get(&uuid) {
+            self.report.anomalies.push(FsdkUidDualWriteAnomaly::DuplicateUuid { uuid, first_fsuid, fsuid });
+        } else {
+            self.uuids.insert(uuid, fsuid);
+        }
+    }
+
+    pub fn report(&self) -> &FsdkUidDualWriteReport {
+        &self.report
+    }
+
+    pub fn finish(self) -> FsdkUidDualWriteReport {
+        self.report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    const UUID_V4: u128 = 0x8f2c_1e4a_7b3d_4c5e_9a1b_2c3d_4e5f_6a7b;
+    const OTHER_UUID_V4: u128 = 0x1d2c_3b4a_5968_4776_a5b4_c3d2_e1f0_0a1b;
+
+    #[test]
+    fn test_fsdkuid_dual_write_consistent_records() {
+        let fsuid = FsdkUid::new(113131996488794368);
+        let created_at = Utc.timestamp_millis_opt(1726257270642 + 40).unwrap();
+
+        let mut verifier = FsdkUidDualWriteVerifier::new(Duration::from_millis(100));
+        verifier.check(UUID_V4, fsuid, created_at);
+        verifier.check(OTHER_UUID_V4, FsdkUid::new(113131996488794369), created_at);
+
+        let report = verifier.finish();
+        assert_eq!(report.records, 2);
+        assert!(report.is_consistent(), "[FsdkUidDualWriteVerifier.check() Error] Consistent records reported anomalies: {:?}", report.anomalies);
+    }
+
+    #[test]
+    fn test_fsdkuid_dual_write_anomalies() {
+        let fsuid = FsdkUid::new(113131996488794368);
+        let created_at = Utc.timestamp_millis_opt(1726257270642).unwrap();
+
+        let mut verifier = FsdkUidDualWriteVerifier::new(Duration::from_millis(100));
+        verifier.check(UUID_V4, fsuid, created_at);
+        verifier.check(OTHER_UUID_V4, fsuid, created_at);
+        verifier.check(UUID_V4, FsdkUid::new(113131996488794369), created_at + chrono::Duration::seconds(5));
+        verifier.check(0x1d2c_3b4a_5968_1776_a5b4_c3d2_e1f0_0a1b, FsdkUid::new(113131996488794370), created_at);
+
+        assert_eq!(
+            verifier.report().anomalies,
+            vec![
+                FsdkUidDualWriteAnomaly::DuplicateFsuid { fsuid, first_uuid: UUID_V4, uuid: OTHER_UUID_V4 },
+                FsdkUidDualWriteAnomaly::TimestampOutsideWindow { uuid: UUID_V4, fsuid: FsdkUid::new(113131996488794369), skew_milliseconds: -5000 },
+                FsdkUidDualWriteAnomaly::DuplicateUuid { uuid: UUID_V4, first_fsuid: fsuid, fsuid: FsdkUid::new(113131996488794369) },
+                FsdkUidDualWriteAnomaly::UuidNotVersion4 { uuid: 0x1d2c_3b4a_5968_1776_a5b4_c3d2_e1f0_0a1b, fsuid: FsdkUid::new(113131996488794370) },
+            ]
+        );
+    }
+}
@@ -0,0 +1,65 @@
+// Amazon DynamoDB interoperability without depending on the AWS SDK: FSUID attributes are AttributeValue::N(fsuid.to_dynamodb_number()),
+// sort keys are AttributeValue::S(fsuid.to_dynamodb_sort_key()) since DynamoDB orders string keys by their UTF-8 bytes
+
+use crate::{fsdkuid_parse_decimal, Encoding, FsdkUid, FsdkUidError, Sortable};
+
+const FSDK_FSUID_DYNAMODB_KEY_SEPARATOR: char = '#'; // Separator between the entity prefix and the FSUID of single-table design sort keys (ex: ORDER#<sortable>)
+
+impl FsdkUid {
+    // Decimal i64, exactly stored by DynamoDB numbers (38 digits of precision)
+    pub fn to_dynamodb_number(&self) -> String {
+        self.i64().to_string()
+    }
+
+    pub fn try_from_dynamodb_number(text: &str) -> Result<Self, FsdkUidError> {
+        fsdkuid_parse_decimal(text.as_bytes()).map(FsdkUid::new)
+    }
+
+    // Sortable format, so querying sort keys in ascending order returns FSUID's in generation order
+    pub fn to_dynamodb_sort_key(&self) -> String {
+        Sortable::encode(self)
+    }
+
+    // Single-table design sort key (ex: ORDER#<sortable>), so begins_with(prefix#) queries return the entity FSUID's in generation order
+    pub fn to_dynamodb_sort_key_with_prefix(&self, prefix: &str) -> String {
+        format!("{}{}{}", prefix, FSDK_FSUID_DYNAMODB_KEY_SEPARATOR, Sortable::encode(self))
+    }
+
+    // Accepts sort keys with or without prefix, the FSUID being after the last separator
+    pub fn try_from_dynamodb_sort_key(text: &str) -> Result<Self, FsdkUidError> {
+        let sortable = text.rsplit_once(FSDK_FSUID_DYNAMODB_KEY_SEPARATOR).map_or(text, |(_, sortable)| sortable);
+        Sortable::decode(sortable)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fsdkuid_dynamodb_number() {
+        let fsuid = FsdkUid::new(113131996488794368);
+
+        assert_eq!(fsuid.to_dynamodb_number(), "113131996488794368", "[fsuid.to_dynamodb_number() Error] DynamoDB number must be the i64 decimal");
+        assert_eq!(FsdkUid::try_from_dynamodb_number("113131996488794368"), Ok(fsuid));
+        assert_eq!(FsdkUid::try_from_dynamodb_number(&FsdkUid::new(i64::MIN).to_dynamodb_number()), Ok(FsdkUid::new(i64::MIN)));
+        assert_eq!(FsdkUid::try_from_dynamodb_number(""), Err(FsdkUidError::Empty));
+        assert!(FsdkUid::try_from_dynamodb_number("1.5").is_err());
+    }
+
+    #[test]
+    fn test_fsdkuid_dynamodb_sort_key() {
+        let fsuids = [FsdkUid::new(i64::MIN), FsdkUid::new(-1), FsdkUid::new(0), FsdkUid::new(113131996488794368), FsdkUid::new(i64::MAX)];
+
+        for pair in fsuids.windows(2) {
+            assert!(pair[0].to_dynamodb_sort_key() < pair[1].to_dynamodb_sort_key(), "[fsuid.to_dynamodb_sort_key() Error] Sort key of {} must sort before the one of {}", pair[0].i64(), pair[1].i64());
+            assert!(pair[0].to_dynamodb_sort_key_with_prefix("ORDER") < pair[1].to_dynamodb_sort_key_with_prefix("ORDER"));
+        }
+
+        let fsuid = fsuids[3];
+        assert_eq!(fsuid.to_dynamodb_sort_key_with_prefix("ORDER"), format!("ORDER#{}", fsuid.to_dynamodb_sort_key()));
+        assert_eq!(FsdkUid::try_from_dynamodb_sort_key(&fsuid.to_dynamodb_sort_key()), Ok(fsuid));
+        assert_eq!(FsdkUid::try_from_dynamodb_sort_key(&fsuid.to_dynamodb_sort_key_with_prefix("TENANT#7#ORDER")), Ok(fsuid), "[FsdkUid::try_from_dynamodb_sort_key() Error] FSUID must be read after the last separator");
+        assert_eq!(FsdkUid::try_from_dynamodb_sort_key("ORDER#"), Err(FsdkUidError::Empty));
+    }
+}
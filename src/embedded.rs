@@ -0,0 +1,209 @@
+// Decimal and Base62 routines restricted to `core`: they never allocate, never panic and don't go through core::fmt, so they can be used on embedded targets and in hot paths
+
+use crate::FsdkUidError;
+
+pub const FSDK_FSUID_DECIMAL_MAX_LENGTH: usize = 20; // Max number of characters of a FSUID in decimal format ("-9223372036854775808")
+pub const FSDK_FSUID_BASE62_MAX_LENGTH: usize = 11; // Max number of characters of a FSUID in Base62 format ("LygHa16AHYF")
+
+// Writes the FSUID in decimal format at the end of the buffer and returns the written text
+pub fn fsdkuid_format_decimal(fsuid: i64, buffer: &mut [u8; FSDK_FSUID_DECIMAL_MAX_LENGTH]) -> &str {
+    let mut remaining = fsuid.unsigned_abs();
+    let mut start = buffer.len();
+
+    for slot in buffer.iter_mut().rev() {
+        *slot = b'0' + (remaining % 10) as u8;
+        remaining /= 10;
+        start -= 1;
+
+        if remaining == 0 {
+            break;
+        }
+    }
+
+    if fsuid < 0 {
+        start -= 1;
+        if let Some(sign) = buffer.get_mut(start) {
+            *sign = b'-';
+        }
+    }
+
+    fsdkuid_ascii_str(buffer.get(start..).unwrap_or_default())
+}
+
+// Parses a FSUID in canonical decimal format (optional minus sign, no leading zeros, no "-0")
+pub fn fsdkuid_parse_decimal(text: &[u8]) -> Result<i64, FsdkUidError> {
+    let (negative, digits) = match text.split_first() {
+        None => return Err(FsdkUidError::Empty),
+        Some((b'-', digits)) => (true, digits),
+        Some(_) => (false, text),
+    };
+
+    match digits {
+        [] => return Err(FsdkUidError::Empty),
+        [b'0'] if negative => return Err(FsdkUidError::NonCanonical),
+        [b'0', _, ..] => return Err(FsdkUidError::NonCanonical),
+        _ => {}
+    }
+
+    let offset = negative as usize;
+    let mut value: i64 = 0;
+
+    // Accumulated as a negative number, since i64::MIN has no positive counterpart
+    for (index, &byte) in digits.iter().enumerate() {
+        if !byte.is_ascii_digit() {
+            return Err(FsdkUidError::InvalidCharacter { character: fsdkuid_invalid_character(digits, index), position: index + offset });
+        }
+
+        value = value
+            .checked_mul(10)
+            .and_then(|value| value.checked_sub((byte - b'0') as i64))
+            .ok_or(FsdkUidError::Overflow)?;
+    }
+
+    if negative {
+        Ok(value)
+    } else {
+        value.checked_neg().ok_or(FsdkUidError::Overflow)
+    }
+}
+
+// Writes the FSUID in Base62 format at the end of the buffer and returns the written text
+pub fn fsdkuid_format_base62(fsuid: i64, buffer: &mut [u8; FSDK_FSUID_BASE62_MAX_LENGTH]) -> &str {
+    let mut remaining = fsuid as u64;
+    let mut start = buffer.len();
+
+    for slot in buffer.iter_mut().rev() {
+        *slot = fsdkuid_base62_symbol((remaining % 62) as u8);
+        remaining /= 62;
+        start -= 1;
+
+        if remaining == 0 {
+            break;
+        }
+    }
+
+    fsdkuid_ascii_str(buffer.get(start..).unwrap_or_default())
+}
+
+// Parses a FSUID in canonical Base62 format, with the same rules as FsdkUid::from_base62()
+pub fn fsdkuid_parse_base62(text: &[u8]) -> Result<i64, FsdkUidError> {
+    if text.is_empty() {
+        return Err(FsdkUidError::Empty);
+    }
+
+    if let [b'0', _, ..] = text {
+        return Err(FsdkUidError::NonCanonical);
+    }
+
+    let mut value: u64 = 0;
+
+    for (position, &byte) in text.iter().enumerate() {
+        let digit = fsdkuid_base62_digit(byte).ok_or_else(|| FsdkUidError::InvalidCharacter { character: fsdkuid_invalid_character(text, position), position })?;
+
+        value = value
+            .checked_mul(62)
+            .and_then(|value| value.checked_add(digit as u64))
+            .ok_or(FsdkUidError::Overflow)?;
+    }
+
+    Ok(value as i64)
+}
+
+fn fsdkuid_base62_symbol(digit: u8) -> u8 {
+    match digit {
+        0..=9 => b'0' + digit,
+        10..=35 => b'A' + (digit - 10),
+        _ => b'a' + (digit - 36),
+    }
+}
+
+fn fsdkuid_base62_digit(symbol: u8) -> Option<u8> {
+    match symbol {
+        b'0'..=b'9' => Some(symbol - b'0'),
+        b'A'..=b'Z' => Some(symbol - b'A' + 10),
+        b'a'..=b'z' => Some(symbol - b'a' + 36),
+        _ => None,
+    }
+}
+
+// Character starting at the invalid byte, decoded from UTF-8 (U+FFFD when the bytes aren't valid UTF-8). Every previous byte is an ascii symbol,
+// so the byte position is also the character position, as reported by the other FSUID parsers
+fn fsdkuid_invalid_character(text: &[u8], position: usize) -> char {
+    text.get(position..)
+        .and_then(|rest| rest.utf8_chunks().next())
+        .and_then(|chunk| chunk.valid().chars().next())
+        .unwrap_or(char::REPLACEMENT_CHARACTER)
+}
+
+// Every routine in this module only writes ascii symbols, so the conversion never fails
+fn fsdkuid_ascii_str(bytes: &[u8]) -> &str {
+    core::str::from_utf8(bytes).unwrap_or_default()
+}
+
+// Restricted to core like the routines, so they also run in no_std test builds (cargo test --no-default-features)
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fsdkuid_embedded_decimal() {
+        let mut buffer = [0u8; FSDK_FSUID_DECIMAL_MAX_LENGTH];
+
+        let cases: [(i64, &[u8]); 8] = [
+            (0, b"0"),
+            (1, b"1"),
+            (-1, b"-1"),
+            (10, b"10"),
+            (-65277, b"-65277"),
+            (113131996488794368, b"113131996488794368"),
+            (i64::MAX, b"9223372036854775807"),
+            (i64::MIN, b"-9223372036854775808"),
+        ];
+
+        for (value, expected) in cases {
+            assert_eq!(fsdkuid_format_decimal(value, &mut buffer).as_bytes(), expected, "[fsdkuid_format_decimal() Error] {} was formatted wrongly", value);
+            assert_eq!(fsdkuid_parse_decimal(expected), Ok(value), "[fsdkuid_parse_decimal() Error] {} didn't round-trip", value);
+        }
+
+        assert_eq!(fsdkuid_parse_decimal(b""), Err(FsdkUidError::Empty));
+        assert_eq!(fsdkuid_parse_decimal(b"-"), Err(FsdkUidError::Empty));
+        assert_eq!(fsdkuid_parse_decimal(b"-0"), Err(FsdkUidError::NonCanonical));
+        assert_eq!(fsdkuid_parse_decimal(b"007"), Err(FsdkUidError::NonCanonical));
+        assert_eq!(fsdkuid_parse_decimal(b"12a"), Err(FsdkUidError::InvalidCharacter { character: 'a', position: 2 }));
+        assert_eq!(fsdkuid_parse_decimal("12é".as_bytes()), Err(FsdkUidError::InvalidCharacter { character: 'é', position: 2 }), "[fsdkuid_parse_decimal() Error] Non-ascii character must be reported decoded");
+        assert_eq!(fsdkuid_parse_decimal(b"12\xff"), Err(FsdkUidError::InvalidCharacter { character: char::REPLACEMENT_CHARACTER, position: 2 }));
+        assert_eq!(fsdkuid_parse_decimal(b"9223372036854775808"), Err(FsdkUidError::Overflow));
+        assert_eq!(fsdkuid_parse_decimal(b"-9223372036854775809"), Err(FsdkUidError::Overflow));
+    }
+
+    #[test]
+    fn test_fsdkuid_embedded_base62() {
+        let mut buffer = [0u8; FSDK_FSUID_BASE62_MAX_LENGTH];
+
+        // Same symbols as FsdkUid::to_base62()
+        let cases: [(i64, &[u8]); 8] = [
+            (0, b"0"),
+            (61, b"z"),
+            (62, b"10"),
+            (-65277, b"LygHa16A0ZP"),
+            (113131996488794368, b"8M92EuiIHw"),
+            (i64::MAX, b"AzL8n0Y58m7"),
+            (i64::MIN, b"AzL8n0Y58m8"),
+            (-1, b"LygHa16AHYF"),
+        ];
+
+        for (value, expected) in cases {
+            assert_eq!(fsdkuid_format_base62(value, &mut buffer).as_bytes(), expected, "[fsdkuid_format_base62() Error] {} was formatted wrongly", value);
+            assert_eq!(fsdkuid_parse_base62(expected), Ok(value), "[fsdkuid_parse_base62() Error] {} didn't round-trip", value);
+        }
+
+        // Same errors as FsdkUid::from_base62()
+        assert_eq!(fsdkuid_parse_base62(b""), Err(FsdkUidError::Empty));
+        assert_eq!(fsdkuid_parse_base62(b"0A"), Err(FsdkUidError::NonCanonical));
+        assert_eq!(fsdkuid_parse_base62(b"LygHa16AHYG"), Err(FsdkUidError::Overflow));
+        assert_eq!(fsdkuid_parse_base62(b"8M92-uiIHw"), Err(FsdkUidError::InvalidCharacter { character: '-', position: 4 }));
+        assert_eq!(fsdkuid_parse_base62("é".as_bytes()), Err(FsdkUidError::InvalidCharacter { character: 'é', position: 0 }));
+        assert_eq!(fsdkuid_parse_base62("8M92éuiIHw".as_bytes()), Err(FsdkUidError::InvalidCharacter { character: 'é', position: 4 }));
+        assert_eq!(fsdkuid_parse_base62(b"8M92\xffuiIHw"), Err(FsdkUidError::InvalidCharacter { character: char::REPLACEMENT_CHARACTER, position: 4 }));
+    }
+}
@@ -0,0 +1,553 @@
+use crate::{FsdkUid, FsdkUidError};
+
+pub(crate) const FSDK_FSUID_BASE62_ALPHABET: &[u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz"; // Symbols used by the base62 encoding, sorted by ascii value so the digits order matches the value order
+
+pub(crate) const FSDK_FSUID_BASE58_ALPHABET: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz"; // Symbols used by the Base58 encoding (bitcoin alphabet), excluding 0, O, I and l
+
+pub(crate) const FSDK_FSUID_DECIMAL_ALPHABET: &[u8] = b"0123456789"; // Symbols used by the decimal encodings
+
+pub(crate) const FSDK_FSUID_CROCKFORD_BASE32_ALPHABET: &[u8] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ"; // Symbols used by the Crockford Base32 encoding, excluding I, L, O and U to avoid confusion when communicated by humans
+
+// Text representation of a FSUID, implemented by every encoding so callers can be generic over the chosen format
+pub trait Encoding {
+    const NAME: &'static str; // Lowercase name identifying the encoding
+    const ALPHABET: &'static [u8]; // Symbols written by the encoding, also used for the check symbol
+    const CASE_INSENSITIVE: bool = false; // Whether lowercase and uppercase symbols are read as the same symbol
+    const MAX_LENGTH: usize; // Max number of symbols of an encoded FSUID, a buffer of this length always fits encode_into()
+
+    // Writes the encoded FSUID at the start of the buffer without allocating and returns the written text
+    fn encode_into<'a>(fsuid: &FsdkUid, buffer: &'a mut [u8]) -> Result<&'a str, FsdkUidError>;
+    fn decode(text: &str) -> Result<FsdkUid, FsdkUidError>;
+
+    fn encode(fsuid: &FsdkUid) -> String {
+        let mut buffer = vec![0u8; Self::MAX_LENGTH];
+
+        let length = match Self::encode_into(fsuid, &mut buffer) {
+            Ok(text) => text.len(),
+            Err(error) => panic!("[ERROR in Encoding.encode()] FSUID {} encoding should fit in {} symbols, but it failed with: {}!", Self::NAME, Self::MAX_LENGTH, error),
+        };
+
+        // The buffer is reused as the text, keeping a single allocation per encoded FSUID
+        buffer.truncate(length);
+        String::from_utf8(buffer).unwrap_or_default()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Base62;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Base58;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CrockfordBase32;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Base64Url;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Sortable;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FixedDecimal;
+
+impl Encoding for Base62 {
+    const NAME: &'static str = "base62";
+    const ALPHABET: &'static [u8] = FSDK_FSUID_BASE62_ALPHABET;
+    const MAX_LENGTH: usize = 11;
+
+    fn encode_into<'a>(fsuid: &FsdkUid, buffer: &'a mut [u8]) -> Result<&'a str, FsdkUidError> {
+        fsdkuid_encode_radix_into(fsuid.i64() as u64, FSDK_FSUID_BASE62_ALPHABET, 0, buffer)
+    }
+
+    fn decode(text: &str) -> Result<FsdkUid, FsdkUidError> {
+        fsdkuid_decode_radix(text, FSDK_FSUID_BASE62_ALPHABET).map(|value| FsdkUid::new(value as i64))
+    }
+}
+
+impl Encoding for Base58 {
+    const NAME: &'static str = "base58";
+    const ALPHABET: &'static [u8] = FSDK_FSUID_BASE58_ALPHABET;
+    const MAX_LENGTH: usize = 11;
+
+    fn encode_into<'a>(fsuid: &FsdkUid, buffer: &'a mut [u8]) -> Result<&'a str, FsdkUidError> {
+        fsdkuid_encode_radix_into(fsuid.i64() as u64, FSDK_FSUID_BASE58_ALPHABET, 0, buffer)
+    }
+
+    fn decode(text: &str) -> Result<FsdkUid, FsdkUidError> {
+        fsdkuid_decode_radix(text, FSDK_FSUID_BASE58_ALPHABET).map(|value| FsdkUid::new(value as i64))
+    }
+}
+
+impl Encoding for CrockfordBase32 {
+    const NAME: &'static str = "base32";
+    const ALPHABET: &'static [u8] = FSDK_FSUID_CROCKFORD_BASE32_ALPHABET;
+    const CASE_INSENSITIVE: bool = true;
+    const MAX_LENGTH: usize = 13;
+
+    fn encode_into<'a>(fsuid: &FsdkUid, buffer: &'a mut [u8]) -> Result<&'a str, FsdkUidError> {
+        fsdkuid_encode_radix_into(fsuid.i64() as u64, FSDK_FSUID_CROCKFORD_BASE32_ALPHABET, 0, buffer)
+    }
+
+    fn decode(text: &str) -> Result<FsdkUid, FsdkUidError> {
+        fsdkuid_decode_crockford_base32(text).map(|value| FsdkUid::new(value as i64))
+    }
+}
+
+// Unpadded base64url of the 8 big-endian bytes of the FSUID, always 11 characters long
+impl Encoding for Base64Url {
+    const NAME: &'static str = "base64url";
+    const ALPHABET: &'static [u8] = FSDK_FSUID_BASE64URL_ALPHABET;
+    const MAX_LENGTH: usize = 11;
+
+    fn encode_into<'a>(fsuid: &FsdkUid, buffer: &'a mut [u8]) -> Result<&'a str, FsdkUidError> {
+        fsdkuid_encode_base64url_bytes_into(&fsuid.i64().to_be_bytes(), buffer)
+    }
+
+    fn decode(text: &str) -> Result<FsdkUid, FsdkUidError> {
+        let bytes: [u8; 8] = fsdkuid_decode_base64url_bytes(text)?.try_into().map_err(|_| FsdkUidError::InvalidLength)?;
+        Ok(FsdkUid::new(i64::from_be_bytes(bytes)))
+    }
+}
+
+pub(crate) const FSDK_FSUID_SORTABLE_LENGTH: usize = 11; // Number of Base62 digits needed to represent 64 bits, every sortable text has this length
+
+// Fixed-length Base62 (the alphabet is in ascii order) of the FSUID with its sign bit flipped, so comparing texts byte by byte gives the same order as comparing the FSUID's
+impl Encoding for Sortable {
+    const NAME: &'static str = "sortable";
+    const ALPHABET: &'static [u8] = FSDK_FSUID_BASE62_ALPHABET;
+    const MAX_LENGTH: usize = FSDK_FSUID_SORTABLE_LENGTH;
+
+    fn encode_into<'a>(fsuid: &FsdkUid, buffer: &'a mut [u8]) -> Result<&'a str, FsdkUidError> {
+        fsdkuid_encode_radix_into(fsuid.i64() as u64 ^ (1 << 63), FSDK_FSUID_BASE62_ALPHABET, FSDK_FSUID_SORTABLE_LENGTH, buffer)
+    }
+
+    fn decode(text: &str) -> Result<FsdkUid, FsdkUidError> {
+        if text.len() != FSDK_FSUID_SORTABLE_LENGTH {
+            return Err(if text.is_empty() { FsdkUidError::Empty } else { FsdkUidError::InvalidLength });
+        }
+
+        let digits = text.trim_start_matches('0');
+        let offset = text.len() - digits.len();
+        let value = match digits {
+            "" => 0,
+            digits => fsdkuid_decode_radix(digits, FSDK_FSUID_BASE62_ALPHABET).map_err(|error| match error {
+                FsdkUidError::InvalidCharacter { character, position } => FsdkUidError::InvalidCharacter { character, position: position + offset },
+                error => error,
+            })?,
+        };
+
+        Ok(FsdkUid::new((value ^ (1 << 63)) as i64))
+    }
+}
+
+pub(crate) const FSDK_FSUID_FIXED_DECIMAL_LENGTH: usize = 20; // Number of decimal digits needed to represent 64 bits, every fixed decimal text has this length
+
+// Zero padded decimal of the 64 bits of the FSUID read as unsigned, so texts align in logs and sort as text in spreadsheets and CSV pipelines
+impl Encoding for FixedDecimal {
+    const NAME: &'static str = "decimal20";
+    const ALPHABET: &'static [u8] = FSDK_FSUID_DECIMAL_ALPHABET;
+    const MAX_LENGTH: usize = FSDK_FSUID_FIXED_DECIMAL_LENGTH;
+
+    fn encode_into<'a>(fsuid: &FsdkUid, buffer: &'a mut [u8]) -> Result<&'a str, FsdkUidError> {
+        fsdkuid_encode_radix_into(fsuid.i64() as u64, FSDK_FSUID_DECIMAL_ALPHABET, FSDK_FSUID_FIXED_DECIMAL_LENGTH, buffer)
+    }
+
+    fn decode(text: &str) -> Result<FsdkUid, FsdkUidError> {
+        if text.len() != FSDK_FSUID_FIXED_DECIMAL_LENGTH {
+            return Err(if text.is_empty() { FsdkUidError::Empty } else { FsdkUidError::InvalidLength });
+        }
+
+        let mut value: u64 = 0;
+
+        for (position, character) in text.chars().enumerate() {
+            let digit = character.to_digit(10).ok_or(FsdkUidError::InvalidCharacter { character, position })?;
+            value = value
+                .checked_mul(10)
+                .and_then(|value| value.checked_add(digit as u64))
+                .ok_or(FsdkUidError::Overflow)?;
+        }
+
+        Ok(FsdkUid::new(value as i64))
+    }
+}
+
+// Luhn mod N check symbol of an encoded text, detects every single symbol substitution and almost every adjacent symbols transposition
+pub(crate) fn fsdkuid_check_symbol(text: &str, alphabet: &[u8]) -> u8 {
+    let radix = alphabet.len();
+    let mut factor = 2;
+    let mut sum = 0;
+
+    for character in text.bytes().rev() {
+        let digit = alphabet.iter().position(|&symbol| symbol == character).unwrap_or_default();
+        let addend = factor * digit;
+        sum += addend / radix + addend % radix;
+        factor = if factor == 2 { 1 } else { 2 };
+    }
+
+    alphabet[(radix - sum % radix) % radix]
+}
+
+pub(crate) fn fsdkuid_append_check_symbol(mut text: String, alphabet: &[u8]) -> String {
+    let check_symbol = fsdkuid_check_symbol(&text, alphabet);
+    text.push(check_symbol as char);
+    text
+}
+
+// Splits the check symbol of a text, decodes the remaining text and verifies the check symbol against its canonical encoding
+pub(crate) fn fsdkuid_verify_check_symbol(
+    text: &str,
+    alphabet: &[u8],
+    case_insensitive: bool,
+    decode: impl Fn(&str) -> Result<FsdkUid, FsdkUidError>,
+    encode: impl Fn(&FsdkUid) -> String,
+) -> Result<FsdkUid, FsdkUidError> {
+    let check_position = text.char_indices().last().map(|(position, _)| position).ok_or(FsdkUidError::Empty)?;
+    let (body, check_symbol) = text.split_at(check_position);
+    let fsuid = decode(body)?;
+
+    let expected = fsdkuid_check_symbol(&encode(&fsuid), alphabet) as char;
+    let provided = check_symbol.chars().next().unwrap_or_default();
+
+    if provided == expected || (case_insensitive && provided.eq_ignore_ascii_case(&expected)) {
+        Ok(fsuid)
+    } else {
+        Err(FsdkUidError::ChecksumMismatch)
+    }
+}
+
+const FSDK_FSUID_RADIX_MAX_LENGTH: usize = 64; // Max number of digits of 64 bits in a positional encoding (radix 2)
+
+// Writes the digits of the value at the end of the array and returns the position of the first digit
+fn fsdkuid_radix_digits(value: u64, alphabet: &[u8], digits: &mut [u8; FSDK_FSUID_RADIX_MAX_LENGTH]) -> usize {
+    let radix = alphabet.len() as u64;
+    let mut position = digits.len();
+    let mut remaining = value;
+
+    loop {
+        position -= 1;
+        digits[position] = alphabet[(remaining % radix) as usize];
+        remaining /= radix;
+
+        if remaining == 0 {
+            return position;
+        }
+    }
+}
+
+// Encodes the 64 bits of a FSUID as a positional number using each symbol of the alphabet as a digit, without leading zeros
+pub(crate) fn fsdkuid_encode_radix(value: u64, alphabet: &[u8]) -> String {
+    let mut digits = [0u8; FSDK_FSUID_RADIX_MAX_LENGTH];
+    let position = fsdkuid_radix_digits(value, alphabet, &mut digits);
+
+    digits[position..].iter().map(|&digit| digit as char).collect()
+}
+
+// Same as fsdkuid_encode_radix() written at the start of the buffer, left padded with the zero symbol up to width symbols
+pub(crate) fn fsdkuid_encode_radix_into<'a>(value: u64, alphabet: &[u8], width: usize, buffer: &'a mut [u8]) -> Result<&'a str, FsdkUidError> {
+    let mut digits = [0u8; FSDK_FSUID_RADIX_MAX_LENGTH];
+    let position = fsdkuid_radix_digits(value, alphabet, &mut digits);
+    let length = (digits.len() - position).max(width);
+    let padding = length - (digits.len() - position);
+
+    if buffer.len() < length {
+        return Err(FsdkUidError::BufferTooSmall);
+    }
+
+    buffer[..padding].fill(alphabet[0]);
+    buffer[padding..length].copy_from_slice(&digits[position..]);
+
+    Ok(std::str::from_utf8(&buffer[..length]).unwrap_or_default())
+}
+
+// Number of symbols of the longest FSUID encoded with fsdkuid_encode_radix()
+pub(crate) fn fsdkuid_radix_max_length(alphabet: &[u8]) -> usize {
+    let mut digits = [0u8; FSDK_FSUID_RADIX_MAX_LENGTH];
+    digits.len() - fsdkuid_radix_digits(u64::MAX, alphabet, &mut digits)
+}
+
+// Decodes a positional number encoded with fsdkuid_encode_radix(), rejecting leading zeros and values greater than 64 bits
+pub(crate) fn fsdkuid_decode_radix(text: &str, alphabet: &[u8]) -> Result<u64, FsdkUidError> {
+    fsdkuid_decode_radix_with(text, alphabet.len() as u64, |character| {
+        alphabet.iter().position(|&symbol| symbol as char == character).map(|digit| digit as u64)
+    })
+}
+
+// Decodes a Crockford Base32 text, case-insensitive and reading I and L as 1 and O as 0
+pub(crate) fn fsdkuid_decode_crockford_base32(text: &str) -> Result<u64, FsdkUidError> {
+    fsdkuid_decode_radix_with(text, 32, |character| {
+        let character = match character.to_ascii_uppercase() {
+            'I' | 'L' => '1',
+            'O' => '0',
+            character => character,
+        };
+
+        FSDK_FSUID_CROCKFORD_BASE32_ALPHABET.iter().position(|&symbol| symbol as char == character).map(|digit| digit as u64)
+    })
+}
+
+fn fsdkuid_decode_radix_with(text: &str, radix: u64, digit_of: impl Fn(char) -> Option<u64>) -> Result<u64, FsdkUidError> {
+    if text.is_empty() {
+        return Err(FsdkUidError::Empty);
+    }
+
+    let mut value: u64 = 0;
+
+    for (position, character) in text.chars().enumerate() {
+        let digit = digit_of(character).ok_or(FsdkUidError::InvalidCharacter { character, position })?;
+
+        if position == 0 && digit == 0 && text.len() > 1 {
+            return Err(FsdkUidError::NonCanonical);
+        }
+
+        value = value
+            .checked_mul(radix)
+            .and_then(|value| value.checked_add(digit))
+            .ok_or(FsdkUidError::Overflow)?;
+    }
+
+    Ok(value)
+}
+
+pub(crate) const FSDK_FSUID_BASE64URL_ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_"; // Symbols used by the unpadded base64url encoding (RFC 4648 section 5)
+
+pub(crate) fn fsdkuid_encode_base64url_bytes(bytes: &[u8]) -> String {
+    let mut buffer = vec![0u8; (bytes.len() * 4).div_ceil(3)];
+    let length = fsdkuid_encode_base64url_bytes_into(bytes, &mut buffer).map(str::len).unwrap_or_default();
+
+    buffer.truncate(length);
+    String::from_utf8(buffer).unwrap_or_default()
+}
+
+pub(crate) fn fsdkuid_encode_base64url_bytes_into<'a>(bytes: &[u8], buffer: &'a mut [u8]) -> Result<&'a str, FsdkUidError> {
+    let length = (bytes.len() * 4).div_ceil(3);
+
+    if buffer.len() < length {
+        return Err(FsdkUidError::BufferTooSmall);
+    }
+
+    let mut position = 0;
+    for chunk in bytes.chunks(3) {
+        let group = (chunk[0] as u32) << 16 | (*chunk.get(1).unwrap_or(&0) as u32) << 8 | *chunk.get(2).unwrap_or(&0) as u32;
+
+        for index in 0..=chunk.len() {
+            buffer[position] = FSDK_FSUID_BASE64URL_ALPHABET[(group >> (18 - 6 * index) & 0x3f) as usize];
+            position += 1;
+        }
+    }
+
+    Ok(std::str::from_utf8(&buffer[..length]).unwrap_or_default())
+}
+
+// Decodes an unpadded base64url text, rejecting padding, impossible lengths and unused bits set (which would allow several texts for the same bytes)
+pub(crate) fn fsdkuid_decode_base64url_bytes(text: &str) -> Result<Vec<u8>, FsdkUidError> {
+    if text.is_empty() {
+        return Err(FsdkUidError::Empty);
+    }
+
+    if text.len() % 4 == 1 {
+        return Err(FsdkUidError::InvalidLength);
+    }
+
+    let mut bytes = Vec::with_capacity(text.len() * 3 / 4);
+    let mut group: u32 = 0;
+    let mut group_bits = 0;
+
+    for (position, character) in text.chars().enumerate() {
+        let digit = FSDK_FSUID_BASE64URL_ALPHABET
+            .iter()
+            .position(|&symbol| symbol as char == character)
+            .ok_or(FsdkUidError::InvalidCharacter { character, position })?;
+
+        group = (group << 6) | digit as u32;
+        group_bits += 6;
+
+        if group_bits >= 8 {
+            group_bits -= 8;
+            bytes.push((group >> group_bits) as u8);
+            group &= (1 << group_bits) - 1;
+        }
+    }
+
+    if group != 0 {
+        return Err(FsdkUidError::NonCanonical);
+    }
+
+    Ok(bytes)
+}
+
+// User defined symbol set for positional FSUID encodings, ex: excluding ambiguous or profanity-forming characters
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FsdkAlphabet {
+    symbols: Vec<u8>,
+}
+
+impl FsdkAlphabet {
+    pub fn new(symbols: &str) -> Self {
+        if symbols.len() < 2 || !symbols.bytes().all(|symbol| symbol.is_ascii_graphic()) {
+            panic!("[ERROR in FsdkAlphabet.new()] FSUID alphabet should contain at least 2 visible ascii symbols, but {:?} was specified!", symbols);
+        }
+
+        let mut seen = [false; 128];
+        for symbol in symbols.bytes() {
+            if seen[symbol as usize] {
+                panic!("[ERROR in FsdkAlphabet.new()] FSUID alphabet should contain unique symbols, but '{}' is repeated!", symbol as char);
+            }
+
+            seen[symbol as usize] = true;
+        }
+
+        FsdkAlphabet { symbols: symbols.as_bytes().to_vec() }
+    }
+
+    pub fn symbols(&self) -> &str {
+        std::str::from_utf8(&self.symbols).unwrap_or_default()
+    }
+
+    // Same canonical rules of the Base62 encoding: no leading zero symbol and no overflow
+    pub fn encode(&self, fsuid: &FsdkUid) -> String {
+        fsdkuid_encode_radix(fsuid.i64() as u64, &self.symbols)
+    }
+
+    pub fn encode_into<'a>(&self, fsuid: &FsdkUid, buffer: &'a mut [u8]) -> Result<&'a str, FsdkUidError> {
+        fsdkuid_encode_radix_into(fsuid.i64() as u64, &self.symbols, 0, buffer)
+    }
+
+    // Max number of symbols of an encoded FSUID, a buffer of this length always fits encode_into()
+    pub fn max_length(&self) -> usize {
+        fsdkuid_radix_max_length(&self.symbols)
+    }
+
+    pub fn decode(&self, text: &str) -> Result<FsdkUid, FsdkUidError> {
+        fsdkuid_decode_radix(text, &self.symbols).map(|value| FsdkUid::new(value as i64))
+    }
+
+    pub fn encode_checked(&self, fsuid: &FsdkUid) -> String {
+        fsdkuid_append_check_symbol(self.encode(fsuid), &self.symbols)
+    }
+
+    pub fn decode_checked(&self, text: &str) -> Result<FsdkUid, FsdkUidError> {
+        fsdkuid_verify_check_symbol(text, &self.symbols, false, |body| self.decode(body), |fsuid| self.encode(fsuid))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fsdkuid_custom_alphabet() {
+        let alphabet = FsdkAlphabet::new("23456789bcdfghjkmnpqrstvwxyz");
+        let fsuid = FsdkUid::new(113131996488794368);
+
+        let text = alphabet.encode(&fsuid);
+        assert!(text.chars().all(|character| alphabet.symbols().contains(character)), "[FsdkAlphabet.encode() Error] Encoded FSUID {} contains symbols outside of the alphabet", text);
+        assert_eq!(alphabet.decode(&text), Ok(fsuid), "[FsdkAlphabet.decode() Error] FSUID didn't round-trip through the custom alphabet");
+        assert!(alphabet.decode(&FsdkUid::new(-1).to_base62()).is_err());
+        assert_eq!(alphabet.decode("22"), Err(FsdkUidError::NonCanonical));
+
+        let base62 = FsdkAlphabet::new(std::str::from_utf8(FSDK_FSUID_BASE62_ALPHABET).unwrap());
+        assert_eq!(base62.encode(&fsuid), fsuid.to_base62(), "[FsdkAlphabet.encode() Error] The Base62 alphabet must encode as FsdkUid.to_base62()");
+    }
+
+    #[test]
+    fn test_fsdkuid_encode_into() {
+        let mut buffer = [0u8; 32];
+
+        for value in [0, 1, 65535, 113131996488794368, i64::MAX, i64::MIN, -1] {
+            let fsuid = FsdkUid::new(value);
+            assert_eq!(Base62::encode_into(&fsuid, &mut buffer), Ok(Base62::encode(&fsuid).as_str()), "[Base62::encode_into() Error] FSUID {} must be written as Base62::encode()", value);
+            assert_eq!(Base58::encode_into(&fsuid, &mut buffer), Ok(Base58::encode(&fsuid).as_str()));
+            assert_eq!(CrockfordBase32::encode_into(&fsuid, &mut buffer), Ok(CrockfordBase32::encode(&fsuid).as_str()));
+            assert_eq!(Base64Url::encode_into(&fsuid, &mut buffer), Ok(Base64Url::encode(&fsuid).as_str()));
+            assert_eq!(Sortable::encode_into(&fsuid, &mut buffer), Ok(Sortable::encode(&fsuid).as_str()));
+            assert_eq!(FixedDecimal::encode_into(&fsuid, &mut buffer), Ok(FixedDecimal::encode(&fsuid).as_str()));
+        }
+
+        let fsuid = FsdkUid::new(-1);
+        assert_eq!(Base62::encode_into(&fsuid, &mut buffer).map(str::len), Ok(Base62::MAX_LENGTH), "[Base62::encode_into() Error] The longest FSUID must use MAX_LENGTH symbols");
+        assert_eq!(Base58::encode_into(&fsuid, &mut buffer).map(str::len), Ok(Base58::MAX_LENGTH));
+        assert_eq!(CrockfordBase32::encode_into(&fsuid, &mut buffer).map(str::len), Ok(CrockfordBase32::MAX_LENGTH));
+        assert_eq!(Base64Url::encode_into(&fsuid, &mut buffer).map(str::len), Ok(Base64Url::MAX_LENGTH));
+        assert_eq!(Base62::encode_into(&fsuid, &mut buffer[..Base62::MAX_LENGTH - 1]), Err(FsdkUidError::BufferTooSmall), "[Base62::encode_into() Error] Too small buffers must be rejected");
+        assert_eq!(Base64Url::encode_into(&fsuid, &mut buffer[..4]), Err(FsdkUidError::BufferTooSmall));
+
+        let alphabet = FsdkAlphabet::new("01");
+        assert_eq!(alphabet.max_length(), 64);
+        assert_eq!(alphabet.encode_into(&FsdkUid::new(5), &mut buffer), Ok("101"));
+        assert_eq!(alphabet.encode_into(&fsuid, &mut buffer), Err(FsdkUidError::BufferTooSmall));
+    }
+
+    #[test]
+    fn test_fsdkuid_sortable() {
+        let values = [i64::MIN, -65277, -1, 0, 1, 61, 62, 65535, 113131996488794368, 113131996488794624, i64::MAX];
+
+        let texts: Vec<String> = values.iter().map(|&value| FsdkUid::new(value).encode::<Sortable>()).collect();
+        for (value, text) in values.iter().zip(&texts) {
+            assert_eq!(text.len(), FSDK_FSUID_SORTABLE_LENGTH, "[Sortable::encode() Error] Sortable text {} must have a fixed length", text);
+            assert_eq!(Sortable::decode(text), Ok(FsdkUid::new(*value)), "[Sortable::decode() Error] FSUID {} didn't round-trip", value);
+        }
+
+        assert!(texts.windows(2).all(|pair| pair[0] < pair[1]), "[Sortable::encode() Error] Sortable texts must sort in the same order as the FSUID's: {:?}", texts);
+        assert_eq!(Sortable::encode(&FsdkUid::new(i64::MIN)), "00000000000");
+        assert_eq!(Sortable::decode("0000000000"), Err(FsdkUidError::InvalidLength));
+        assert_eq!(Sortable::decode("zzzzzzzzzzz"), Err(FsdkUidError::Overflow));
+        assert_eq!(Sortable::decode("000000000-0"), Err(FsdkUidError::InvalidCharacter { character: '-', position: 9 }));
+    }
+
+    #[test]
+    fn test_fsdkuid_fixed_decimal() {
+        assert_eq!(FixedDecimal::encode(&FsdkUid::new(113131996488794368)), "00113131996488794368");
+        assert_eq!(FixedDecimal::encode(&FsdkUid::new(0)), "00000000000000000000");
+        assert_eq!(FixedDecimal::encode(&FsdkUid::new(-1)), "18446744073709551615");
+
+        for value in [0, 1, 113131996488794368, i64::MAX, i64::MIN, -1] {
+            let fsuid = FsdkUid::new(value);
+            assert_eq!(FixedDecimal::decode(&FixedDecimal::encode(&fsuid)), Ok(fsuid), "[FixedDecimal::decode() Error] FSUID {} didn't round-trip", value);
+        }
+
+        assert_eq!(FixedDecimal::decode("113131996488794368"), Err(FsdkUidError::InvalidLength));
+        assert_eq!(FixedDecimal::decode("18446744073709551616"), Err(FsdkUidError::Overflow));
+        assert_eq!(FixedDecimal::decode("-0113131996488794368"), Err(FsdkUidError::InvalidCharacter { character: '-', position: 0 }));
+    }
+
+    #[test]
+    fn test_fsdkuid_check_symbol() {
+        assert_eq!(fsdkuid_check_symbol("7992739871", FSDK_FSUID_DECIMAL_ALPHABET), b'3', "[fsdkuid_check_symbol() Error] Luhn mod 10 check digit of 7992739871 must be 3");
+
+        let fsuid = FsdkUid::new(113131996488794368);
+        let text = fsuid.encode_checked::<Base62>();
+        assert_eq!(&text[..text.len() - 1], fsuid.to_base62(), "[FsdkUid.encode_checked() Error] The check symbol must be appended to the encoded FSUID");
+        assert_eq!(FsdkUid::decode_checked::<Base62>(&text), Ok(fsuid));
+
+        let mut substituted = text.clone().into_bytes();
+        substituted[3] = if substituted[3] == b'a' { b'b' } else { b'a' };
+        assert_eq!(FsdkUid::decode_checked::<Base62>(std::str::from_utf8(&substituted).unwrap()), Err(FsdkUidError::ChecksumMismatch), "[FsdkUid::decode_checked() Error] A substituted symbol must be detected");
+
+        let mut transposed = text.clone().into_bytes();
+        transposed.swap(1, 2);
+        assert_eq!(FsdkUid::decode_checked::<Base62>(std::str::from_utf8(&transposed).unwrap()), Err(FsdkUidError::ChecksumMismatch), "[FsdkUid::decode_checked() Error] Transposed symbols must be detected");
+
+        let crockford = fsuid.encode_checked::<CrockfordBase32>();
+        assert_eq!(FsdkUid::decode_checked::<CrockfordBase32>(&crockford.to_lowercase().replace('0', "o")), Ok(fsuid), "[FsdkUid::decode_checked() Error] Crockford check symbol must be verified case-insensitive and with aliases");
+
+        for value in [0, 1, i64::MAX, i64::MIN, -1] {
+            let fsuid = FsdkUid::new(value);
+            assert_eq!(FsdkUid::decode_checked::<Base58>(&fsuid.encode_checked::<Base58>()), Ok(fsuid));
+            assert_eq!(FsdkUid::decode_checked::<Base64Url>(&fsuid.encode_checked::<Base64Url>()), Ok(fsuid));
+            assert_eq!(FsdkUid::decode_checked::<Sortable>(&fsuid.encode_checked::<Sortable>()), Ok(fsuid));
+            assert_eq!(FsdkUid::decode_checked::<FixedDecimal>(&fsuid.encode_checked::<FixedDecimal>()), Ok(fsuid));
+        }
+
+        let alphabet = FsdkAlphabet::new("23456789bcdfghjkmnpqrstvwxyz");
+        assert_eq!(alphabet.decode_checked(&alphabet.encode_checked(&fsuid)), Ok(fsuid));
+        assert_eq!(FsdkUid::decode_checked::<Base62>(""), Err(FsdkUidError::Empty));
+        assert_eq!(FsdkUid::decode_checked::<Base62>("8"), Err(FsdkUidError::Empty));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_fsdkuid_custom_alphabet_rejects_repeated_symbols() {
+        FsdkAlphabet::new("0123456780");
+    }
+}
@@ -0,0 +1,154 @@
+use chrono::{DateTime, TimeDelta, Utc};
+
+use crate::FsdkUid;
+
+const FSDK_FSUID_ERA_CLOCK_TOLERANCE: TimeDelta = TimeDelta::minutes(5); // Accepted clock drift between nodes when rejecting identifiers embedding a future datetime
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FsdkUidScheme {
+    Fsuid, // FSUID layout of this library
+    Snowflake { epoch_milliseconds: i64, timestamp_shift: u8 }, // Snowflake-style identifier with the milliseconds since the epoch above the lowest timestamp_shift bits
+    Sequential, // Identifier without embedded datetime (ex: database auto-increment)
+}
+
+// Identifier scheme used during a period of time, the value range and cutover dates are used to tell eras apart when identifiers are mixed
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FsdkUidEra {
+    pub label: String,
+    pub scheme: FsdkUidScheme,
+    pub min_value: i64,
+    pub max_value: i64,
+    pub from: Option<DateTime<Utc>>, // Cutover date when the era started, identifiers embedding an earlier datetime don't belong to it
+    pub until: Option<DateTime<Utc>>, // Cutover date when the era ended, identifiers embedding this or a later datetime don't belong to it (identifiers embedding a future datetime never do)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum FsdkUidEraConfidence {
+    None, // No era matches the identifier
+    Low, // Several eras match the identifier, the first configured one was chosen
+    Medium, // A single era matches the identifier by value range, but it doesn't embed a datetime to confirm it
+    High, // A single era matches the identifier by value range and embedded datetime
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FsdkUidEraDetection {
+    pub value: i64,
+    pub era: Option<String>,
+    pub confidence: FsdkUidEraConfidence,
+    pub utc_datetime: Option<DateTime<Utc>>, // Datetime embedded in the identifier according to the detected era
+}
+
+impl FsdkUidEra {
+    // Datetime embedded in the value according to the era scheme, None for schemes without datetime or values outside the datetime range
+    pub fn utc_datetime(&self, value: i64) -> Option<DateTime<Utc>> {
+        match self.scheme {
+            FsdkUidScheme::Fsuid => Some(FsdkUid::new(value).utc_datetime()),
+            FsdkUidScheme::Snowflake { epoch_milliseconds, timestamp_shift } => {
+                let milliseconds = epoch_milliseconds.checked_add(value.checked_shr(timestamp_shift as u32)?)?;
+                DateTime::<Utc>::from_timestamp_millis(milliseconds)
+            }
+            FsdkUidScheme::Sequential => None,
+        }
+    }
+
+    fn matches(&self, value: i64) -> Option<Option<DateTime<Utc>>> {
+        if value < self.min_value || value > self.max_value {
+            return None;
+        }
+
+        if self.scheme == FsdkUidScheme::Sequential {
+            return Some(None);
+        }
+
+        let utc_datetime = self.utc_datetime(value)?;
+        let after_from = self.from.is_none_or(|from| utc_datetime >= from);
+        let before_until = self.until.is_none_or(|until| utc_datetime < until);
+        let not_future = utc_datetime <= Utc::now() + FSDK_FSUID_ERA_CLOCK_TOLERANCE;
+
+        (after_from && before_until && not_future).then_some(Some(utc_datetime))
+    }
+}
+
+// Labels identifiers read from mixed sources (ex: log files spanning a migration) with the era they most likely belong to
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FsdkUidEraDetector {
+    eras: Vec<FsdkUidEra>,
+}
+
+impl FsdkUidEraDetector {
+    pub fn new(eras: Vec<FsdkUidEra>) -> Self {
+        FsdkUidEraDetector { eras }
+    }
+
+    pub fn eras(&self) -> &[FsdkUidEra] {
+        &self.eras
+    }
+
+    pub fn detect(&self, value: i64) -> FsdkUidEraDetection {
+        let mut candidates = self.eras.iter().filter_map(|era| era.matches(value).map(|utc_datetime| (era, utc_datetime)));
+
+        let Some((era, utc_datetime)) = candidates.next() else {
+            return FsdkUidEraDetection { value, era: None, confidence: FsdkUidEraConfidence::None, utc_datetime: None };
+        };
+
+        let confidence = match (candidates.next(), utc_datetime) {
+            (Some(_), _) => FsdkUidEraConfidence::Low,
+            (None, None) => FsdkUidEraConfidence::Medium,
+            (None, Some(_)) => FsdkUidEraConfidence::High,
+        };
+
+        FsdkUidEraDetection { value, era: Some(era.label.clone()), confidence, utc_datetime }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn detector() -> FsdkUidEraDetector {
+        let snowflake_start = Utc.with_ymd_and_hms(2016, 1, 1, 0, 0, 0).unwrap();
+        let cutover = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+
+        FsdkUidEraDetector::new(vec![
+            FsdkUidEra { label: "legacy-autoincrement".to_string(), scheme: FsdkUidScheme::Sequential, min_value: 1, max_value: 99_999_999, from: None, until: None },
+            FsdkUidEra {
+                label: "discord-snowflake".to_string(),
+                scheme: FsdkUidScheme::Snowflake { epoch_milliseconds: 1420070400000, timestamp_shift: 22 },
+                min_value: 1,
+                max_value: i64::MAX,
+                from: Some(snowflake_start),
+                until: Some(cutover),
+            },
+            FsdkUidEra { label: "fsuid".to_string(), scheme: FsdkUidScheme::Fsuid, min_value: 1, max_value: i64::MAX, from: Some(cutover), until: None },
+        ])
+    }
+
+    #[test]
+    fn test_fsdkuid_era_detection() {
+        let detector = detector();
+
+        let fsuid = detector.detect(113131996488794368);
+        assert_eq!(fsuid.era.as_deref(), Some("fsuid"), "[FsdkUidEraDetector.detect() Error] FSUID generated after the cutover must be detected as fsuid");
+        assert_eq!(fsuid.confidence, FsdkUidEraConfidence::High);
+        assert_eq!(fsuid.utc_datetime, Some(FsdkUid::new(113131996488794368).utc_datetime()));
+
+        let snowflake = detector.detect(175928847299117063);
+        assert_eq!(snowflake.era.as_deref(), Some("discord-snowflake"), "[FsdkUidEraDetector.detect() Error] Discord snowflake generated before the cutover must be detected as discord-snowflake");
+        assert_eq!(snowflake.confidence, FsdkUidEraConfidence::High);
+        assert_eq!(snowflake.utc_datetime, Some(Utc.timestamp_millis_opt(1462015105796).unwrap()));
+
+        let sequential = detector.detect(4242);
+        assert_eq!(sequential.era.as_deref(), Some("legacy-autoincrement"));
+        assert_eq!(sequential.confidence, FsdkUidEraConfidence::Medium, "[FsdkUidEraDetector.detect() Error] Values only matched by value range must have medium confidence");
+        assert_eq!(sequential.utc_datetime, None);
+
+        let ambiguous = FsdkUidEraDetector::new(vec![detector.eras()[2].clone(), FsdkUidEra { label: "fsuid-replica".to_string(), ..detector.eras()[2].clone() }]).detect(113131996488794368);
+        assert_eq!(ambiguous.era.as_deref(), Some("fsuid"), "[FsdkUidEraDetector.detect() Error] The first configured era must be chosen when several match");
+        assert_eq!(ambiguous.confidence, FsdkUidEraConfidence::Low);
+
+        let unknown = detector.detect(-5);
+        assert_eq!(unknown.era, None);
+        assert_eq!(unknown.confidence, FsdkUidEraConfidence::None);
+    }
+}
@@ -0,0 +1,52 @@
+use core::fmt;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FsdkUidError {
+    Empty, // The text to parse didn't contain any character
+    InvalidCharacter { character: char, position: usize }, // The text contains a character outside of the encoding alphabet
+    NonCanonical, // The text decodes to a valid FSUID but isn't in its canonical form (ex: leading zeros)
+    Overflow, // The text represents a number that doesn't fit in the 64 bits of a FSUID
+    InvalidLength, // The text or bytes don't have a length possible for the expected format
+    ChecksumMismatch, // The integrity check embedded in the text doesn't match its content, usually a typo or a truncated copy
+    SignatureMismatch, // The HMAC embedded in the text doesn't match its content for the given key, the text was forged or signed with another key
+    BufferTooSmall, // The buffer given to write the encoded FSUID is shorter than the encoded text
+    InvalidUri, // The text isn't a fsuid: URI or urn:fsuid: URN with an encoding name
+    UnknownEncoding, // The encoding name of the text isn't supported by this library
+    InvalidVersion, // The foreign identifier (ex: UUID) isn't of the version or variant FSUID's convert to
+    PrefixMismatch, // The prefixed text doesn't start with the expected prefix
+    RateLimited, // The FSUID issuance quota is exhausted, the FSUID wasn't generated
+    InvalidConfiguration, // The generator configuration has an unknown key or a value outside of its range
+    LeaseLost, // The node identifier lease couldn't be renewed and may be held by another process, the FSUID wasn't generated
+    NodeIdentifierUnavailable, // Every node identifier of the generator manager is assigned or none could be assigned to the key, the FSUID wasn't generated
+    ShutDown, // The generator manager was shut down, the FSUID wasn't generated
+    DuplicateNodeIdentifier, // The node identifier is used by another live generator of the process and the duplicate policy refuses it
+    ClockRollback, // The clock is behind the timestamp watermark persisted before a restart, FSUID's of used timestamp ticks could be generated again
+}
+
+impl fmt::Display for FsdkUidError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FsdkUidError::Empty => write!(f, "FSUID text is empty"),
+            FsdkUidError::InvalidCharacter { character, position } => write!(f, "FSUID text contains the invalid character '{}' at position {}", character, position),
+            FsdkUidError::NonCanonical => write!(f, "FSUID text isn't in its canonical form"),
+            FsdkUidError::Overflow => write!(f, "FSUID text represents a value greater than 64 bits"),
+            FsdkUidError::InvalidLength => write!(f, "FSUID text has an invalid length for its format"),
+            FsdkUidError::ChecksumMismatch => write!(f, "FSUID text checksum doesn't match its content"),
+            FsdkUidError::SignatureMismatch => write!(f, "FSUID text signature doesn't match its content"),
+            FsdkUidError::BufferTooSmall => write!(f, "FSUID buffer is too small for the encoded text"),
+            FsdkUidError::InvalidUri => write!(f, "FSUID text isn't a valid FSUID URI"),
+            FsdkUidError::UnknownEncoding => write!(f, "FSUID text uses an unknown encoding"),
+            FsdkUidError::InvalidVersion => write!(f, "FSUID foreign identifier has an unsupported version"),
+            FsdkUidError::PrefixMismatch => write!(f, "FSUID text doesn't have the expected prefix"),
+            FsdkUidError::RateLimited => write!(f, "FSUID issuance quota is exhausted"),
+            FsdkUidError::InvalidConfiguration => write!(f, "FSUID generator configuration is invalid"),
+            FsdkUidError::LeaseLost => write!(f, "FSUID node identifier lease was lost"),
+            FsdkUidError::NodeIdentifierUnavailable => write!(f, "FSUID node identifier isn't available"),
+            FsdkUidError::ShutDown => write!(f, "FSUID generator manager was shut down"),
+            FsdkUidError::DuplicateNodeIdentifier => write!(f, "FSUID node identifier is already used by another generator of this process"),
+            FsdkUidError::ClockRollback => write!(f, "FSUID clock is behind the persisted timestamp watermark"),
+        }
+    }
+}
+
+impl core::error::Error for FsdkUidError {}
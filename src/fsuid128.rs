@@ -0,0 +1,207 @@
+use std::collections::hash_map::RandomState;
+use std::hash::BuildHasher;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use chrono::{DateTime, Utc};
+
+use crate::encoding::FSDK_FSUID_BASE62_ALPHABET;
+use crate::{FsdkUid, FsdkUidError, FsdkUidGenerator};
+
+const FSDK_FSUID128_RANDOM_BITS: u32 = 64; // Number of random bits below the 64 bits FSUID in a 128 bits FSUID
+
+// 128 bits FSUID: the 64 bits FSUID (timestamp delta, node identifier, node counter) followed by 64 random bits, so FSUID's can't be guessed from a known one
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FsdkUid128 {
+    fsuid128: u128,
+}
+
+impl FsdkUid128 {
+    pub fn new(fsuid128: u128) -> Self {
+        FsdkUid128 { fsuid128 }
+    }
+
+    pub fn from_parts(fsuid: FsdkUid, random: u64) -> Self {
+        FsdkUid128::new(((fsuid.i64() as u64 as u128) << FSDK_FSUID128_RANDOM_BITS) | random as u128)
+    }
+
+    pub fn u128(&self) -> u128 {
+        self.fsuid128
+    }
+
+    // 64 bits FSUID without the random bits, always available but only lossless when random() is 0 (see TryFrom<FsdkUid128> for FsdkUid)
+    pub fn fsuid(&self) -> FsdkUid {
+        FsdkUid::new((self.fsuid128 >> FSDK_FSUID128_RANDOM_BITS) as u64 as i64)
+    }
+
+    pub fn random(&self) -> u64 {
+        self.fsuid128 as u64
+    }
+
+    pub fn timestamp_delta(&self) -> i64 {
+        self.fsuid().timestamp_delta()
+    }
+
+    pub fn node_identifier(&self) -> u8 {
+        self.fsuid().node_identifier()
+    }
+
+    pub fn node_counter(&self) -> u8 {
+        self.fsuid().node_counter()
+    }
+
+    pub fn utc_datetime(&self) -> DateTime<Utc> {
+        self.fsuid().utc_datetime()
+    }
+
+    pub fn to_be_bytes(&self) -> [u8; 16] {
+        self.fsuid128.to_be_bytes()
+    }
+
+    pub fn from_be_bytes(bytes: [u8; 16]) -> Self {
+        FsdkUid128::new(u128::from_be_bytes(bytes))
+    }
+
+    pub fn to_base62(&self) -> String {
+        let mut digits = Vec::with_capacity(22);
+        let mut remaining = self.fsuid128;
+
+        loop {
+            digits.push(FSDK_FSUID_BASE62_ALPHABET[(remaining % 62) as usize] as char);
+            remaining /= 62;
+
+            if remaining == 0 {
+                break;
+            }
+        }
+
+        digits.iter().rev().collect()
+    }
+
+    // Same canonical rules of FsdkUid::from_base62(): no leading zeros and no overflow
+    pub fn from_base62(text: &str) -> Result<Self, FsdkUidError> {
+        if text.is_empty() {
+            return Err(FsdkUidError::Empty);
+        }
+
+        let mut value: u128 = 0;
+
+        for (position, character) in text.chars().enumerate() {
+            let digit = FSDK_FSUID_BASE62_ALPHABET
+                .iter()
+                .position(|&symbol| symbol as char == character)
+                .ok_or(FsdkUidError::InvalidCharacter { character, position })?;
+
+            if position == 0 && digit == 0 && text.len() > 1 {
+                return Err(FsdkUidError::NonCanonical);
+            }
+
+            value = value
+                .checked_mul(62)
+                .and_then(|value| value.checked_add(digit as u128))
+                .ok_or(FsdkUidError::Overflow)?;
+        }
+
+        Ok(FsdkUid128::new(value))
+    }
+}
+
+impl From<FsdkUid> for FsdkUid128 {
+    fn from(fsuid: FsdkUid) -> Self {
+        FsdkUid128::from_parts(fsuid, 0)
+    }
+}
+
+// Only FSUID's without random bits convert back to 64 bits, the others fail with FsdkUidError::Overflow
+impl TryFrom<FsdkUid128> for FsdkUid {
+    type Error = FsdkUidError;
+
+    fn try_from(fsuid128: FsdkUid128) -> Result<Self, Self::Error> {
+        if fsuid128.random() != 0 {
+            return Err(FsdkUidError::Overflow);
+        }
+
+        Ok(fsuid128.fsuid())
+    }
+}
+
+pub struct FsdkUidGenerator128 {
+    generator: FsdkUidGenerator,
+    random_state: RandomState,
+    sequence: AtomicU64,
+}
+
+impl FsdkUidGenerator128 {
+    pub fn new(node_identifier: u8) -> Self {
+        FsdkUidGenerator128 {
+            generator: FsdkUidGenerator::new(node_identifier),
+            random_state: RandomState::new(),
+            sequence: AtomicU64::new(0),
+        }
+    }
+
+    pub fn generate_u128(&self) -> u128 {
+        self.generate_fsuid128().u128()
+    }
+
+    // The random bits are a SipHash, keyed with the 128 bits random key std seeds from the operating system, of a per generator sequence
+    pub fn generate_fsuid128(&self) -> FsdkUid128 {
+        let fsuid = self.generator.generate_fsuid();
+        let sequence = self.sequence.fetch_add(1, Ordering::Relaxed);
+        let random = self.random_state.hash_one((sequence, fsuid.i64()));
+
+        FsdkUid128::from_parts(fsuid, random)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_fsdkuid128_fields() {
+        let fsuid = FsdkUid::new(113131996488794368);
+        let fsuid128 = FsdkUid128::from_parts(fsuid, 0xdead_beef_0123_4567);
+
+        assert_eq!(fsuid128.u128(), 0x0191_ecf3_8f72_0100_dead_beef_0123_4567, "[FsdkUid128::from_parts() Error] FSUID must be in the 64 most significant bits");
+        assert_eq!(fsuid128.fsuid(), fsuid);
+        assert_eq!(fsuid128.random(), 0xdead_beef_0123_4567);
+        assert_eq!((fsuid128.timestamp_delta(), fsuid128.node_identifier(), fsuid128.node_counter()), (1726257270642, 1, 0));
+        assert_eq!(fsuid128.utc_datetime(), fsuid.utc_datetime());
+        assert_eq!(FsdkUid128::from_be_bytes(fsuid128.to_be_bytes()), fsuid128);
+        assert_eq!(FsdkUid128::from_parts(FsdkUid::new(-1), 7).fsuid(), FsdkUid::new(-1));
+    }
+
+    #[test]
+    fn test_fsdkuid128_conversions() {
+        let fsuid = FsdkUid::new(113131996488794368);
+
+        assert_eq!(FsdkUid::try_from(FsdkUid128::from(fsuid)), Ok(fsuid), "[FsdkUid::try_from() Error] FSUID without random bits must convert back losslessly");
+        assert_eq!(FsdkUid::try_from(FsdkUid128::from_parts(fsuid, 1)), Err(FsdkUidError::Overflow), "[FsdkUid::try_from() Error] FSUID with random bits must not convert to 64 bits");
+    }
+
+    #[test]
+    fn test_fsdkuid128_base62() {
+        for value in [0, 61, 62, u64::MAX as u128, u128::MAX, 0x0191_ecf3_8f72_0100_dead_beef_0123_4567] {
+            let fsuid128 = FsdkUid128::new(value);
+            assert_eq!(FsdkUid128::from_base62(&fsuid128.to_base62()), Ok(fsuid128), "[FsdkUid128::from_base62() Error] FSUID {} didn't round-trip", value);
+        }
+
+        assert_eq!(FsdkUid128::new(u128::MAX).to_base62().len(), 22);
+        assert_eq!(FsdkUid128::new(u64::MAX as u128).to_base62(), FsdkUid::new(-1).to_base62());
+        assert_eq!(FsdkUid128::from_base62(""), Err(FsdkUidError::Empty));
+        assert_eq!(FsdkUid128::from_base62("01"), Err(FsdkUidError::NonCanonical));
+        assert_eq!(FsdkUid128::from_base62("zzzzzzzzzzzzzzzzzzzzzz"), Err(FsdkUidError::Overflow));
+    }
+
+    #[test]
+    fn test_fsdkuid_generator128() {
+        let generator = FsdkUidGenerator128::new(7);
+        let fsuids: Vec<FsdkUid128> = (0..1000).map(|_| generator.generate_fsuid128()).collect();
+
+        assert!(fsuids.iter().all(|fsuid128| fsuid128.node_identifier() == 7));
+        assert_eq!(fsuids.iter().map(|fsuid128| fsuid128.random()).collect::<HashSet<u64>>().len(), fsuids.len(), "[FsdkUidGenerator128.generate_fsuid128() Error] Random bits must differ between FSUID's");
+        assert!(fsuids.windows(2).all(|pair| pair[0].fsuid() < pair[1].fsuid()), "[FsdkUidGenerator128.generate_fsuid128() Error] FSUID's must keep the generation order");
+        assert!((fsuids[0].utc_datetime() - Utc::now()).num_seconds().abs() < 5);
+    }
+}
@@ -0,0 +1,138 @@
+// SHA-256 (FIPS 180-4) and HMAC-SHA-256 (RFC 2104), implemented here so tamper-evident FSUID texts don't pull a cryptography dependency
+
+const FSDK_FSUID_SHA256_BLOCK_BYTES: usize = 64; // Number of bytes processed by each SHA-256 compression round
+
+const FSDK_FSUID_SHA256_ROUND_CONSTANTS: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+fn fsdkuid_sha256_compress(state: &mut [u32; 8], block: &[u8]) {
+    let mut schedule = [0u32; 64];
+
+    for (index, word) in block.chunks_exact(4).enumerate() {
+        schedule[index] = u32::from_be_bytes([word[0], word[1], word[2], word[3]]);
+    }
+
+    for index in 16..64 {
+        let sigma0 = schedule[index - 15].rotate_right(7) ^ schedule[index - 15].rotate_right(18) ^ (schedule[index - 15] >> 3);
+        let sigma1 = schedule[index - 2].rotate_right(17) ^ schedule[index - 2].rotate_right(19) ^ (schedule[index - 2] >> 10);
+        schedule[index] = schedule[index - 16].wrapping_add(sigma0).wrapping_add(schedule[index - 7]).wrapping_add(sigma1);
+    }
+
+    let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = *state;
+
+    for (constant, word) in FSDK_FSUID_SHA256_ROUND_CONSTANTS.iter().zip(schedule.iter()) {
+        let sum1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+        let choice = (e & f) ^ (!e & g);
+        let temporary1 = h.wrapping_add(sum1).wrapping_add(choice).wrapping_add(*constant).wrapping_add(*word);
+        let sum0 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+        let majority = (a & b) ^ (a & c) ^ (b & c);
+        let temporary2 = sum0.wrapping_add(majority);
+
+        h = g;
+        g = f;
+        f = e;
+        e = d.wrapping_add(temporary1);
+        d = c;
+        c = b;
+        b = a;
+        a = temporary1.wrapping_add(temporary2);
+    }
+
+    for (word, value) in state.iter_mut().zip([a, b, c, d, e, f, g, h]) {
+        *word = word.wrapping_add(value);
+    }
+}
+
+pub(crate) fn fsdkuid_sha256(parts: &[&[u8]]) -> [u8; 32] {
+    let mut state: [u32; 8] = [0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19];
+    let mut block = [0u8; FSDK_FSUID_SHA256_BLOCK_BYTES];
+    let mut block_length = 0;
+    let mut total_length: u64 = 0;
+
+    for part in parts {
+        for &byte in part.iter() {
+            block[block_length] = byte;
+            block_length += 1;
+
+            if block_length == FSDK_FSUID_SHA256_BLOCK_BYTES {
+                fsdkuid_sha256_compress(&mut state, &block);
+                block_length = 0;
+            }
+        }
+
+        total_length += part.len() as u64;
+    }
+
+    block[block_length] = 0x80;
+    block[block_length + 1..].fill(0);
+
+    if block_length >= FSDK_FSUID_SHA256_BLOCK_BYTES - 8 {
+        fsdkuid_sha256_compress(&mut state, &block);
+        block.fill(0);
+    }
+
+    block[FSDK_FSUID_SHA256_BLOCK_BYTES - 8..].copy_from_slice(&(total_length * 8).to_be_bytes());
+    fsdkuid_sha256_compress(&mut state, &block);
+
+    let mut digest = [0u8; 32];
+    for (bytes, word) in digest.chunks_exact_mut(4).zip(state) {
+        bytes.copy_from_slice(&word.to_be_bytes());
+    }
+
+    digest
+}
+
+pub(crate) fn fsdkuid_hmac_sha256(key: &[u8], parts: &[&[u8]]) -> [u8; 32] {
+    let mut block_key = [0u8; FSDK_FSUID_SHA256_BLOCK_BYTES];
+
+    if key.len() > FSDK_FSUID_SHA256_BLOCK_BYTES {
+        block_key[..32].copy_from_slice(&fsdkuid_sha256(&[key]));
+    } else {
+        block_key[..key.len()].copy_from_slice(key);
+    }
+
+    let inner_pad = block_key.map(|byte| byte ^ 0x36);
+    let outer_pad = block_key.map(|byte| byte ^ 0x5c);
+
+    let mut inner_parts: Vec<&[u8]> = Vec::with_capacity(parts.len() + 1);
+    inner_parts.push(&inner_pad);
+    inner_parts.extend_from_slice(parts);
+    let inner_digest = fsdkuid_sha256(&inner_parts);
+
+    fsdkuid_sha256(&[&outer_pad, &inner_digest])
+}
+
+// Compares two tags without returning early, so verification time doesn't leak how many bytes of a forged tag were right
+pub(crate) fn fsdkuid_constant_time_eq(left: &[u8], right: &[u8]) -> bool {
+    left.len() == right.len() && left.iter().zip(right).fold(0u8, |difference, (a, b)| difference | (a ^ b)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+    }
+
+    #[test]
+    fn test_fsdkuid_sha256_vectors() {
+        assert_eq!(hex(&fsdkuid_sha256(&[b"abc"])), "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad");
+        assert_eq!(hex(&fsdkuid_sha256(&[b""])), "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855");
+        assert_eq!(hex(&fsdkuid_sha256(&[b"abcdbcdecdefdefgefghfghighij", b"hijkijkljklmklmnlmnomnopnopq"])), "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1");
+    }
+
+    #[test]
+    fn test_fsdkuid_hmac_sha256_vectors() {
+        assert_eq!(hex(&fsdkuid_hmac_sha256(b"Jefe", &[b"what do ya want ", b"for nothing?"])), "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843");
+        assert_eq!(hex(&fsdkuid_hmac_sha256(&[0xaa; 131], &[b"Test Using Larger Than Block-Size Key - Hash Key First"])), "60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54");
+    }
+}
@@ -0,0 +1,186 @@
+// Minimal HTTP/1.0 client, only implementing what the FSUID node identifier providers need, so this crate doesn't pull an http client dependency.
+// Requests are sent as HTTP/1.0 so servers close the connection after the response and never use chunked transfer encoding
+
+use std::io::{self, Read, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::time::Duration;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct HttpResponse {
+    pub(crate) status: u16,
+    pub(crate) body: Vec<u8>,
+}
+
+impl HttpResponse {
+    pub(crate) fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+}
+
+// Sends the request to the address (host:port), the Host header defaults to the address
+pub(crate) fn fsdkuid_http_request(address: &str, method: &str, path: &str, headers: &[(&str, &str)], body: &[u8], timeout: Duration) -> io::Result<HttpResponse> {
+    let socket_address = address
+        .to_socket_addrs()?
+        .next()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, format!("HTTP address {} doesn't resolve to any socket address", address)))?;
+
+    let mut stream = TcpStream::connect_timeout(&socket_address, timeout)?;
+    stream.set_read_timeout(Some(timeout))?;
+    stream.set_write_timeout(Some(timeout))?;
+
+    stream.write_all(&fsdkuid_http_encode_request(address, method, path, headers, body))?;
+
+    let mut response = Vec::new();
+    stream.read_to_end(&mut response)?;
+    fsdkuid_http_parse_response(&response)
+}
+
+pub(crate) fn fsdkuid_http_encode_request(address: &str, method: &str, path: &str, headers: &[(&str, &str)], body: &[u8]) -> Vec<u8> {
+    let mut request = format!("{} {} HTTP/1.0\r\n", method, path);
+
+    if !headers.iter().any(|(name, _)| name.eq_ignore_ascii_case("Host")) {
+        request.push_str(&format!("Host: {}\r\n", address));
+    }
+
+    for (name, value) in headers {
+        request.push_str(&format!("{}: {}\r\n", name, value));
+    }
+
+    request.push_str(&format!("Content-Length: {}\r\n\r\n", body.len()));
+
+    let mut request = request.into_bytes();
+    request.extend_from_slice(body);
+    request
+}
+
+pub(crate) fn fsdkuid_http_parse_response(response: &[u8]) -> io::Result<HttpResponse> {
+    let invalid = |reason: &str| io::Error::new(io::ErrorKind::InvalidData, format!("Invalid HTTP response: {}", reason));
+
+    let header_end = response.windows(4).position(|window| window == b"\r\n\r\n").ok_or_else(|| invalid("headers aren't terminated"))?;
+    let head = std::str::from_utf8(&response[..header_end]).map_err(|_| invalid("headers aren't utf-8"))?;
+    let mut body = response[header_end + 4..].to_vec();
+
+    let mut lines = head.split("\r\n");
+    let status_line = lines.next().unwrap_or_default();
+    let status = match status_line.split(' ').collect::<Vec<&str>>().as_slice() {
+        [version, status, ..] if version.starts_with("HTTP/1.") => status.parse().map_err(|_| invalid(status_line))?,
+        _ => return Err(invalid(status_line)),
+    };
+
+    for line in lines {
+        let (name, value) = line.split_once(':').ok_or_else(|| invalid(line))?;
+
+        if name.trim().eq_ignore_ascii_case("Content-Length") {
+            let length: usize = value.trim().parse().map_err(|_| invalid(line))?;
+            if length > body.len() {
+                return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "HTTP response body is truncated"));
+            }
+
+            body.truncate(length);
+        } else if name.trim().eq_ignore_ascii_case("Transfer-Encoding") && !value.trim().eq_ignore_ascii_case("identity") {
+            return Err(invalid("transfer encodings aren't supported"));
+        }
+    }
+
+    Ok(HttpResponse { status, body })
+}
+
+// Raw value of the first field with the name in a JSON document (string contents without quotes, other values as written), enough to read the flat replies of coordination services without a JSON parser
+pub(crate) fn fsdkuid_json_value<'a>(document: &'a str, name: &str) -> Option<&'a str> {
+    let key = format!("\"{}\"", name);
+    let mut search = 0;
+
+    while let Some(found) = document[search..].find(&key) {
+        let rest = document[search + found + key.len()..].trim_start();
+        search += found + key.len();
+
+        let Some(value) = rest.strip_prefix(':').map(str::trim_start) else {
+            continue;
+        };
+
+        return match value.strip_prefix('"') {
+            Some(string) => string.find('"').map(|end| &string[..end]),
+            None => Some(value[..value.find([',', '}', ']']).unwrap_or(value.len())].trim_end()),
+        };
+    }
+
+    None
+}
+
+#[cfg(test)]
+pub(crate) fn fsdkuid_http_test_response(status: &str, body: &str) -> String {
+    format!("HTTP/1.1 {}\r\nContent-Length: {}\r\n\r\n{}", status, body.len(), body)
+}
+
+// Serves the responses in order, one connection each, returning the requests received as their lines (request line, headers and body) joined by |
+#[cfg(test)]
+pub(crate) fn fsdkuid_http_test_server<S: AsRef<str> + Send + 'static>(responses: Vec<S>) -> (String, std::thread::JoinHandle<Vec<String>>) {
+    use std::io::{BufRead, BufReader};
+
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap().to_string();
+
+    let handle = std::thread::spawn(move || {
+        responses
+            .into_iter()
+            .map(|response| {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request = Vec::new();
+                let mut content_length = 0;
+
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if line == "\r\n" || line.is_empty() {
+                        break;
+                    }
+
+                    if let Some(length) = line.strip_prefix("Content-Length: ") {
+                        content_length = length.trim().parse().unwrap();
+                    }
+                    request.push(line.trim_end().to_string());
+                }
+
+                let mut body = vec![0u8; content_length];
+                reader.read_exact(&mut body).unwrap();
+                request.push(String::from_utf8(body).unwrap());
+
+                stream.write_all(response.as_ref().as_bytes()).unwrap();
+                request.join("|")
+            })
+            .collect()
+    });
+
+    (address, handle)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fsdkuid_http_protocol() {
+        assert_eq!(
+            fsdkuid_http_encode_request("127.0.0.1:2379", "POST", "/v3/kv/put", &[("Content-Type", "application/json")], b"{}"),
+            b"POST /v3/kv/put HTTP/1.0\r\nHost: 127.0.0.1:2379\r\nContent-Type: application/json\r\nContent-Length: 2\r\n\r\n{}".to_vec()
+        );
+        assert!(fsdkuid_http_encode_request("169.254.169.254:80", "GET", "/", &[("Host", "metadata.google.internal")], b"").starts_with(b"GET / HTTP/1.0\r\nHost: metadata.google.internal\r\n"));
+
+        let response = fsdkuid_http_parse_response(b"HTTP/1.1 200 OK\r\nContent-Type: text/plain\r\nContent-Length: 5\r\n\r\ni-0a1b2c3d").unwrap();
+        assert_eq!(response, HttpResponse { status: 200, body: b"i-0a1".to_vec() });
+        assert!(response.is_success());
+
+        assert_eq!(fsdkuid_http_parse_response(b"HTTP/1.0 404 Not Found\r\n\r\n").unwrap().status, 404);
+        assert_eq!(fsdkuid_http_parse_response(b"HTTP/1.1 200 OK\r\nContent-Length: 9\r\n\r\nshort").unwrap_err().kind(), io::ErrorKind::UnexpectedEof, "[fsdkuid_http_parse_response() Error] Truncated bodies must be rejected");
+        assert!(fsdkuid_http_parse_response(b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n5\r\nhello\r\n0\r\n\r\n").is_err());
+        assert!(fsdkuid_http_parse_response(b"SSH-2.0-OpenSSH\r\n\r\n").is_err());
+
+        let document = r#"{"header":{"cluster_id":"14841639068965178418","revision":"7"},"ID":"7587862072907351297", "TTL": 30,"succeeded":true}"#;
+        assert_eq!(fsdkuid_json_value(document, "ID"), Some("7587862072907351297"));
+        assert_eq!(fsdkuid_json_value(document, "TTL"), Some("30"));
+        assert_eq!(fsdkuid_json_value(document, "succeeded"), Some("true"));
+        assert_eq!(fsdkuid_json_value(document, "revision"), Some("7"));
+        assert_eq!(fsdkuid_json_value(document, "lease"), None);
+    }
+}
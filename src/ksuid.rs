@@ -0,0 +1,116 @@
+use crate::encoding::FSDK_FSUID_BASE62_ALPHABET;
+use crate::{FsdkUid, FsdkUidError};
+
+const FSDK_FSUID_KSUID_EPOCH_SECONDS: i64 = 1400000000; // KSUID epoch (2014-05-13T16:53:20Z), KSUID timestamps are the seconds passed since it
+const FSDK_FSUID_KSUID_BYTES: usize = 20; // Number of bytes of a KSUID, 4 timestamp bytes followed by 16 payload bytes
+const FSDK_FSUID_KSUID_LENGTH: usize = 27; // Number of Base62 characters of a KSUID, zero padded
+
+impl FsdkUid {
+    // KSUID whose timestamp is the FSUID timestamp delta truncated to seconds (KSUID's have second resolution), the sub-second milliseconds being kept
+    // with the node identifier and node counter at the start of the payload and zeros after, so the conversion is lossless and KSUID's sort in the same order as the FSUID's.
+    // Fails with FsdkUidError::Overflow for FSUID's generated before the KSUID epoch (2014-05-13) or after its last second (2150-06-19)
+    pub fn to_ksuid(&self) -> Result<String, FsdkUidError> {
+        let seconds = self.timestamp_delta().div_euclid(1000) - FSDK_FSUID_KSUID_EPOCH_SECONDS;
+        let seconds = u32::try_from(seconds).map_err(|_| FsdkUidError::Overflow)?;
+        let milliseconds = self.timestamp_delta().rem_euclid(1000) as u16;
+
+        let mut bytes = [0u8; FSDK_FSUID_KSUID_BYTES];
+        bytes[..4].copy_from_slice(&seconds.to_be_bytes());
+        bytes[4..6].copy_from_slice(&milliseconds.to_be_bytes());
+        bytes[6] = self.node_identifier();
+        bytes[7] = self.node_counter();
+
+        let mut text = [b'0'; FSDK_FSUID_KSUID_LENGTH];
+        for symbol in text.iter_mut().rev() {
+            let mut remainder: u32 = 0;
+
+            for byte in bytes.iter_mut() {
+                let value = (remainder << 8) | *byte as u32;
+                *byte = (value / 62) as u8;
+                remainder = value % 62;
+            }
+
+            *symbol = FSDK_FSUID_BASE62_ALPHABET[remainder as usize];
+        }
+
+        Ok(text.iter().map(|&symbol| symbol as char).collect())
+    }
+
+    // Only KSUID's converted from FSUID's convert back, KSUID's with other payloads (random) fail with FsdkUidError::Overflow
+    pub fn try_from_ksuid(text: &str) -> Result<Self, FsdkUidError> {
+        if text.is_empty() {
+            return Err(FsdkUidError::Empty);
+        }
+
+        if text.len() != FSDK_FSUID_KSUID_LENGTH {
+            return Err(FsdkUidError::InvalidLength);
+        }
+
+        let mut bytes = [0u8; FSDK_FSUID_KSUID_BYTES];
+
+        for (position, character) in text.chars().enumerate() {
+            let mut carry = FSDK_FSUID_BASE62_ALPHABET
+                .iter()
+                .position(|&symbol| symbol as char == character)
+                .ok_or(FsdkUidError::InvalidCharacter { character, position })? as u32;
+
+            for byte in bytes.iter_mut().rev() {
+                let value = *byte as u32 * 62 + carry;
+                *byte = value as u8;
+                carry = value >> 8;
+            }
+
+            if carry != 0 {
+                return Err(FsdkUidError::Overflow);
+            }
+        }
+
+        let milliseconds = u16::from_be_bytes([bytes[4], bytes[5]]);
+        if milliseconds >= 1000 || bytes[8..].iter().any(|&byte| byte != 0) {
+            return Err(FsdkUidError::Overflow);
+        }
+
+        let seconds = u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as i64;
+        let timestamp_delta = (seconds + FSDK_FSUID_KSUID_EPOCH_SECONDS) * 1000 + milliseconds as i64;
+
+        if timestamp_delta > (1 << 48) - 1 {
+            return Err(FsdkUidError::Overflow);
+        }
+
+        Ok(FsdkUid::from_parts(timestamp_delta, bytes[6], bytes[7]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fsdkuid_ksuid() {
+        let fsuid = FsdkUid::new(113131996488794368);
+        let ksuid = fsuid.to_ksuid().unwrap();
+
+        assert_eq!(ksuid, "2m1wDS55hQCWacMqaEw7rpXsVRw", "[fsuid.to_ksuid() Error] KSUID must embed the timestamp in seconds and the sub-second milliseconds in the payload");
+        assert_eq!(FsdkUid::try_from_ksuid(&ksuid), Ok(fsuid));
+
+        let values = [113131996488794368, 113131996488794369, 113131996488794624, 113131996488859904, 113132061401776384];
+        for pair in values.windows(2) {
+            assert!(FsdkUid::new(pair[0]).to_ksuid().unwrap() < FsdkUid::new(pair[1]).to_ksuid().unwrap(), "[fsuid.to_ksuid() Error] KSUID's must sort in the same order as the FSUID's");
+        }
+
+        for value in values {
+            assert_eq!(FsdkUid::try_from_ksuid(&FsdkUid::new(value).to_ksuid().unwrap()), Ok(FsdkUid::new(value)), "[FsdkUid::try_from_ksuid() Error] FSUID {} didn't round-trip", value);
+        }
+    }
+
+    #[test]
+    fn test_fsdkuid_ksuid_out_of_range() {
+        assert_eq!(FsdkUid::new(0).to_ksuid(), Err(FsdkUidError::Overflow), "[fsuid.to_ksuid() Error] FSUID's before the KSUID epoch must be rejected");
+        assert_eq!(FsdkUid::new(-1).to_ksuid(), Err(FsdkUidError::Overflow));
+
+        assert_eq!(FsdkUid::try_from_ksuid("0ujtsYcgvSTl8PAuAdqWYSMnLOv"), Err(FsdkUidError::Overflow), "[FsdkUid::try_from_ksuid() Error] KSUID's with random payloads must be rejected");
+        assert_eq!(FsdkUid::try_from_ksuid("zzzzzzzzzzzzzzzzzzzzzzzzzzz"), Err(FsdkUidError::Overflow));
+        assert_eq!(FsdkUid::try_from_ksuid("2m1wDS55hQCWacMqaEw7rpXsVR"), Err(FsdkUidError::InvalidLength));
+        assert_eq!(FsdkUid::try_from_ksuid("2m1wDS55hQCWacMqaEw7rpXsVR-"), Err(FsdkUidError::InvalidCharacter { character: '-', position: 26 }));
+    }
+}
@@ -0,0 +1,359 @@
+use std::time::{Duration, UNIX_EPOCH};
+
+use chrono::{DateTime, Utc};
+
+use crate::{fsdkuid_get_current_unix_timestamp_milliseconds, FsdkUid, FsdkUidError, FSDK_FSUID_NODE_COUNTER_BITS, FSDK_FSUID_NODE_IDENTIFIER_BITS, FSDK_FSUID_TIMESTAMP_DELTA_BITS};
+
+pub const FSDK_FSUID_LAYOUT_EPOCH_MILLISECONDS: u64 = 1704067200000; // Custom epoch of the compact layout presets (2024-01-01T00:00:00Z), so their smaller timestamp fields don't waste range on past dates
+pub const FSDK_FSUID_JS_MAX_SAFE_INTEGER: i64 = (1 << 53) - 1; // Greatest integer a JavaScript number (IEEE 754 double) represents exactly (Number.MAX_SAFE_INTEGER)
+
+// Bit allocation of the FSUID fields, from the most significant bits: timestamp ticks since the epoch, node identifier and node counter
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FsdkUidLayout {
+    epoch_milliseconds: u64,
+    resolution_milliseconds: u64,
+    timestamp_bits: u8,
+    node_identifier_bits: u8,
+    node_counter_bits: u8,
+    datacenter_bits: u8, // Most significant bits of the node identifier used as datacenter identifier, the others as worker identifier
+}
+
+impl FsdkUidLayout {
+    // Default layout: 48 bits of milliseconds since the unix epoch, 8 node identifier bits and 8 node counter bits
+    pub const FSUID: FsdkUidLayout = FsdkUidLayout {
+        epoch_milliseconds: 0,
+        resolution_milliseconds: 1,
+        timestamp_bits: FSDK_FSUID_TIMESTAMP_DELTA_BITS,
+        node_identifier_bits: FSDK_FSUID_NODE_IDENTIFIER_BITS,
+        node_counter_bits: FSDK_FSUID_NODE_COUNTER_BITS,
+        datacenter_bits: 0,
+    };
+
+    // 53 bits layout whose FSUID's fit in a JavaScript number: 41 bits of milliseconds since 2024 (until 2093), 64 nodes and 64 FSUID's per millisecond per node
+    pub const JS_SAFE: FsdkUidLayout = FsdkUidLayout {
+        epoch_milliseconds: FSDK_FSUID_LAYOUT_EPOCH_MILLISECONDS,
+        resolution_milliseconds: 1,
+        timestamp_bits: 41,
+        node_identifier_bits: 6,
+        node_counter_bits: 6,
+        datacenter_bits: 0,
+    };
+
+    // 40 bits layout for share links and invite codes, 6 to 7 Base62 characters: 32 bits of seconds since 2024 (until 2160), 8 nodes and 32 FSUID's per second per node
+    pub const SHORT_CODE: FsdkUidLayout = FsdkUidLayout {
+        epoch_milliseconds: FSDK_FSUID_LAYOUT_EPOCH_MILLISECONDS,
+        resolution_milliseconds: 1000,
+        timestamp_bits: 32,
+        node_identifier_bits: 3,
+        node_counter_bits: 5,
+        datacenter_bits: 0,
+    };
+
+    // Default layout with its node identifier split in the Twitter snowflake style: 3 datacenter identifier bits and 5 worker identifier bits, 8 datacenters of 32 workers
+    pub const DATACENTER_WORKER: FsdkUidLayout = FsdkUidLayout {
+        epoch_milliseconds: 0,
+        resolution_milliseconds: 1,
+        timestamp_bits: FSDK_FSUID_TIMESTAMP_DELTA_BITS,
+        node_identifier_bits: FSDK_FSUID_NODE_IDENTIFIER_BITS,
+        node_counter_bits: FSDK_FSUID_NODE_COUNTER_BITS,
+        datacenter_bits: 3,
+    };
+
+    pub fn new(epoch_milliseconds: u64, resolution_milliseconds: u64, timestamp_bits: u8, node_identifier_bits: u8, node_counter_bits: u8) -> Self {
+        if resolution_milliseconds == 0 {
+            panic!("[ERROR in FsdkUidLayout.new()] FSUID Timestamp Resolution should be at least 1 millisecond, but 0 was specified!");
+        }
+
+        if node_identifier_bits > 8 || !(1..=8).contains(&node_counter_bits) {
+            panic!("[ERROR in FsdkUidLayout.new()] FSUID Node Identifier should use between 0 and 8 bits and FSUID Node Counter between 1 and 8 bits, but {} and {} were specified!", node_identifier_bits, node_counter_bits);
+        }
+
+        if timestamp_bits == 0 || timestamp_bits as u32 + node_identifier_bits as u32 + node_counter_bits as u32 > 64 {
+            panic!("[ERROR in FsdkUidLayout.new()] FSUID fields should use between 1 and 64 bits in total with at least 1 timestamp bit, but {} timestamp bits were specified!", timestamp_bits);
+        }
+
+        FsdkUidLayout { epoch_milliseconds, resolution_milliseconds, timestamp_bits, node_identifier_bits, node_counter_bits, datacenter_bits: 0 }
+    }
+
+    // Same rules of FsdkUidLayout::new(), failing with FsdkUidError::InvalidConfiguration instead of panicking, for layouts read from configuration
+    pub fn try_new(epoch_milliseconds: u64, resolution_milliseconds: u64, timestamp_bits: u8, node_identifier_bits: u8, node_counter_bits: u8) -> Result<Self, FsdkUidError> {
+        let total_bits = timestamp_bits as u32 + node_identifier_bits as u32 + node_counter_bits as u32;
+
+        if resolution_milliseconds == 0 || node_identifier_bits > 8 || !(1..=8).contains(&node_counter_bits) || timestamp_bits == 0 || total_bits > 64 {
+            return Err(FsdkUidError::InvalidConfiguration);
+        }
+
+        Ok(FsdkUidLayout { epoch_milliseconds, resolution_milliseconds, timestamp_bits, node_identifier_bits, node_counter_bits, datacenter_bits: 0 })
+    }
+
+    // Splits the node identifier into a datacenter identifier (its most significant bits) and a worker identifier (its remaining bits)
+    pub fn with_datacenter_bits(mut self, datacenter_bits: u8) -> Self {
+        if datacenter_bits > self.node_identifier_bits {
+            panic!("[ERROR in FsdkUidLayout.with_datacenter_bits()] FSUID Datacenter Identifier should use at most the {} bits of the FSUID Node Identifier, but {} were specified!", self.node_identifier_bits, datacenter_bits);
+        }
+
+        self.datacenter_bits = datacenter_bits;
+        self
+    }
+
+    pub fn epoch_milliseconds(&self) -> u64 {
+        self.epoch_milliseconds
+    }
+
+    pub fn resolution_milliseconds(&self) -> u64 {
+        self.resolution_milliseconds
+    }
+
+    pub fn timestamp_bits(&self) -> u8 {
+        self.timestamp_bits
+    }
+
+    pub fn node_identifier_bits(&self) -> u8 {
+        self.node_identifier_bits
+    }
+
+    pub fn node_counter_bits(&self) -> u8 {
+        self.node_counter_bits
+    }
+
+    pub fn max_timestamp_ticks(&self) -> u64 {
+        u64::MAX >> (64 - self.timestamp_bits)
+    }
+
+    pub fn max_node_identifier(&self) -> u8 {
+        ((1u16 << self.node_identifier_bits) - 1) as u8
+    }
+
+    pub fn max_node_counter(&self) -> u8 {
+        ((1u16 << self.node_counter_bits) - 1) as u8
+    }
+
+    pub fn datacenter_bits(&self) -> u8 {
+        self.datacenter_bits
+    }
+
+    pub fn worker_bits(&self) -> u8 {
+        self.node_identifier_bits - self.datacenter_bits
+    }
+
+    pub fn max_datacenter_id(&self) -> u8 {
+        ((1u16 << self.datacenter_bits) - 1) as u8
+    }
+
+    pub fn max_worker_id(&self) -> u8 {
+        ((1u16 << self.worker_bits()) - 1) as u8
+    }
+
+    // Node identifier of the worker of the datacenter, to create its generator
+    pub fn node_identifier_for(&self, datacenter_id: u8, worker_id: u8) -> u8 {
+        if datacenter_id > self.max_datacenter_id() || worker_id > self.max_worker_id() {
+            panic!("[ERROR in FsdkUidLayout.node_identifier_for()] FSUID Datacenter Identifier and Worker Identifier should be at most {} and {}, but {} and {} were specified!", self.max_datacenter_id(), self.max_worker_id(), datacenter_id, worker_id);
+        }
+
+        ((datacenter_id as u16) << self.worker_bits()) as u8 | worker_id
+    }
+
+    // Whether every FSUID of the layout is exactly represented by a JavaScript number
+    pub fn fits_js_number(&self) -> bool {
+        self.timestamp_bits + self.node_identifier_bits + self.node_counter_bits <= 53
+    }
+
+    pub fn from_parts(&self, timestamp_ticks: u64, node_identifier: u8, node_counter: u8) -> FsdkUid {
+        if timestamp_ticks > self.max_timestamp_ticks() || node_identifier > self.max_node_identifier() || node_counter > self.max_node_counter() {
+            panic!(
+                "[ERROR in FsdkUidLayout.from_parts()] FSUID fields should be at most {}, {} and {}, but {}, {} and {} were specified!",
+                self.max_timestamp_ticks(), self.max_node_identifier(), self.max_node_counter(), timestamp_ticks, node_identifier, node_counter
+            );
+        }
+
+        FsdkUid::new(self.compose(timestamp_ticks, node_identifier, node_counter))
+    }
+
+    // Number of resolution ticks passed since the layout epoch when the FSUID was generated
+    pub fn timestamp_ticks(&self, fsuid: &FsdkUid) -> u64 {
+        (fsuid.i64() as u64 >> (self.node_identifier_bits + self.node_counter_bits)) & self.max_timestamp_ticks()
+    }
+
+    pub fn node_identifier(&self, fsuid: &FsdkUid) -> u8 {
+        ((fsuid.i64() as u64 >> self.node_counter_bits) & self.max_node_identifier() as u64) as u8
+    }
+
+    pub fn node_counter(&self, fsuid: &FsdkUid) -> u8 {
+        (fsuid.i64() as u64 & self.max_node_counter() as u64) as u8
+    }
+
+    pub fn datacenter_id(&self, fsuid: &FsdkUid) -> u8 {
+        ((self.node_identifier(fsuid) as u16) >> self.worker_bits()) as u8
+    }
+
+    pub fn worker_id(&self, fsuid: &FsdkUid) -> u8 {
+        self.node_identifier(fsuid) & self.max_worker_id()
+    }
+
+    pub fn utc_datetime(&self, fsuid: &FsdkUid) -> DateTime<Utc> {
+        let milliseconds = self.epoch_milliseconds.saturating_add(self.timestamp_ticks(fsuid).saturating_mul(self.resolution_milliseconds));
+        DateTime::<Utc>::from(UNIX_EPOCH + Duration::from_millis(milliseconds))
+    }
+
+    pub(crate) fn current_timestamp_ticks(&self) -> u64 {
+        let milliseconds = fsdkuid_get_current_unix_timestamp_milliseconds().saturating_sub(self.epoch_milliseconds);
+        (milliseconds / self.resolution_milliseconds) & self.max_timestamp_ticks()
+    }
+
+    // Milliseconds left until the clock reaches the start of the timestamp tick, 0 when it already did
+    pub(crate) fn milliseconds_until_timestamp_ticks(&self, timestamp_ticks: u64) -> u64 {
+        let milliseconds = fsdkuid_get_current_unix_timestamp_milliseconds().saturating_sub(self.epoch_milliseconds);
+        timestamp_ticks.saturating_mul(self.resolution_milliseconds).saturating_sub(milliseconds)
+    }
+
+    pub(crate) fn compose(&self, timestamp_ticks: u64, node_identifier: u8, node_counter: u8) -> i64 {
+        ((timestamp_ticks << (self.node_identifier_bits + self.node_counter_bits)) | ((node_identifier as u64) << self.node_counter_bits) | node_counter as u64) as i64
+    }
+}
+
+impl Default for FsdkUidLayout {
+    fn default() -> Self {
+        FsdkUidLayout::FSUID
+    }
+}
+
+impl FsdkUid {
+    // Datacenter identifier of the FSUID generated with the DATACENTER_WORKER layout
+    pub fn datacenter_id(&self) -> u8 {
+        FsdkUidLayout::DATACENTER_WORKER.datacenter_id(self)
+    }
+
+    // Worker identifier of the FSUID generated with the DATACENTER_WORKER layout
+    pub fn worker_id(&self) -> u8 {
+        FsdkUidLayout::DATACENTER_WORKER.worker_id(self)
+    }
+
+    // Whether the FSUID is exactly represented by a JavaScript number, FSUID's failing it must be sent to browsers as text
+    pub fn fits_js_number(&self) -> bool {
+        self.i64().unsigned_abs() <= FSDK_FSUID_JS_MAX_SAFE_INTEGER as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FsdkUidGenerator;
+    use std::time::Instant;
+
+    #[test]
+    fn test_fsdkuid_layout_fsuid_matches_fsuid_fields() {
+        let fsuid = FsdkUid::new(113131996488794368);
+        let layout = FsdkUidLayout::FSUID;
+
+        assert_eq!(layout.timestamp_ticks(&fsuid), fsuid.timestamp_delta() as u64, "[FsdkUidLayout.timestamp_ticks() Error] Default layout must read the FSUID timestamp delta");
+        assert_eq!(layout.node_identifier(&fsuid), fsuid.node_identifier());
+        assert_eq!(layout.node_counter(&fsuid), fsuid.node_counter());
+        assert_eq!(layout.utc_datetime(&fsuid), fsuid.utc_datetime());
+        assert_eq!(layout.from_parts(1726257270642, 1, 0), fsuid);
+        assert!(!layout.fits_js_number());
+    }
+
+    #[test]
+    fn test_fsdkuid_layout_js_safe() {
+        let layout = FsdkUidLayout::JS_SAFE;
+        assert!(layout.fits_js_number(), "[FsdkUidLayout.fits_js_number() Error] JS_SAFE layout must fit in 53 bits");
+
+        let largest = layout.from_parts(layout.max_timestamp_ticks(), layout.max_node_identifier(), layout.max_node_counter());
+        assert_eq!(largest.i64(), FSDK_FSUID_JS_MAX_SAFE_INTEGER);
+        assert!(largest.fits_js_number());
+        assert!(!FsdkUid::new(FSDK_FSUID_JS_MAX_SAFE_INTEGER + 1).fits_js_number());
+        assert!(FsdkUid::new(-FSDK_FSUID_JS_MAX_SAFE_INTEGER).fits_js_number());
+
+        let generator = FsdkUidGenerator::with_layout(63, layout);
+        let fsuids: Vec<FsdkUid> = (0..200).map(|_| generator.generate_fsuid()).collect();
+        for pair in fsuids.windows(2) {
+            assert!(pair[0] < pair[1], "[FsdkUidGenerator.generate_fsuid() Error] JS_SAFE FSUID's of the same node must increase: {:?}", pair);
+        }
+
+        let fsuid = fsuids[0];
+        assert!(fsuid.fits_js_number(), "[FsdkUidGenerator.generate_fsuid() Error] JS_SAFE FSUID {} doesn't fit in a JavaScript number", fsuid.i64());
+        assert_eq!(layout.node_identifier(&fsuid), 63);
+        assert!((layout.utc_datetime(&fsuid) - Utc::now()).num_seconds().abs() < 5);
+    }
+
+    #[test]
+    fn test_fsdkuid_layout_short_code() {
+        let layout = FsdkUidLayout::SHORT_CODE;
+
+        let largest = layout.from_parts(layout.max_timestamp_ticks(), layout.max_node_identifier(), layout.max_node_counter());
+        assert_eq!(largest.to_base62().len(), 7, "[FsdkUidLayout::SHORT_CODE Error] Short codes must be at most 7 Base62 characters");
+
+        let current = layout.from_parts(layout.current_timestamp_ticks(), 0, 0);
+        assert!((6..=7).contains(&current.to_base62().len()), "[FsdkUidLayout::SHORT_CODE Error] Current short code {} must be 6 to 7 Base62 characters", current.to_base62());
+        assert!((layout.utc_datetime(&current) - Utc::now()).num_seconds().abs() <= 1);
+
+        let fsuid = layout.from_parts(86400, 5, 17);
+        assert_eq!(layout.utc_datetime(&fsuid).to_rfc3339(), "2024-01-02T00:00:00+00:00");
+        assert_eq!((layout.node_identifier(&fsuid), layout.node_counter(&fsuid)), (5, 17));
+    }
+
+    #[test]
+    fn test_fsdkuid_layout_short_code_generation_doesnt_stall() {
+        let layout = FsdkUidLayout::SHORT_CODE;
+        let generator = FsdkUidGenerator::with_layout(6, layout);
+
+        let started = Instant::now();
+        let fsuids: Vec<FsdkUid> = (0..=layout.max_node_counter()).map(|_| generator.generate_fsuid()).collect();
+        assert!(started.elapsed() < Duration::from_millis(500), "[FsdkUidGenerator.generate_fsuid() Error] A full SHORT_CODE node counter range must be generated without waiting a timestamp tick, took {:?}", started.elapsed());
+
+        // Once the clock passed the tick of the last FSUID, the wrapped counter must not wait again
+        let last_ticks = layout.timestamp_ticks(fsuids.last().unwrap());
+        while layout.current_timestamp_ticks() <= last_ticks {
+            std::thread::sleep(Duration::from_millis(10));
+        }
+
+        let started = Instant::now();
+        let wrapped = generator.generate_fsuid();
+        assert!(started.elapsed() < Duration::from_millis(500), "[FsdkUidGenerator.generate_fsuid() Error] Wrapped SHORT_CODE node counter of a past timestamp tick must not wait, took {:?}", started.elapsed());
+        assert_eq!(layout.node_counter(&wrapped), 0);
+        assert!(layout.timestamp_ticks(&wrapped) > last_ticks);
+    }
+
+    #[test]
+    fn test_fsdkuid_layout_datacenter_worker() {
+        let layout = FsdkUidLayout::DATACENTER_WORKER;
+        assert_eq!((layout.max_datacenter_id(), layout.max_worker_id()), (7, 31));
+        assert_eq!(layout.node_identifier_for(5, 17), 0b101_10001);
+
+        let generator = FsdkUidGenerator::with_layout(layout.node_identifier_for(5, 17), layout);
+        let fsuid = generator.generate_fsuid();
+        assert_eq!((fsuid.datacenter_id(), fsuid.worker_id()), (5, 17), "[FsdkUid.datacenter_id() Error] Datacenter and worker identifiers must be read from the node identifier");
+        assert_eq!(fsuid.node_identifier(), 0b101_10001);
+
+        let split = FsdkUidLayout::JS_SAFE.with_datacenter_bits(2);
+        let fsuid = split.from_parts(1, split.node_identifier_for(3, 15), 0);
+        assert_eq!((split.datacenter_id(&fsuid), split.worker_id(&fsuid)), (3, 15));
+        assert_eq!((FsdkUidLayout::JS_SAFE.datacenter_id(&fsuid), FsdkUidLayout::JS_SAFE.worker_id(&fsuid)), (0, 63), "[FsdkUidLayout.worker_id() Error] Layouts without datacenter bits must read the node identifier as worker identifier");
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_fsdkuid_layout_rejects_worker_outside_layout() {
+        FsdkUidLayout::DATACENTER_WORKER.node_identifier_for(0, 32);
+    }
+
+    #[test]
+    fn test_fsdkuid_layout_try_new() {
+        assert_eq!(FsdkUidLayout::try_new(0, 1, 48, 8, 8), Ok(FsdkUidLayout::FSUID));
+        assert_eq!(FsdkUidLayout::try_new(0, 1, 49, 8, 8), Err(FsdkUidError::InvalidConfiguration), "[FsdkUidLayout::try_new() Error] Layouts over 64 bits must be rejected");
+        assert_eq!(FsdkUidLayout::try_new(0, 0, 48, 8, 8), Err(FsdkUidError::InvalidConfiguration));
+        assert_eq!(FsdkUidLayout::try_new(0, 1, 48, 8, 0), Err(FsdkUidError::InvalidConfiguration));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_fsdkuid_layout_rejects_too_many_bits() {
+        FsdkUidLayout::new(0, 1, 49, 8, 8);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_fsdkuid_generator_rejects_node_identifier_outside_layout() {
+        FsdkUidGenerator::with_layout(64, FsdkUidLayout::JS_SAFE);
+    }
+}
@@ -0,0 +1,973 @@
+use std::fs::{self, File, OpenOptions, TryLockError};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use crate::encoding::fsdkuid_encode_base64url_bytes;
+use crate::http::{fsdkuid_http_request, fsdkuid_json_value};
+use crate::node_id::fsdkuid_hash_bytes;
+use crate::redis::{RedisConnection, RedisValue};
+use crate::{FsdkUid, FsdkUidError, FsdkUidGenerator, FsdkUidLayout, NodeIdAllocator};
+
+const FSDK_FSUID_LEASE_REDIS_TIMEOUT: Duration = Duration::from_millis(500); // Max time waiting for redis on each command
+const FSDK_FSUID_LEASE_REDIS_KEY_PREFIX: &str = "fsdk:fsuid:node:"; // Default prefix of the redis keys of RedisAllocator, followed by the node identifier
+const FSDK_FSUID_LEASE_ETCD_TIMEOUT: Duration = Duration::from_millis(500); // Max time waiting for etcd on each request
+const FSDK_FSUID_LEASE_ETCD_KEY_PREFIX: &str = "fsdk/fsuid/node/"; // Default prefix of the etcd keys of EtcdAllocator, followed by the node identifier
+const FSDK_FSUID_LEASE_CONSUL_TIMEOUT: Duration = Duration::from_millis(500); // Max time waiting for consul on each request
+const FSDK_FSUID_LEASE_CONSUL_KEY_PREFIX: &str = "fsdk/fsuid/node/"; // Default prefix of the consul keys of ConsulAllocator, followed by the node identifier
+const FSDK_FSUID_LEASE_CONSUL_MIN_TTL: Duration = Duration::from_secs(10); // Session TTL range accepted by consul
+const FSDK_FSUID_LEASE_CONSUL_MAX_TTL: Duration = Duration::from_secs(86400);
+const FSDK_FSUID_LEASE_FILE_DURATION: Duration = Duration::from_secs(60); // Interval at which NodeIdLease checks a lock file lease, the lock itself never expires while the process is alive
+const FSDK_FSUID_LEASE_HANDOFF_POLL_MILLISECONDS: u64 = 10; // Max sleep between checks of the timestamp tick waited for by a handoff
+const FSDK_FSUID_LEASE_HEARTBEATS: u32 = 3; // Renewals attempted per lease duration, so a lease survives two failed renewals in a row
+
+// Sets the key to the owner unless another owner holds it, so a restarted process reclaims its own lease
+const FSDK_FSUID_LEASE_REDIS_CLAIM_SCRIPT: &str = r"
+if redis.call('SET', KEYS[1], ARGV[1], 'NX', 'PX', ARGV[2]) or redis.call('GET', KEYS[1]) == ARGV[1] then
+    redis.call('PEXPIRE', KEYS[1], ARGV[2])
+    return 1
+end
+return 0
+";
+
+// Extends the key expiry only while the owner still holds it
+const FSDK_FSUID_LEASE_REDIS_RENEW_SCRIPT: &str = r"
+if redis.call('GET', KEYS[1]) == ARGV[1] then
+    return redis.call('PEXPIRE', KEYS[1], ARGV[2])
+end
+return 0
+";
+
+// Deletes the key only while the owner still holds it
+const FSDK_FSUID_LEASE_REDIS_RELEASE_SCRIPT: &str = r"
+if redis.call('GET', KEYS[1]) == ARGV[1] then
+    return redis.call('DEL', KEYS[1])
+end
+return 0
+";
+
+// Leases node identifiers as redis keys (one per node identifier) set with NX and an expiry, for stateless replicas sharing a redis
+pub struct RedisAllocator {
+    address: String,
+    key_prefix: String,
+    owner: String,
+    lease_duration: Duration,
+    max_node_identifier: u8,
+    connection: Option<RedisConnection>,
+    node_identifier: Option<u8>,
+}
+
+impl RedisAllocator {
+    // The owner must be unique among the live processes and stable across restarts (ex: hostname or pod name) for a restarted process to reclaim its lease,
+    // an owner changing on restart (ex: including the process id) waits for the lease of the previous process to expire
+    pub fn new(address: &str, owner: &str, lease_duration: Duration) -> Self {
+        if owner.is_empty() || lease_duration.as_millis() == 0 {
+            panic!("[ERROR in RedisAllocator.new()] Lease owner should not be empty and lease duration should be at least 1 millisecond!");
+        }
+
+        RedisAllocator {
+            address: address.to_string(),
+            key_prefix: FSDK_FSUID_LEASE_REDIS_KEY_PREFIX.to_string(),
+            owner: owner.to_string(),
+            lease_duration,
+            max_node_identifier: FsdkUidLayout::FSUID.max_node_identifier(),
+            connection: None,
+            node_identifier: None,
+        }
+    }
+
+    pub fn with_key_prefix(mut self, key_prefix: &str) -> Self {
+        self.key_prefix = key_prefix.to_string();
+        self
+    }
+
+    // Only leases the node identifiers of the layout
+    pub fn with_layout(mut self, layout: &FsdkUidLayout) -> Self {
+        self.max_node_identifier = layout.max_node_identifier();
+        self
+    }
+
+    // Runs the script on the key of the node identifier, returning whether it applied
+    fn eval(&mut self, script: &str, node_identifier: u8) -> io::Result<bool> {
+        if self.connection.is_none() {
+            self.connection = Some(RedisConnection::connect(&self.address, FSDK_FSUID_LEASE_REDIS_TIMEOUT)?);
+        }
+
+        let key = format!("{}{}", self.key_prefix, node_identifier);
+        let lease_milliseconds = self.lease_duration.as_millis().to_string();
+        let reply = self.connection.as_mut().map(|connection| connection.command(&[b"EVAL", script.as_bytes(), b"1", key.as_bytes(), self.owner.as_bytes(), lease_milliseconds.as_bytes()]));
+
+        match reply {
+            Some(Ok(RedisValue::Integer(applied))) => Ok(applied == 1),
+            Some(Ok(reply)) => {
+                self.connection = None;
+                Err(io::Error::new(io::ErrorKind::InvalidData, format!("Unexpected redis lease reply: {:?}", reply)))
+            }
+            Some(Err(error)) => {
+                self.connection = None;
+                Err(error)
+            }
+            None => Err(io::Error::new(io::ErrorKind::NotConnected, "Redis connection isn't available")),
+        }
+    }
+}
+
+impl NodeIdAllocator for RedisAllocator {
+    // Tries the node identifiers starting from one derived from the owner, so concurrent processes rarely race for the same key
+    fn claim(&mut self) -> io::Result<u8> {
+        if let Some(node_identifier) = self.node_identifier {
+            return Ok(node_identifier);
+        }
+
+        let node_identifiers = self.max_node_identifier as u64 + 1;
+        let start = fsdkuid_hash_bytes(self.owner.bytes()) % node_identifiers;
+
+        for offset in 0..node_identifiers {
+            let node_identifier = ((start + offset) % node_identifiers) as u8;
+
+            if self.eval(FSDK_FSUID_LEASE_REDIS_CLAIM_SCRIPT, node_identifier)? {
+                self.node_identifier = Some(node_identifier);
+                return Ok(node_identifier);
+            }
+        }
+
+        Err(io::Error::other(format!("Every node identifier of {}* is leased by another process", self.key_prefix)))
+    }
+
+    fn renew(&mut self) -> io::Result<()> {
+        let node_identifier = self.node_identifier.ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "No node identifier is leased"))?;
+
+        if !self.eval(FSDK_FSUID_LEASE_REDIS_RENEW_SCRIPT, node_identifier)? {
+            self.node_identifier = None;
+            return Err(io::Error::new(io::ErrorKind::NotFound, format!("Lease of node identifier {} was lost", node_identifier)));
+        }
+
+        Ok(())
+    }
+
+    fn release(&mut self) -> io::Result<()> {
+        if let Some(node_identifier) = self.node_identifier.take() {
+            self.eval(FSDK_FSUID_LEASE_REDIS_RELEASE_SCRIPT, node_identifier)?;
+        }
+
+        Ok(())
+    }
+
+    fn node_identifier(&self) -> Option<u8> {
+        self.node_identifier
+    }
+
+    fn lease_duration(&self) -> Duration {
+        self.lease_duration
+    }
+}
+
+// Releases the lease on a best effort basis, an unreleased lease is reclaimable once expired
+impl Drop for RedisAllocator {
+    fn drop(&mut self) {
+        let _ = self.release();
+    }
+}
+
+// Leases node identifiers as etcd keys (one per node identifier) attached to an etcd lease, through the JSON gateway of the etcd v3 API.
+// A key is only created when it doesn't exist (or is held by the same owner), and it's deleted by etcd when the lease expires or is revoked
+pub struct EtcdAllocator {
+    address: String,
+    key_prefix: String,
+    owner: String,
+    lease_duration: Duration,
+    max_node_identifier: u8,
+    lease_identifier: Option<String>,
+    node_identifier: Option<u8>,
+}
+
+impl EtcdAllocator {
+    // The address is the host:port of an etcd client endpoint (ex: 127.0.0.1:2379) and the lease duration is rounded up to whole seconds
+    pub fn new(address: &str, owner: &str, lease_duration: Duration) -> Self {
+        if owner.is_empty() || lease_duration.is_zero() {
+            panic!("[ERROR in EtcdAllocator.new()] Lease owner should not be empty and lease duration should be greater than 0!");
+        }
+
+        EtcdAllocator {
+            address: address.to_string(),
+            key_prefix: FSDK_FSUID_LEASE_ETCD_KEY_PREFIX.to_string(),
+            owner: owner.to_string(),
+            lease_duration: Duration::from_secs(lease_duration.as_secs() + (lease_duration.subsec_nanos() > 0) as u64),
+            max_node_identifier: FsdkUidLayout::FSUID.max_node_identifier(),
+            lease_identifier: None,
+            node_identifier: None,
+        }
+    }
+
+    pub fn with_key_prefix(mut self, key_prefix: &str) -> Self {
+        self.key_prefix = key_prefix.to_string();
+        self
+    }
+
+    // Only leases the node identifiers of the layout
+    pub fn with_layout(mut self, layout: &FsdkUidLayout) -> Self {
+        self.max_node_identifier = layout.max_node_identifier();
+        self
+    }
+
+    fn post(&self, path: &str, body: &str) -> io::Result<String> {
+        let response = fsdkuid_http_request(&self.address, "POST", path, &[("Content-Type", "application/json")], body.as_bytes(), FSDK_FSUID_LEASE_ETCD_TIMEOUT)?;
+        let success = response.is_success();
+        let body = String::from_utf8(response.body).map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "Etcd reply isn't utf-8"))?;
+
+        match success {
+            true => Ok(body),
+            false => Err(io::Error::other(format!("Etcd replied with status {}: {}", response.status, body))),
+        }
+    }
+
+    // Puts the owner in the key of the node identifier, attached to the lease, when the comparison holds
+    fn put_if(&self, node_identifier: u8, lease_identifier: &str, comparison: &str) -> io::Result<bool> {
+        let key = fsdkuid_encode_base64(format!("{}{}", self.key_prefix, node_identifier).as_bytes());
+        let request = format!(
+            r#"{{"compare":[{{"key":"{}","result":"EQUAL",{}}}],"success":[{{"request_put":{{"key":"{}","value":"{}","lease":"{}"}}}}]}}"#,
+            key,
+            comparison,
+            key,
+            fsdkuid_encode_base64(self.owner.as_bytes()),
+            lease_identifier
+        );
+
+        Ok(fsdkuid_json_value(&self.post("/v3/kv/txn", &request)?, "succeeded") == Some("true"))
+    }
+}
+
+impl NodeIdAllocator for EtcdAllocator {
+    // Grants a lease, then tries the node identifiers starting from one derived from the owner, so concurrent processes rarely race for the same key
+    fn claim(&mut self) -> io::Result<u8> {
+        if let Some(node_identifier) = self.node_identifier {
+            return Ok(node_identifier);
+        }
+
+        let grant = self.post("/v3/lease/grant", &format!(r#"{{"TTL":"{}"}}"#, self.lease_duration.as_secs()))?;
+        let lease_identifier = fsdkuid_json_value(&grant, "ID").filter(|identifier| !identifier.is_empty()).ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, format!("Unexpected etcd lease reply: {}", grant)))?.to_string();
+
+        let node_identifiers = self.max_node_identifier as u64 + 1;
+        let start = fsdkuid_hash_bytes(self.owner.bytes()) % node_identifiers;
+        let owned = format!(r#""target":"VALUE","value":"{}""#, fsdkuid_encode_base64(self.owner.as_bytes()));
+
+        for offset in 0..node_identifiers {
+            let node_identifier = ((start + offset) % node_identifiers) as u8;
+
+            if self.put_if(node_identifier, &lease_identifier, r#""target":"CREATE","create_revision":"0""#)? || self.put_if(node_identifier, &lease_identifier, &owned)? {
+                self.lease_identifier = Some(lease_identifier);
+                self.node_identifier = Some(node_identifier);
+                return Ok(node_identifier);
+            }
+        }
+
+        let _ = self.post("/v3/lease/revoke", &format!(r#"{{"ID":"{}"}}"#, lease_identifier));
+        Err(io::Error::other(format!("Every node identifier of {}* is leased by another process", self.key_prefix)))
+    }
+
+    // Sends a single keepalive, etcd replies without TTL when the lease already expired
+    fn renew(&mut self) -> io::Result<()> {
+        let (Some(node_identifier), Some(lease_identifier)) = (self.node_identifier, self.lease_identifier.clone()) else {
+            return Err(io::Error::new(io::ErrorKind::NotFound, "No node identifier is leased"));
+        };
+
+        let keepalive = self.post("/v3/lease/keepalive", &format!(r#"{{"ID":"{}"}}"#, lease_identifier))?;
+
+        if fsdkuid_json_value(&keepalive, "TTL").and_then(|ttl| ttl.parse::<i64>().ok()).unwrap_or(0) <= 0 {
+            self.lease_identifier = None;
+            self.node_identifier = None;
+            return Err(io::Error::new(io::ErrorKind::NotFound, format!("Lease of node identifier {} was lost", node_identifier)));
+        }
+
+        Ok(())
+    }
+
+    // Revokes the lease, which deletes its key
+    fn release(&mut self) -> io::Result<()> {
+        self.node_identifier = None;
+
+        if let Some(lease_identifier) = self.lease_identifier.take() {
+            self.post("/v3/lease/revoke", &format!(r#"{{"ID":"{}"}}"#, lease_identifier))?;
+        }
+
+        Ok(())
+    }
+
+    fn node_identifier(&self) -> Option<u8> {
+        self.node_identifier
+    }
+
+    fn lease_duration(&self) -> Duration {
+        self.lease_duration
+    }
+}
+
+// Releases the lease on a best effort basis, an unreleased lease is reclaimable once expired
+impl Drop for EtcdAllocator {
+    fn drop(&mut self) {
+        let _ = self.release();
+    }
+}
+
+// Leases node identifiers as consul KV keys (one per node identifier) locked by a consul session with a TTL, through the consul HTTP API.
+// The session is created with the delete behavior, so consul deletes the key when the session expires or is destroyed
+pub struct ConsulAllocator {
+    address: String,
+    key_prefix: String,
+    owner: String,
+    lease_duration: Duration,
+    max_node_identifier: u8,
+    session: Option<String>,
+    node_identifier: Option<u8>,
+}
+
+impl ConsulAllocator {
+    // The address is the host:port of a consul agent (ex: 127.0.0.1:8500) and the lease duration, rounded up to whole seconds, must be between 10 seconds and 24 hours
+    pub fn new(address: &str, owner: &str, lease_duration: Duration) -> Self {
+        let lease_duration = Duration::from_secs(lease_duration.as_secs() + (lease_duration.subsec_nanos() > 0) as u64);
+
+        if owner.is_empty() || lease_duration < FSDK_FSUID_LEASE_CONSUL_MIN_TTL || lease_duration > FSDK_FSUID_LEASE_CONSUL_MAX_TTL {
+            panic!("[ERROR in ConsulAllocator.new()] Lease owner should not be empty and lease duration should be between 10 seconds and 24 hours!");
+        }
+
+        ConsulAllocator {
+            address: address.to_string(),
+            key_prefix: FSDK_FSUID_LEASE_CONSUL_KEY_PREFIX.to_string(),
+            owner: owner.to_string(),
+            lease_duration,
+            max_node_identifier: FsdkUidLayout::FSUID.max_node_identifier(),
+            session: None,
+            node_identifier: None,
+        }
+    }
+
+    pub fn with_key_prefix(mut self, key_prefix: &str) -> Self {
+        self.key_prefix = key_prefix.to_string();
+        self
+    }
+
+    // Only leases the node identifiers of the layout
+    pub fn with_layout(mut self, layout: &FsdkUidLayout) -> Self {
+        self.max_node_identifier = layout.max_node_identifier();
+        self
+    }
+
+    // Returns the status and body of the reply, failing on statuses other than 200 and 404
+    fn request(&self, method: &str, path: &str, body: &str) -> io::Result<(u16, String)> {
+        let response = fsdkuid_http_request(&self.address, method, path, &[], body.as_bytes(), FSDK_FSUID_LEASE_CONSUL_TIMEOUT)?;
+        let status = response.status;
+        let body = String::from_utf8(response.body).map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "Consul reply isn't utf-8"))?;
+
+        match status {
+            200 | 404 => Ok((status, body)),
+            status => Err(io::Error::other(format!("Consul replied with status {}: {}", status, body))),
+        }
+    }
+
+    fn acquire(&self, key: &str, session: &str) -> io::Result<bool> {
+        Ok(self.request("PUT", &format!("/v1/kv/{}?acquire={}", key, session), &self.owner)?.1.trim() == "true")
+    }
+}
+
+impl NodeIdAllocator for ConsulAllocator {
+    // Creates a session, then tries the node identifiers starting from one derived from the owner, so concurrent processes rarely race for the same key.
+    // A key locked by a previous session of the same owner (ex: before a restart) is reclaimed by destroying that session
+    fn claim(&mut self) -> io::Result<u8> {
+        if let Some(node_identifier) = self.node_identifier {
+            return Ok(node_identifier);
+        }
+
+        let created = self.request("PUT", "/v1/session/create", &format!(r#"{{"Name":"fsdk-fsuid","TTL":"{}s","Behavior":"delete","LockDelay":"0s"}}"#, self.lease_duration.as_secs()))?.1;
+        let session = fsdkuid_json_value(&created, "ID").filter(|identifier| !identifier.is_empty()).ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, format!("Unexpected consul session reply: {}", created)))?.to_string();
+
+        let node_identifiers = self.max_node_identifier as u64 + 1;
+        let start = fsdkuid_hash_bytes(self.owner.bytes()) % node_identifiers;
+        let owned = fsdkuid_encode_base64(self.owner.as_bytes());
+
+        for offset in 0..node_identifiers {
+            let node_identifier = ((start + offset) % node_identifiers) as u8;
+            let key = format!("{}{}", self.key_prefix, node_identifier);
+
+            let mut acquired = self.acquire(&key, &session)?;
+            if !acquired {
+                let (status, entry) = self.request("GET", &format!("/v1/kv/{}", key), "")?;
+
+                if let (200, Some(previous_session)) = (status, fsdkuid_json_value(&entry, "Session")) {
+                    if fsdkuid_json_value(&entry, "Value") == Some(owned.as_str()) && previous_session != session {
+                        self.request("PUT", &format!("/v1/session/destroy/{}", previous_session), "")?;
+                        acquired = self.acquire(&key, &session)?;
+                    }
+                }
+            }
+
+            if acquired {
+                self.session = Some(session);
+                self.node_identifier = Some(node_identifier);
+                return Ok(node_identifier);
+            }
+        }
+
+        let _ = self.request("PUT", &format!("/v1/session/destroy/{}", session), "");
+        Err(io::Error::other(format!("Every node identifier of {}* is leased by another process", self.key_prefix)))
+    }
+
+    // Renews the session, consul replies 404 when it already expired
+    fn renew(&mut self) -> io::Result<()> {
+        let (Some(node_identifier), Some(session)) = (self.node_identifier, self.session.clone()) else {
+            return Err(io::Error::new(io::ErrorKind::NotFound, "No node identifier is leased"));
+        };
+
+        if self.request("PUT", &format!("/v1/session/renew/{}", session), "")?.0 == 404 {
+            self.session = None;
+            self.node_identifier = None;
+            return Err(io::Error::new(io::ErrorKind::NotFound, format!("Lease of node identifier {} was lost", node_identifier)));
+        }
+
+        Ok(())
+    }
+
+    // Destroys the session, which deletes its key
+    fn release(&mut self) -> io::Result<()> {
+        self.node_identifier = None;
+
+        if let Some(session) = self.session.take() {
+            self.request("PUT", &format!("/v1/session/destroy/{}", session), "")?;
+        }
+
+        Ok(())
+    }
+
+    fn node_identifier(&self) -> Option<u8> {
+        self.node_identifier
+    }
+
+    fn lease_duration(&self) -> Duration {
+        self.lease_duration
+    }
+}
+
+// Releases the lease on a best effort basis, an unreleased lease is reclaimable once expired
+impl Drop for ConsulAllocator {
+    fn drop(&mut self) {
+        let _ = self.release();
+    }
+}
+
+// Leases node identifiers as exclusive locks on files (fsdk-fsuid-node-<node identifier>.lock) of a directory, so processes of the same host never share a node identifier.
+// The operating system releases the lock when the process exits, even when it crashes, so leases never need to be renewed or expire
+pub struct FileLockAllocator {
+    directory: PathBuf,
+    max_node_identifier: u8,
+    lock: Option<(u8, File)>,
+}
+
+impl FileLockAllocator {
+    pub fn new(directory: impl AsRef<Path>) -> Self {
+        FileLockAllocator { directory: directory.as_ref().to_path_buf(), max_node_identifier: FsdkUidLayout::FSUID.max_node_identifier(), lock: None }
+    }
+
+    // Only leases the node identifiers of the layout
+    pub fn with_layout(mut self, layout: &FsdkUidLayout) -> Self {
+        self.max_node_identifier = layout.max_node_identifier();
+        self
+    }
+}
+
+impl NodeIdAllocator for FileLockAllocator {
+    // Tries the node identifiers in ascending order, writing the process id in the locked file to find which process holds it
+    fn claim(&mut self) -> io::Result<u8> {
+        if let Some((node_identifier, _)) = self.lock {
+            return Ok(node_identifier);
+        }
+
+        fs::create_dir_all(&self.directory)?;
+
+        for node_identifier in 0..=self.max_node_identifier {
+            let path = self.directory.join(format!("fsdk-fsuid-node-{}.lock", node_identifier));
+            let mut file = OpenOptions::new().read(true).write(true).create(true).truncate(false).open(&path)?;
+
+            match file.try_lock() {
+                Ok(()) => {
+                    file.set_len(0)?;
+                    writeln!(file, "{}", std::process::id())?;
+
+                    self.lock = Some((node_identifier, file));
+                    return Ok(node_identifier);
+                }
+                Err(TryLockError::WouldBlock) => continue,
+                Err(TryLockError::Error(error)) => return Err(error),
+            }
+        }
+
+        Err(io::Error::other(format!("Every node identifier of {} is locked by another process", self.directory.display())))
+    }
+
+    fn renew(&mut self) -> io::Result<()> {
+        match self.lock {
+            Some(_) => Ok(()),
+            None => Err(io::Error::new(io::ErrorKind::NotFound, "No node identifier is leased")),
+        }
+    }
+
+    // Unlocks the file by closing it, the file is kept so processes waiting on it don't race with its removal
+    fn release(&mut self) -> io::Result<()> {
+        self.lock = None;
+        Ok(())
+    }
+
+    fn node_identifier(&self) -> Option<u8> {
+        self.lock.as_ref().map(|(node_identifier, _)| *node_identifier)
+    }
+
+    fn lease_duration(&self) -> Duration {
+        FSDK_FSUID_LEASE_FILE_DURATION
+    }
+}
+
+// Padded standard base64 (RFC 4648 section 4), the encoding of bytes in the JSON of etcd and consul
+fn fsdkuid_encode_base64(bytes: &[u8]) -> String {
+    let mut base64: String = fsdkuid_encode_base64url_bytes(bytes)
+        .chars()
+        .map(|symbol| match symbol {
+            '-' => '+',
+            '_' => '/',
+            symbol => symbol,
+        })
+        .collect();
+
+    while !base64.len().is_multiple_of(4) {
+        base64.push('=');
+    }
+
+    base64
+}
+
+// Time source of the lease expiry, replaced in tests to expire leases without depending on how the heartbeat thread is scheduled
+pub(crate) trait LeaseClock: Send + Sync {
+    fn now(&self) -> Instant;
+}
+
+struct SystemLeaseClock;
+
+impl LeaseClock for SystemLeaseClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+struct NodeIdLeaseState {
+    renewed: Instant, // Last successful claim or renewal, from the lease clock
+    lost: bool,
+    lost_hook: Option<Box<dyn Fn(u8) + Send>>,
+}
+
+// Node identifier claimed from an allocator and renewed by a background heartbeat thread until the lease is handed off or dropped, which releases it.
+// Failed renewals are retried on the next heartbeat, and the lease is lost when the allocator reports it lost or no renewal succeeded for two thirds of the lease duration,
+// leaving a heartbeat of margin before another process can claim the node identifier
+pub struct NodeIdLease<A: NodeIdAllocator + 'static> {
+    allocator: Arc<Mutex<A>>,
+    node_identifier: u8,
+    valid_duration: Duration,
+    clock: Arc<dyn LeaseClock>,
+    state: Arc<Mutex<NodeIdLeaseState>>,
+    stop: Arc<(Mutex<bool>, Condvar)>,
+    heartbeat: Option<JoinHandle<()>>,
+}
+
+impl<A: NodeIdAllocator + 'static> NodeIdLease<A> {
+    pub fn claim(allocator: A) -> io::Result<Self> {
+        NodeIdLease::claim_with_clock(allocator, Arc::new(SystemLeaseClock))
+    }
+
+    pub(crate) fn claim_with_clock(mut allocator: A, clock: Arc<dyn LeaseClock>) -> io::Result<Self> {
+        let node_identifier = allocator.claim()?;
+        let lease_duration = allocator.lease_duration();
+        let interval = lease_duration / FSDK_FSUID_LEASE_HEARTBEATS;
+        let valid_duration = lease_duration - interval;
+
+        let allocator = Arc::new(Mutex::new(allocator));
+        let state = Arc::new(Mutex::new(NodeIdLeaseState { renewed: clock.now(), lost: false, lost_hook: None }));
+        let stop = Arc::new((Mutex::new(false), Condvar::new()));
+
+        let heartbeat = {
+            let (allocator, clock, state, stop) = (allocator.clone(), clock.clone(), state.clone(), stop.clone());
+
+            thread::spawn(move || loop {
+                let (stopped, condvar) = &*stop;
+                let stopped = condvar.wait_timeout_while(stopped.lock().unwrap_or_else(|poisoned| poisoned.into_inner()), interval, |stopped| !*stopped).unwrap_or_else(|poisoned| poisoned.into_inner()).0;
+                if *stopped {
+                    return;
+                }
+                drop(stopped);
+
+                let renewal = allocator.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).renew();
+                let mut state = state.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+
+                match renewal {
+                    Ok(()) => state.renewed = clock.now(),
+                    Err(error) if error.kind() == io::ErrorKind::NotFound || clock.now().saturating_duration_since(state.renewed) >= valid_duration => {
+                        state.lost = true;
+                        if let Some(lost_hook) = state.lost_hook.take() {
+                            drop(state);
+                            lost_hook(node_identifier);
+                        }
+                        return;
+                    }
+                    Err(_) => {}
+                }
+            })
+        };
+
+        Ok(NodeIdLease { allocator, node_identifier, valid_duration, clock, state, stop, heartbeat: Some(heartbeat) })
+    }
+
+    // Calls the hook from the heartbeat thread when the lease is lost, ex: to log an error or to stop the service
+    pub fn with_lost_hook(self, lost_hook: impl Fn(u8) + Send + 'static) -> Self {
+        self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).lost_hook = Some(Box::new(lost_hook));
+        self
+    }
+
+    pub fn node_identifier(&self) -> u8 {
+        self.node_identifier
+    }
+
+    // A lost lease may be claimed by another process, FSUID's must stop being generated with its node identifier.
+    // Also checked from the last renewal, in case the heartbeat thread is late
+    pub fn is_lost(&self) -> bool {
+        let state = self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        state.lost || self.clock.now().saturating_duration_since(state.renewed) >= self.valid_duration
+    }
+
+    // Stops renewing and releases the lease for graceful shutdowns, returning the release error that dropping the lease ignores
+    pub fn handoff(mut self) -> io::Result<()> {
+        self.shutdown()
+    }
+
+    fn shutdown(&mut self) -> io::Result<()> {
+        let Some(heartbeat) = self.heartbeat.take() else {
+            return Ok(());
+        };
+
+        let (stopped, condvar) = &*self.stop;
+        *stopped.lock().unwrap_or_else(|poisoned| poisoned.into_inner()) = true;
+        condvar.notify_all();
+        let _ = heartbeat.join();
+
+        self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).lost = true;
+        self.allocator.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).release()
+    }
+}
+
+impl<A: NodeIdAllocator + 'static> Drop for NodeIdLease<A> {
+    fn drop(&mut self) {
+        let _ = self.shutdown();
+    }
+}
+
+// Generator using the node identifier of a lease, refusing to generate FSUID's once the lease is lost instead of risking duplicates with the next holder of the node identifier
+pub struct FsdkUidLeasedGenerator<A: NodeIdAllocator + 'static> {
+    lease: NodeIdLease<A>,
+    generator: FsdkUidGenerator,
+}
+
+impl<A: NodeIdAllocator + 'static> FsdkUidLeasedGenerator<A> {
+    pub fn new(lease: NodeIdLease<A>, layout: FsdkUidLayout) -> Self {
+        let generator = FsdkUidGenerator::with_layout(lease.node_identifier(), layout);
+        FsdkUidLeasedGenerator { lease, generator }
+    }
+
+    pub fn lease(&self) -> &NodeIdLease<A> {
+        &self.lease
+    }
+
+    pub fn generate_i64(&self) -> Result<i64, FsdkUidError> {
+        if self.lease.is_lost() {
+            return Err(FsdkUidError::LeaseLost);
+        }
+
+        let fsuid = self.generator.generate_i64();
+
+        // The lease may have been lost while waiting for an exhausted node counter
+        match self.lease.is_lost() {
+            true => Err(FsdkUidError::LeaseLost),
+            false => Ok(fsuid),
+        }
+    }
+
+    pub fn generate_fsuid(&self) -> Result<FsdkUid, FsdkUidError> {
+        self.generate_i64().map(FsdkUid::new)
+    }
+
+    // Stops generating, waits for the current timestamp tick to end so the next holder of the node identifier can't generate the FSUID's of this tick, then releases the lease
+    pub fn handoff(self) -> io::Result<()> {
+        let layout = self.generator.layout();
+        let last_ticks = layout.current_timestamp_ticks();
+
+        while layout.current_timestamp_ticks() == last_ticks {
+            thread::sleep(Duration::from_millis(layout.resolution_milliseconds().min(FSDK_FSUID_LEASE_HANDOFF_POLL_MILLISECONDS)));
+        }
+
+        self.lease.handoff()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::{fsdkuid_http_test_response, fsdkuid_http_test_server};
+    use crate::redis::fsdkuid_redis_test_server;
+    use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+
+    // Leases node identifier 1, counting renewals and failing them while unavailable
+    struct MemoryAllocator {
+        renewals: Arc<AtomicU32>,
+        released: Arc<AtomicBool>,
+        available: Arc<AtomicBool>,
+        lost: bool,
+    }
+
+    impl NodeIdAllocator for MemoryAllocator {
+        fn claim(&mut self) -> io::Result<u8> {
+            Ok(1)
+        }
+
+        fn renew(&mut self) -> io::Result<()> {
+            self.renewals.fetch_add(1, Ordering::SeqCst);
+
+            match (self.lost, self.available.load(Ordering::SeqCst)) {
+                (true, _) => Err(io::Error::new(io::ErrorKind::NotFound, "lost")),
+                (false, true) => Ok(()),
+                (false, false) => Err(io::Error::new(io::ErrorKind::ConnectionRefused, "unavailable")),
+            }
+        }
+
+        fn release(&mut self) -> io::Result<()> {
+            self.released.store(true, Ordering::SeqCst);
+            Ok(())
+        }
+
+        fn node_identifier(&self) -> Option<u8> {
+            Some(1)
+        }
+
+        fn lease_duration(&self) -> Duration {
+            Duration::from_millis(60)
+        }
+    }
+
+    fn memory_allocator(lost: bool, available: bool) -> (MemoryAllocator, Arc<AtomicU32>, Arc<AtomicBool>, Arc<AtomicBool>) {
+        let (renewals, released, available) = (Arc::new(AtomicU32::new(0)), Arc::new(AtomicBool::new(false)), Arc::new(AtomicBool::new(available)));
+        (MemoryAllocator { renewals: renewals.clone(), released: released.clone(), available: available.clone(), lost }, renewals, released, available)
+    }
+
+    // Lease clock only moving when the test advances it, so the lease expiry doesn't depend on when the heartbeat thread is scheduled
+    struct ManualLeaseClock {
+        now: Mutex<Instant>,
+    }
+
+    impl ManualLeaseClock {
+        fn advance(&self, duration: Duration) {
+            *self.now.lock().unwrap() += duration;
+        }
+    }
+
+    impl LeaseClock for ManualLeaseClock {
+        fn now(&self) -> Instant {
+            *self.now.lock().unwrap()
+        }
+    }
+
+    fn manual_lease_clock() -> Arc<ManualLeaseClock> {
+        Arc::new(ManualLeaseClock { now: Mutex::new(Instant::now()) })
+    }
+
+    // Polls the condition until it holds, with a deadline generous enough for a loaded machine
+    fn eventually(condition: impl Fn() -> bool) -> bool {
+        let deadline = Instant::now() + Duration::from_secs(10);
+
+        while !condition() {
+            if Instant::now() >= deadline {
+                return false;
+            }
+            thread::sleep(Duration::from_millis(1));
+        }
+
+        true
+    }
+
+    #[test]
+    fn test_fsdkuid_node_id_lease_heartbeat() {
+        let (allocator, renewals, released, _) = memory_allocator(false, true);
+        let lease = NodeIdLease::claim_with_clock(allocator, manual_lease_clock()).unwrap();
+        assert_eq!(lease.node_identifier(), 1);
+
+        assert!(eventually(|| renewals.load(Ordering::SeqCst) >= 3), "[NodeIdLease Error] Lease must be renewed in the background");
+        assert!(!lease.is_lost());
+
+        drop(lease);
+        assert!(released.load(Ordering::SeqCst), "[NodeIdLease Error] Lease must be released when dropped");
+    }
+
+    #[test]
+    fn test_fsdkuid_node_id_lease_lost() {
+        let (allocator, _, _, _) = memory_allocator(true, true);
+        let lease = NodeIdLease::claim_with_clock(allocator, manual_lease_clock()).unwrap();
+        assert!(eventually(|| lease.is_lost()), "[NodeIdLease Error] Lease must be lost when the allocator reports it lost");
+
+        let clock = manual_lease_clock();
+        let (allocator, renewals, _, available) = memory_allocator(false, false);
+        let lease = NodeIdLease::claim_with_clock(allocator, clock.clone()).unwrap();
+        assert!(eventually(|| renewals.load(Ordering::SeqCst) >= 2));
+        assert!(!lease.is_lost(), "[NodeIdLease Error] Failed renewals must be retried before the lease expires");
+
+        clock.advance(Duration::from_millis(40)); // Two thirds of the lease duration
+        assert!(lease.is_lost(), "[NodeIdLease Error] Lease must be lost when no renewal succeeded for two thirds of the lease duration");
+        assert!(eventually(|| lease.state.lock().unwrap().lost), "[NodeIdLease Error] Heartbeat must stop renewing once the lease expired");
+        available.store(true, Ordering::SeqCst);
+    }
+
+    #[test]
+    fn test_fsdkuid_leased_generator_stops_when_lost() {
+        let (allocator, _, released, _) = memory_allocator(false, true);
+        let generator = FsdkUidLeasedGenerator::new(NodeIdLease::claim_with_clock(allocator, manual_lease_clock()).unwrap(), FsdkUidLayout::FSUID);
+        let fsuid = generator.generate_fsuid().unwrap();
+        assert_eq!(fsuid.node_identifier(), 1);
+
+        generator.handoff().unwrap();
+        assert!(released.load(Ordering::SeqCst), "[FsdkUidLeasedGenerator.handoff() Error] Lease must be released by a handoff");
+        assert!(FsdkUidLayout::FSUID.current_timestamp_ticks() > fsuid.timestamp_delta() as u64, "[FsdkUidLeasedGenerator.handoff() Error] Handoff must wait for the last timestamp tick to end");
+
+        let lost_node = Arc::new(AtomicU32::new(u32::MAX));
+        let (allocator, _, _, _) = memory_allocator(false, false);
+        let reported = lost_node.clone();
+        let clock = manual_lease_clock();
+        let lease = NodeIdLease::claim_with_clock(allocator, clock.clone()).unwrap().with_lost_hook(move |node_identifier| reported.store(node_identifier as u32, Ordering::SeqCst));
+        let generator = FsdkUidLeasedGenerator::new(lease, FsdkUidLayout::FSUID);
+        clock.advance(Duration::from_millis(40)); // Expires the lease after the hook is set, the next failed renewal reports it lost
+
+        assert!(eventually(|| lost_node.load(Ordering::SeqCst) == 1), "[NodeIdLease Error] Lost hook must be called with the node identifier");
+        assert_eq!(generator.generate_i64(), Err(FsdkUidError::LeaseLost), "[FsdkUidLeasedGenerator.generate_i64() Error] FSUID's must not be generated once the lease is lost");
+    }
+
+    #[test]
+    fn test_fsdkuid_etcd_allocator() {
+        assert_eq!(fsdkuid_encode_base64(b"fsdk/fsuid/node/1"), "ZnNkay9mc3VpZC9ub2RlLzE=");
+
+        let reply = |body: &str| fsdkuid_http_test_response("200 OK", body);
+        let (address, etcd) = fsdkuid_http_test_server(vec![
+            reply(r#"{"ID":"7587862072","TTL":"2"}"#),
+            reply(r#"{"header":{}}"#),
+            reply(r#"{"header":{}}"#),
+            reply(r#"{"header":{},"succeeded":true}"#),
+            reply(r#"{"result":{"ID":"7587862072","TTL":2}}"#),
+            reply(r#"{"result":{"ID":"7587862072"}}"#),
+        ]);
+
+        let layout = FsdkUidLayout::new(0, 1, 48, 1, 8); // Node identifiers 0 and 1
+        let mut allocator = EtcdAllocator::new(&address, "host", Duration::from_millis(1500)).with_layout(&layout);
+        assert_eq!(allocator.lease_duration(), Duration::from_secs(2));
+
+        let node_identifier = allocator.claim().unwrap();
+        allocator.renew().unwrap();
+        assert_eq!(allocator.renew().unwrap_err().kind(), io::ErrorKind::NotFound, "[EtcdAllocator.renew() Error] Renewal of an expired lease must fail");
+        assert_eq!(allocator.node_identifier(), None);
+
+        let requests = etcd.join().unwrap();
+        assert!(requests[0].starts_with("POST /v3/lease/grant ") && requests[0].ends_with(r#"{"TTL":"2"}"#));
+        assert!(requests[1].contains(r#""target":"CREATE","create_revision":"0""#) && requests[2].contains(r#""target":"VALUE""#), "[EtcdAllocator.claim() Error] Key must be created or reclaimed by its owner");
+        assert!(requests[3].contains(&format!(r#""key":"{}""#, fsdkuid_encode_base64(format!("fsdk/fsuid/node/{}", node_identifier).as_bytes()))) && requests[3].contains(r#""lease":"7587862072""#));
+        assert!(requests[4].ends_with(r#"{"ID":"7587862072"}"#));
+    }
+
+    #[test]
+    fn test_fsdkuid_consul_allocator() {
+        let owned = format!(r#"[{{"Key":"fsdk/fsuid/node/0","Value":"{}","Session":"previous"}}]"#, fsdkuid_encode_base64(b"host"));
+        let (address, consul) = fsdkuid_http_test_server(vec![
+            fsdkuid_http_test_response("200 OK", r#"{"ID":"adf4238a-882b-9ddc-4a9d-5b6758e4159e"}"#),
+            fsdkuid_http_test_response("200 OK", "false"),
+            fsdkuid_http_test_response("200 OK", r#"[{"Key":"fsdk/fsuid/node/1","Value":"b3RoZXI=","Session":"other"}]"#),
+            fsdkuid_http_test_response("200 OK", "false"),
+            fsdkuid_http_test_response("200 OK", &owned),
+            fsdkuid_http_test_response("200 OK", "true"),
+            fsdkuid_http_test_response("200 OK", "true"),
+            fsdkuid_http_test_response("200 OK", "[]"),
+            fsdkuid_http_test_response("404 Not Found", ""),
+        ]);
+
+        let layout = FsdkUidLayout::new(0, 1, 48, 1, 8); // Node identifiers 0 and 1
+        let mut allocator = ConsulAllocator::new(&address, "host", Duration::from_secs(15)).with_layout(&layout);
+        let node_identifier = allocator.claim().unwrap();
+        allocator.renew().unwrap();
+        assert_eq!(allocator.renew().unwrap_err().kind(), io::ErrorKind::NotFound, "[ConsulAllocator.renew() Error] Renewal of an expired session must fail");
+
+        let requests = consul.join().unwrap();
+        assert!(requests[0].starts_with("PUT /v1/session/create ") && requests[0].ends_with(r#"{"Name":"fsdk-fsuid","TTL":"15s","Behavior":"delete","LockDelay":"0s"}"#));
+        assert!(requests[1].starts_with(&format!("PUT /v1/kv/fsdk/fsuid/node/{}?acquire=adf4238a-882b-9ddc-4a9d-5b6758e4159e ", 1 - node_identifier)) && requests[1].ends_with("|host"));
+        assert!(requests[3].starts_with(&format!("PUT /v1/kv/fsdk/fsuid/node/{}?acquire=", node_identifier)));
+        assert!(requests[5].starts_with("PUT /v1/session/destroy/previous "), "[ConsulAllocator.claim() Error] Key locked by a previous session of the owner must be reclaimed");
+        assert!(requests[8].starts_with("PUT /v1/session/renew/adf4238a-882b-9ddc-4a9d-5b6758e4159e "));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_fsdkuid_consul_allocator_rejects_short_lease() {
+        ConsulAllocator::new("127.0.0.1:8500", "host", Duration::from_secs(5));
+    }
+
+    #[test]
+    fn test_fsdkuid_file_lock_allocator() {
+        let directory = std::env::temp_dir().join(format!("fsdk_fsuid_locks_{}", std::process::id()));
+        let layout = FsdkUidLayout::new(0, 1, 48, 1, 8); // Node identifiers 0 and 1
+
+        let mut first = FileLockAllocator::new(&directory).with_layout(&layout);
+        let mut second = FileLockAllocator::new(&directory).with_layout(&layout);
+        assert_eq!(first.claim().unwrap(), 0);
+        assert_eq!(second.claim().unwrap(), 1, "[FileLockAllocator.claim() Error] Node identifier locked by another allocator must be skipped");
+        assert!(FileLockAllocator::new(&directory).with_layout(&layout).claim().is_err(), "[FileLockAllocator.claim() Error] Claim must fail when every node identifier is locked");
+        assert_eq!(fs::read_to_string(directory.join("fsdk-fsuid-node-1.lock")).unwrap().trim(), std::process::id().to_string());
+
+        first.release().unwrap();
+        assert_eq!(first.renew().unwrap_err().kind(), io::ErrorKind::NotFound);
+        assert_eq!(FileLockAllocator::new(&directory).with_layout(&layout).claim().unwrap(), 0, "[FileLockAllocator.claim() Error] Released node identifier must be claimable");
+
+        second.renew().unwrap();
+        drop(second);
+        fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn test_fsdkuid_redis_allocator() {
+        let (address, redis) = fsdkuid_redis_test_server(vec![b":0\r\n", b":1\r\n", b":1\r\n", b":0\r\n", b":1\r\n", b":1\r\n", b"+OK\r\n"]);
+
+        let layout = FsdkUidLayout::new(0, 1, 48, 1, 8); // Node identifiers 0 and 1
+        let mut allocator = RedisAllocator::new(&address, "host", Duration::from_secs(30)).with_layout(&layout);
+        let node_identifier = allocator.claim().unwrap();
+        allocator.renew().unwrap();
+        assert_eq!(allocator.renew().unwrap_err().kind(), io::ErrorKind::NotFound, "[RedisAllocator.renew() Error] Renewal of a key held by another owner must fail");
+        assert_eq!(allocator.node_identifier(), None, "[RedisAllocator.renew() Error] Lost lease must be forgotten");
+
+        assert_eq!(allocator.claim().unwrap(), 1 - node_identifier);
+        allocator.release().unwrap();
+        assert_eq!(allocator.node_identifier(), None);
+        assert_eq!(allocator.claim().unwrap_err().kind(), io::ErrorKind::InvalidData, "[RedisAllocator.claim() Error] Reply other than an integer must fail");
+
+        let commands = redis.join().unwrap();
+        let key = |node_identifier: u8| format!("fsdk:fsuid:node:{}", node_identifier).into_bytes();
+        let eval = |script: &str, node_identifier: u8| vec![b"EVAL".to_vec(), script.as_bytes().to_vec(), b"1".to_vec(), key(node_identifier), b"host".to_vec(), b"30000".to_vec()];
+        assert_eq!(commands[0], eval(FSDK_FSUID_LEASE_REDIS_CLAIM_SCRIPT, 1 - node_identifier));
+        assert_eq!(commands[1], eval(FSDK_FSUID_LEASE_REDIS_CLAIM_SCRIPT, node_identifier), "[RedisAllocator.claim() Error] Key held by another owner must be skipped");
+        assert_eq!(commands[2], eval(FSDK_FSUID_LEASE_REDIS_RENEW_SCRIPT, node_identifier));
+        assert_eq!(commands[3], eval(FSDK_FSUID_LEASE_REDIS_RENEW_SCRIPT, node_identifier));
+        assert_eq!(commands[4], eval(FSDK_FSUID_LEASE_REDIS_CLAIM_SCRIPT, 1 - node_identifier));
+        assert_eq!(commands[5], eval(FSDK_FSUID_LEASE_REDIS_RELEASE_SCRIPT, 1 - node_identifier));
+    }
+
+    #[test]
+    fn test_fsdkuid_redis_allocator_unreachable() {
+        let mut allocator = RedisAllocator::new("127.0.0.1:1", "host", Duration::from_secs(30));
+        assert!(allocator.claim().is_err(), "[RedisAllocator.claim() Error] Claim must fail when redis isn't reachable");
+        assert_eq!(allocator.node_identifier(), None);
+    }
+}
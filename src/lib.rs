@@ -1,15 +1,207 @@
-use std::sync::atomic::{AtomicU8, Ordering};
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "std")]
+use std::cmp::Ordering as CmpOrdering;
+#[cfg(feature = "std")]
+use std::fmt;
+#[cfg(feature = "std")]
+use std::sync::atomic::{AtomicU64, AtomicU8, Ordering};
+#[cfg(feature = "std")]
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
+#[cfg(feature = "std")]
 use chrono::{DateTime, Utc};
 
+#[cfg(feature = "std")]
+mod archive;
+#[cfg(feature = "std")]
+mod arrow;
+#[cfg(feature = "std")]
+mod avro;
+#[cfg(feature = "cloud-metadata")]
+mod cloud;
+#[cfg(feature = "std")]
+mod conformance;
+#[cfg(feature = "std")]
+mod config;
+#[cfg(feature = "std")]
+mod cursor;
+#[cfg(feature = "std")]
+mod dualwrite;
+#[cfg(feature = "std")]
+mod dynamodb;
+mod embedded;
+#[cfg(feature = "std")]
+mod encoding;
+#[cfg(feature = "std")]
+mod era;
+mod error;
+#[cfg(feature = "std")]
+mod fsuid128;
+#[cfg(feature = "std")]
+mod hmac;
+#[cfg(feature = "std")]
+mod http;
+#[cfg(feature = "std")]
+mod ksuid;
+#[cfg(feature = "std")]
+mod layout;
+#[cfg(feature = "std")]
+mod lease;
+#[cfg(feature = "std")]
+mod manager;
+#[cfg(feature = "std")]
+mod migrate;
+#[cfg(feature = "std")]
+mod node_id;
+#[cfg(feature = "std")]
+mod obfuscate;
+#[cfg(feature = "std")]
+mod objectid;
+#[cfg(feature = "std")]
+mod otel;
+#[cfg(feature = "std")]
+mod parse;
+#[cfg(feature = "std")]
+mod partition;
+#[cfg(feature = "std")]
+mod prefixed;
+#[cfg(feature = "std")]
+mod proto;
+#[cfg(feature = "std")]
+mod range;
+#[cfg(feature = "std")]
+mod rate;
+#[cfg(feature = "std")]
+mod redact;
+#[cfg(feature = "std")]
+mod region;
+#[cfg(feature = "std")]
+mod redis;
+#[cfg(feature = "std")]
+mod registry;
+#[cfg(feature = "std")]
+mod ring;
+#[cfg(feature = "std")]
+mod schema;
+#[cfg(feature = "std")]
+mod score;
+#[cfg(feature = "std")]
+mod shard;
+#[cfg(feature = "std")]
+mod shared;
+#[cfg(feature = "std")]
+mod signed;
+#[cfg(feature = "std")]
+mod snapshot;
+#[cfg(feature = "std")]
+mod snowflake;
+#[cfg(feature = "std")]
+mod stream;
+#[cfg(feature = "std")]
+mod striped;
+#[cfg(feature = "std")]
+mod timeuuid;
+#[cfg(feature = "std")]
+mod ulid;
+#[cfg(feature = "std")]
+mod uri;
+#[cfg(feature = "std")]
+mod uuid;
+#[cfg(feature = "std")]
+mod varint;
+#[cfg(feature = "std")]
+mod vectors;
+#[cfg(feature = "std")]
+mod watermark;
+
+#[cfg(feature = "std")]
+pub use archive::{fsdkuid_archive, FsdkUidArchive};
+#[cfg(feature = "std")]
+pub use arrow::{fsdkuid_arrow_extension_metadata, fsdkuid_arrow_extension_metadata_with_layout, fsdkuid_from_i64_column, fsdkuid_is_arrow_extension, fsdkuid_to_i64_column, FSDK_FSUID_ARROW_EXTENSION_METADATA_KEY, FSDK_FSUID_ARROW_EXTENSION_NAME, FSDK_FSUID_ARROW_EXTENSION_NAME_KEY};
+#[cfg(feature = "std")]
+pub use avro::{FSDK_FSUID_AVRO_LOGICAL_TYPE, FSDK_FSUID_AVRO_SCHEMA};
+#[cfg(feature = "std")]
+pub use conformance::FsdkUidConformanceVector;
+#[cfg(feature = "std")]
+pub use config::FsdkUidGeneratorConfig;
+#[cfg(feature = "std")]
+pub use cursor::{Cursor, CursorDirection};
+#[cfg(feature = "std")]
+pub use dualwrite::{FsdkUidDualWriteAnomaly, FsdkUidDualWriteReport, FsdkUidDualWriteVerifier};
+pub use embedded::{fsdkuid_format_base62, fsdkuid_format_decimal, fsdkuid_parse_base62, fsdkuid_parse_decimal, FSDK_FSUID_BASE62_MAX_LENGTH, FSDK_FSUID_DECIMAL_MAX_LENGTH};
+#[cfg(feature = "std")]
+pub use encoding::{Base58, Base62, Base64Url, CrockfordBase32, Encoding, FixedDecimal, FsdkAlphabet, Sortable};
+#[cfg(feature = "std")]
+pub use era::{FsdkUidEra, FsdkUidEraConfidence, FsdkUidEraDetection, FsdkUidEraDetector, FsdkUidScheme};
+pub use error::FsdkUidError;
+#[cfg(feature = "std")]
+pub use fsuid128::{FsdkUid128, FsdkUidGenerator128};
+#[cfg(feature = "std")]
+pub use layout::{FsdkUidLayout, FSDK_FSUID_JS_MAX_SAFE_INTEGER, FSDK_FSUID_LAYOUT_EPOCH_MILLISECONDS};
+#[cfg(feature = "std")]
+pub use lease::{ConsulAllocator, EtcdAllocator, FileLockAllocator, FsdkUidLeasedGenerator, NodeIdLease, RedisAllocator};
+#[cfg(feature = "std")]
+pub use manager::{GeneratorManager, ManagedGenerator};
+#[cfg(feature = "std")]
+pub use migrate::{fsdkuid_migrate, FsdkUidMigrator};
+#[cfg(feature = "std")]
+pub use node_id::{NodeIdAllocator, NodeIdSource, SqlAllocator, SqlDialect, SqlExecutor, SqlParameter};
+#[cfg(feature = "std")]
+pub use otel::FSDK_FSUID_OTEL_ATTRIBUTE_KEY;
+#[cfg(feature = "std")]
+pub use partition::Granularity;
+#[cfg(feature = "std")]
+pub use prefixed::PrefixedFsdkUid;
+#[cfg(feature = "std")]
+pub use proto::{FSDK_FSUID_PROTO_DEFINITION, FSDK_FSUID_PROTO_LENGTH};
+#[cfg(feature = "std")]
+pub use range::fsdkuid_mongodb_time_range_filter;
+#[cfg(feature = "std")]
+pub use rate::{FsdkUidRateCoordinator, FsdkUidRedisTokenBucket, FsdkUidTokenBucket};
+#[cfg(feature = "std")]
+pub use redact::{FsdkUidRedaction, RedactedFsdkUid};
+#[cfg(feature = "std")]
+pub use region::FsdkUidRegionRegistry;
+#[cfg(feature = "std")]
+pub use registry::FsdkUidDuplicatePolicy;
+#[cfg(feature = "std")]
+pub use ring::FsdkUidHashRing;
+#[cfg(feature = "std")]
+pub use schema::{FSDK_FSUID_JSON_SCHEMA_BASE62, FSDK_FSUID_JSON_SCHEMA_DECIMAL, FSDK_FSUID_JSON_SCHEMA_INTEGER};
+#[cfg(feature = "std")]
+pub use shard::FsdkUidShardStrategy;
+#[cfg(feature = "std")]
+pub use shared::FsdkUidSharedGenerator;
+#[cfg(feature = "std")]
+pub use signed::SignedFsdkUid;
+#[cfg(feature = "std")]
+pub use snapshot::FsdkUidGeneratorSnapshot;
+#[cfg(feature = "std")]
+pub use snowflake::{ForeignId, ForeignLayout, SnowflakeLayout};
+#[cfg(feature = "std")]
+pub use striped::{FsdkUidStripeMetrics, FsdkUidStripedGenerator};
+#[cfg(feature = "std")]
+pub use varint::{FsdkUidVarintMode, FSDK_FSUID_VARINT_MAX_LENGTH};
+#[cfg(feature = "std")]
+pub use vectors::{FsdkUidTestVector, FSDK_FSUID_TEST_VECTORS};
+#[cfg(feature = "std")]
+pub use watermark::{FsdkUidFileWatermarkStore, FsdkUidWatermarkPolicy, FsdkUidWatermarkStore, FsdkUidWatermarkedGenerator};
+
+#[cfg(feature = "std")]
 const FSDK_FSUID_TIMESTAMP_DELTA_BITS: u8 = 48; // Number of bits used to represent the milliseconds passed since the unix timestamp when a FSUID was generated
+#[cfg(feature = "std")]
 const FSDK_FSUID_NODE_IDENTIFIER_BITS: u8 = 8; // Number of bits used to represent the node identifier number, used to prevent collisions between FSUID's and identify which decentralized FSUID node generated the FSUID
+#[cfg(feature = "std")]
 const FSDK_FSUID_NODE_COUNTER_BITS: u8 = 8; //  Number of bits used to represent the node counter, used to prevent collisions between FSUID's between the same node and determine the order of FSUID generation within the same millisecond
 
+#[cfg(feature = "std")]
 const FSDK_FSUID_MAX_TIMESTAMP_DELTA: u64 = (1 << FSDK_FSUID_TIMESTAMP_DELTA_BITS) - 1; // Max timestamp delta that can be represented with FSDK_FSUID_TIMESTAMP_DELTA_BITS before overflow occurs
-const FSDK_FSUID_MAX_NODE_IDENTIFIER: u8 = ((1 << (FSDK_FSUID_NODE_IDENTIFIER_BITS - 1))) +  ((1 << (FSDK_FSUID_NODE_IDENTIFIER_BITS - 1)) - 1); // Max node identifier that can be represented with FSDK_FSUID_NODE_IDENTIFIER_BITS before overflow occurs
-const FSDK_FSUID_MAX_NODE_COUNTER: u8 = ((1 << (FSDK_FSUID_NODE_COUNTER_BITS - 1))) +  ((1 << (FSDK_FSUID_NODE_COUNTER_BITS - 1)) - 1); // Max node counter that can be represented with FSDK_FSUID_NODE_COUNTER_BITS before overflow occurs
+#[cfg(feature = "std")]
+const FSDK_FSUID_MAX_NODE_IDENTIFIER: u8 = (1 << (FSDK_FSUID_NODE_IDENTIFIER_BITS - 1)) +  ((1 << (FSDK_FSUID_NODE_IDENTIFIER_BITS - 1)) - 1); // Max node identifier that can be represented with FSDK_FSUID_NODE_IDENTIFIER_BITS before overflow occurs
+#[cfg(feature = "std")]
+const FSDK_FSUID_MAX_NODE_COUNTER: u8 = (1 << (FSDK_FSUID_NODE_COUNTER_BITS - 1)) +  ((1 << (FSDK_FSUID_NODE_COUNTER_BITS - 1)) - 1); // Max node counter that can be represented with FSDK_FSUID_NODE_COUNTER_BITS before overflow occurs
 
+#[cfg(feature = "std")]
 pub fn fsdkuid_get_current_unix_timestamp_milliseconds() -> u64 {
     SystemTime::now()
         .duration_since(UNIX_EPOCH)
@@ -17,38 +209,90 @@ pub fn fsdkuid_get_current_unix_timestamp_milliseconds() -> u64 {
         .as_millis() as u64
 }
 
+// What the generator does when the node counter of the current timestamp tick is exhausted
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum FsdkUidExhaustionPolicy {
+    #[default]
+    Wait, // Sleeps until the next timestamp tick, freeing the CPU
+    Spin, // Busy-waits until the next timestamp tick, lower latency for bursts at the cost of a CPU core
+}
+
+#[cfg(feature = "std")]
 pub struct FsdkUidGenerator {
     node_identifier: u8,
     counter: AtomicU8,
+    last_timestamp_ticks: AtomicU64, // Highest timestamp tick of the generated FSUID's, kept for snapshots
+    layout: FsdkUidLayout,
+    exhaustion_policy: FsdkUidExhaustionPolicy,
 }
 
+#[cfg(feature = "std")]
 impl FsdkUidGenerator {
     pub fn new(node_identifier: u8) -> Self {
-        if node_identifier > FSDK_FSUID_MAX_NODE_IDENTIFIER {
-            panic!("[ERROR in FsdkUidGenerator.new()] FSUID Instance Identifier should be between 0 and {}, but a greater value was specified!", FSDK_FSUID_MAX_NODE_IDENTIFIER);
+        FsdkUidGenerator::with_layout(node_identifier, FsdkUidLayout::FSUID)
+    }
+
+    pub fn with_layout(node_identifier: u8, layout: FsdkUidLayout) -> Self {
+        if node_identifier > layout.max_node_identifier() {
+            panic!("[ERROR in FsdkUidGenerator.with_layout()] FSUID Instance Identifier should be between 0 and {}, but a greater value was specified!", layout.max_node_identifier());
         }
 
-        FsdkUidGenerator {
+        FsdkUidGenerator::try_with_layout(node_identifier, layout)
+            .unwrap_or_else(|_| panic!("[ERROR in FsdkUidGenerator.with_layout()] FSUID Node Identifier {} is already used by another generator of this process!", node_identifier))
+    }
+
+    // Fails instead of panicking when the node identifier is out of range (InvalidConfiguration) or refused by the duplicate policy (DuplicateNodeIdentifier)
+    pub fn try_with_layout(node_identifier: u8, layout: FsdkUidLayout) -> Result<Self, FsdkUidError> {
+        if node_identifier > layout.max_node_identifier() {
+            return Err(FsdkUidError::InvalidConfiguration);
+        }
+
+        registry::fsdkuid_register_generator(node_identifier)?;
+
+        Ok(FsdkUidGenerator {
             node_identifier,
             counter: AtomicU8::new(0),
-        }
+            last_timestamp_ticks: AtomicU64::new(0),
+            layout,
+            exhaustion_policy: FsdkUidExhaustionPolicy::Wait,
+        })
+    }
+
+    pub fn with_exhaustion_policy(mut self, exhaustion_policy: FsdkUidExhaustionPolicy) -> Self {
+        self.exhaustion_policy = exhaustion_policy;
+        self
+    }
+
+    pub fn layout(&self) -> FsdkUidLayout {
+        self.layout
+    }
+
+    pub fn exhaustion_policy(&self) -> FsdkUidExhaustionPolicy {
+        self.exhaustion_policy
     }
 
     pub fn generate_i64(&self) -> i64 {
 
-        let counter = self.counter.fetch_add(1, Ordering::SeqCst);
+        // The counter wraps at 256, a multiple of every node counter range, so the masked counter wraps at the same time
+        let counter = self.counter.fetch_add(1, Ordering::SeqCst) & self.layout.max_node_counter();
 
+        // Only waits when the counter wrapped within the timestamp tick of the last FSUID (never on the first FSUID), until the clock passes that tick
         if counter == 0 {
-            std::thread::sleep(Duration::from_millis(1));
+            let exhausted_ticks = self.last_timestamp_ticks.load(Ordering::SeqCst);
+
+            while self.layout.current_timestamp_ticks() <= exhausted_ticks {
+                match self.exhaustion_policy {
+                    FsdkUidExhaustionPolicy::Wait => std::thread::sleep(Duration::from_millis(self.layout.milliseconds_until_timestamp_ticks(exhausted_ticks + 1).max(1))),
+                    FsdkUidExhaustionPolicy::Spin => std::hint::spin_loop(),
+                }
+            }
         }
 
-        let timestamp_delta = (fsdkuid_get_current_unix_timestamp_milliseconds() & FSDK_FSUID_MAX_TIMESTAMP_DELTA) as i64;
-        
-        let fsuid: i64 = (timestamp_delta << (FSDK_FSUID_NODE_IDENTIFIER_BITS + FSDK_FSUID_NODE_COUNTER_BITS))
-            | ((self.node_identifier as i64) << FSDK_FSUID_NODE_COUNTER_BITS)
-            | (counter as i64);
+        let timestamp_ticks = self.layout.current_timestamp_ticks();
+        self.last_timestamp_ticks.fetch_max(timestamp_ticks, Ordering::Relaxed);
 
-        fsuid
+        self.layout.compose(timestamp_ticks, self.node_identifier, counter)
     }
 
     pub fn generate_fsuid(&self) -> FsdkUid {
@@ -59,20 +303,68 @@ impl FsdkUidGenerator {
 
 }
 
+#[cfg(feature = "std")]
+impl Drop for FsdkUidGenerator {
+    fn drop(&mut self) {
+        registry::fsdkuid_unregister_generator(self.node_identifier);
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct FsdkUid {
     fsuid: i64,
 }
 
 
+#[cfg(feature = "std")]
 impl FsdkUid {
     pub fn new(fsuid: i64) -> Self {
         FsdkUid { fsuid }
     }
 
+    pub fn from_parts(timestamp_delta: i64, node_identifier: u8, node_counter: u8) -> Self {
+        if timestamp_delta < 0 || timestamp_delta as u64 > FSDK_FSUID_MAX_TIMESTAMP_DELTA {
+            panic!("[ERROR in FsdkUid.from_parts()] FSUID Timestamp Delta should be between 0 and {}, but {} was specified!", FSDK_FSUID_MAX_TIMESTAMP_DELTA, timestamp_delta);
+        }
+
+        FsdkUid::new(
+            (timestamp_delta << (FSDK_FSUID_NODE_IDENTIFIER_BITS + FSDK_FSUID_NODE_COUNTER_BITS))
+                | ((node_identifier as i64) << FSDK_FSUID_NODE_COUNTER_BITS)
+                | (node_counter as i64),
+        )
+    }
+
     pub fn i64(&self) -> i64 {
         self.fsuid
     }
 
+    // Big-endian bytes keep the FSUID fields in reading order (timestamp delta, node identifier, node counter), as expected by network protocols
+    pub fn to_be_bytes(&self) -> [u8; 8] {
+        self.fsuid.to_be_bytes()
+    }
+
+    pub fn from_be_bytes(bytes: [u8; 8]) -> Self {
+        FsdkUid::new(i64::from_be_bytes(bytes))
+    }
+
+    pub fn to_le_bytes(&self) -> [u8; 8] {
+        self.fsuid.to_le_bytes()
+    }
+
+    pub fn from_le_bytes(bytes: [u8; 8]) -> Self {
+        FsdkUid::new(i64::from_le_bytes(bytes))
+    }
+
+    // Big-endian bytes with the sign bit flipped, so the lexicographic order of the keys matches the FSUID order (embedded KV stores range scans)
+    pub fn to_key_bytes(&self) -> [u8; 8] {
+        ((self.fsuid as u64) ^ (1 << 63)).to_be_bytes()
+    }
+
+    pub fn from_key_bytes(bytes: [u8; 8]) -> Self {
+        FsdkUid::new((u64::from_be_bytes(bytes) ^ (1 << 63)) as i64)
+    }
+
     pub fn timestamp_delta(&self) -> i64 {
         (self.fsuid >> (FSDK_FSUID_NODE_IDENTIFIER_BITS + FSDK_FSUID_NODE_COUNTER_BITS)) & FSDK_FSUID_MAX_TIMESTAMP_DELTA as i64
     }
@@ -89,10 +381,146 @@ impl FsdkUid {
         let timestamp_delta = self.timestamp_delta();
         DateTime::<Utc>::from(UNIX_EPOCH + Duration::from_millis(timestamp_delta as u64))
     }
+
+    // Orders two FSUID's generated by the same node, comparing the node counter with serial number arithmetic (RFC 1982) when both share the timestamp,
+    // so a counter that wrapped around within the millisecond (ex: 254, 255, 0, 1) still orders after the values preceding the wrap.
+    // Returns None for FSUID's of different nodes or same millisecond counters exactly 128 apart, since their order can't be determined
+    pub fn cmp_within_node(&self, other: &FsdkUid) -> Option<CmpOrdering> {
+        if self.node_identifier() != other.node_identifier() {
+            return None;
+        }
+
+        if self.timestamp_delta() != other.timestamp_delta() {
+            return Some(self.timestamp_delta().cmp(&other.timestamp_delta()));
+        }
+
+        match other.node_counter().wrapping_sub(self.node_counter()) {
+            0 => Some(CmpOrdering::Equal),
+            1..=127 => Some(CmpOrdering::Less),
+            128 => None,
+            _ => Some(CmpOrdering::Greater),
+        }
+    }
+
+    pub fn encode<E: Encoding>(&self) -> String {
+        E::encode(self)
+    }
+
+    pub fn encode_into<'a, E: Encoding>(&self, buffer: &'a mut [u8]) -> Result<&'a str, FsdkUidError> {
+        E::encode_into(self, buffer)
+    }
+
+    pub fn decode<E: Encoding>(text: &str) -> Result<Self, FsdkUidError> {
+        E::decode(text)
+    }
+
+    // Encoded FSUID followed by a check symbol from the same alphabet, so typos are detected when parsed by decode_checked()
+    pub fn encode_checked<E: Encoding>(&self) -> String {
+        encoding::fsdkuid_append_check_symbol(E::encode(self), E::ALPHABET)
+    }
+
+    pub fn decode_checked<E: Encoding>(text: &str) -> Result<Self, FsdkUidError> {
+        encoding::fsdkuid_verify_check_symbol(text, E::ALPHABET, E::CASE_INSENSITIVE, E::decode, E::encode)
+    }
+
+    pub fn to_base62(&self) -> String {
+        Base62::encode(self)
+    }
+
+    pub fn from_base62(text: &str) -> Result<Self, FsdkUidError> {
+        Base62::decode(text)
+    }
+
+    pub fn to_base58(&self) -> String {
+        Base58::encode(self)
+    }
+
+    pub fn from_base58(text: &str) -> Result<Self, FsdkUidError> {
+        Base58::decode(text)
+    }
+
+    pub fn to_crockford_base32(&self) -> String {
+        CrockfordBase32::encode(self)
+    }
+
+    pub fn from_crockford_base32(text: &str) -> Result<Self, FsdkUidError> {
+        CrockfordBase32::decode(text)
+    }
+
+    pub fn to_base64url(&self) -> String {
+        Base64Url::encode(self)
+    }
+
+    pub fn from_base64url(text: &str) -> Result<Self, FsdkUidError> {
+        Base64Url::decode(text)
+    }
+
+    pub fn to_sortable(&self) -> String {
+        Sortable::encode(self)
+    }
+
+    pub fn from_sortable(text: &str) -> Result<Self, FsdkUidError> {
+        Sortable::decode(text)
+    }
+
+    pub fn to_fixed_decimal(&self) -> String {
+        FixedDecimal::encode(self)
+    }
+
+    pub fn from_fixed_decimal(text: &str) -> Result<Self, FsdkUidError> {
+        FixedDecimal::decode(text)
+    }
+
+    // Parses the 64 bits of a FSUID written in hexadecimal (as formatted by {:x} or {:X}), optionally prefixed by 0x and zero padded
+    pub fn from_hex(text: &str) -> Result<Self, FsdkUidError> {
+        let (offset, digits) = match text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+            Some(digits) => (2, digits),
+            None => (0, text),
+        };
+
+        if digits.is_empty() {
+            return Err(FsdkUidError::Empty);
+        }
+
+        let mut fsuid: u64 = 0;
+
+        for (position, character) in digits.chars().enumerate() {
+            let digit = character.to_digit(16).ok_or(FsdkUidError::InvalidCharacter { character, position: position + offset })?;
+
+            if position >= 16 {
+                return Err(FsdkUidError::Overflow);
+            }
+
+            fsuid = (fsuid << 4) | digit as u64;
+        }
+
+        Ok(FsdkUid::new(fsuid as i64))
+    }
 }
 
+#[cfg(feature = "std")]
+impl fmt::LowerHex for FsdkUid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(&self.fsuid, f)
+    }
+}
 
-#[cfg(test)]
+#[cfg(feature = "std")]
+impl fmt::UpperHex for FsdkUid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::UpperHex::fmt(&self.fsuid, f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl fmt::Binary for FsdkUid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Binary::fmt(&self.fsuid, f)
+    }
+}
+
+
+#[cfg(all(test, feature = "std"))]
 mod tests {
     use super::*;
 
@@ -121,7 +549,159 @@ mod tests {
         assert_eq!(fsuid.node_counter(), 0, "[fsuid.node_counter() Error] FSUID->node_counter field must be 0 but it contains another value");
     }
 
-    
+    #[test]
+    fn test_fsdkuid_bytes() {
+        let fsuid = FsdkUid::new(113131996488794368);
+        assert_eq!(fsuid.to_be_bytes(), [0x01, 0x91, 0xec, 0xf3, 0x8f, 0x72, 0x01, 0x00], "[fsuid.to_be_bytes() Error] Big-endian bytes must start with the timestamp delta");
+        assert_eq!(fsuid.to_le_bytes(), [0x00, 0x01, 0x72, 0x8f, 0xf3, 0xec, 0x91, 0x01]);
+        assert_eq!(FsdkUid::from_be_bytes(fsuid.to_be_bytes()), fsuid);
+        assert_eq!(FsdkUid::from_le_bytes(fsuid.to_le_bytes()), fsuid);
+        assert_eq!(FsdkUid::from_be_bytes([0xff; 8]), FsdkUid::new(-1));
+    }
+
+    #[test]
+    fn test_fsdkuid_key_bytes() {
+        let fsuids = [i64::MIN, -1, 0, 1, 65535, 113131996488794368, 113131996488794369, i64::MAX].map(FsdkUid::new);
+
+        for pair in fsuids.windows(2) {
+            assert!(pair[0].to_key_bytes() < pair[1].to_key_bytes(), "[fsuid.to_key_bytes() Error] Key bytes of {} must sort before the ones of {}", pair[0].i64(), pair[1].i64());
+        }
+
+        for fsuid in fsuids {
+            assert_eq!(FsdkUid::from_key_bytes(fsuid.to_key_bytes()), fsuid);
+        }
+
+        assert_eq!(FsdkUid::new(0).to_key_bytes(), [0x80, 0, 0, 0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_fsdkuid_base62() {
+        let fsuid = FsdkUid::new(113131996488794368);
+        assert_eq!(fsuid.to_base62(), "8M92EuiIHw", "[fsuid.to_base62() Error] FSUID 113131996488794368 must be encoded as 8M92EuiIHw");
+        assert_eq!(FsdkUid::from_base62("8M92EuiIHw"), Ok(fsuid), "[FsdkUid::from_base62() Error] 8M92EuiIHw must be decoded as FSUID 113131996488794368");
+
+        for value in [0, 1, 61, 62, i64::MAX, i64::MIN, -1, -65277] {
+            let fsuid = FsdkUid::new(value);
+            assert_eq!(FsdkUid::from_base62(&fsuid.to_base62()), Ok(fsuid), "[FsdkUid::from_base62() Error] FSUID {} didn't round-trip through base62", value);
+        }
+    }
+
+    #[test]
+    fn test_fsdkuid_base62_rejects_invalid_text() {
+        assert_eq!(FsdkUid::from_base62(""), Err(FsdkUidError::Empty));
+        assert_eq!(FsdkUid::from_base62("08M92EuiIHw"), Err(FsdkUidError::NonCanonical));
+        assert_eq!(FsdkUid::from_base62("8M92-uiIHw"), Err(FsdkUidError::InvalidCharacter { character: '-', position: 4 }));
+        assert_eq!(FsdkUid::from_base62("LygHa16AHYG"), Err(FsdkUidError::Overflow));
+        assert_eq!(FsdkUid::from_base62("LygHa16AHYF"), Ok(FsdkUid::new(-1)));
+    }
+
+    #[test]
+    fn test_fsdkuid_base62_matches_embedded_routines() {
+        let mut buffer = [0u8; FSDK_FSUID_BASE62_MAX_LENGTH];
+
+        for value in [0, 61, 62, -65277, 113131996488794368, i64::MAX, i64::MIN, -1] {
+            let expected = FsdkUid::new(value).to_base62();
+            assert_eq!(fsdkuid_format_base62(value, &mut buffer), expected, "[fsdkuid_format_base62() Error] {} must be formatted as {}", value, expected);
+        }
+
+        for text in ["", "0A", "LygHa16AHYG", "8M92-uiIHw", "é", "8M92éuiIHw"] {
+            assert_eq!(fsdkuid_parse_base62(text.as_bytes()).err(), FsdkUid::from_base62(text).err(), "[fsdkuid_parse_base62() Error] {} must be rejected like FsdkUid::from_base62()", text);
+        }
+    }
+
+    #[test]
+    fn test_fsdkuid_crockford_base32() {
+        let fsuid = FsdkUid::new(113131996488794368);
+        assert_eq!(fsuid.to_crockford_base32(), "34FCYE7Q4080", "[fsuid.to_crockford_base32() Error] FSUID 113131996488794368 must be encoded as 34FCYE7Q4080");
+        assert_eq!(FsdkUid::from_crockford_base32("34FCYE7Q4080"), Ok(fsuid));
+        assert_eq!(FsdkUid::from_crockford_base32("34fcye7q4o8o"), Ok(fsuid), "[FsdkUid::from_crockford_base32() Error] Parsing must be case-insensitive and read O as 0");
+        assert_eq!(FsdkUid::from_crockford_base32("IL"), Ok(FsdkUid::new(33)), "[FsdkUid::from_crockford_base32() Error] Parsing must read I and L as 1");
+
+        for value in [0, 31, 32, i64::MAX, i64::MIN, -1] {
+            let fsuid = FsdkUid::new(value);
+            assert_eq!(FsdkUid::from_crockford_base32(&fsuid.to_crockford_base32()), Ok(fsuid), "[FsdkUid::from_crockford_base32() Error] FSUID {} didn't round-trip through Crockford Base32", value);
+        }
+
+        assert_eq!(FsdkUid::from_crockford_base32("FZZZZZZZZZZZZ"), Ok(FsdkUid::new(-1)));
+        assert_eq!(FsdkUid::from_crockford_base32("G000000000000"), Err(FsdkUidError::Overflow));
+        assert_eq!(FsdkUid::from_crockford_base32("3U"), Err(FsdkUidError::InvalidCharacter { character: 'U', position: 1 }));
+    }
+
+    #[test]
+    fn test_fsdkuid_cmp_within_node() {
+        let fsuid = |timestamp_delta: i64, node_identifier: i64, node_counter: i64| FsdkUid::new((timestamp_delta << 16) | (node_identifier << 8) | node_counter);
+
+        assert_eq!(fsuid(10, 1, 5).cmp_within_node(&fsuid(10, 1, 6)), Some(CmpOrdering::Less));
+        assert_eq!(fsuid(10, 1, 6).cmp_within_node(&fsuid(10, 1, 6)), Some(CmpOrdering::Equal));
+        assert_eq!(fsuid(11, 1, 0).cmp_within_node(&fsuid(10, 1, 200)), Some(CmpOrdering::Greater), "[fsuid.cmp_within_node() Error] Timestamp must take precedence over the node counter");
+        assert_eq!(fsuid(10, 1, 255).cmp_within_node(&fsuid(10, 1, 1)), Some(CmpOrdering::Less), "[fsuid.cmp_within_node() Error] Counter 1 after a wrap must order after counter 255");
+        assert_eq!(fsuid(10, 1, 1).cmp_within_node(&fsuid(10, 1, 250)), Some(CmpOrdering::Greater), "[fsuid.cmp_within_node() Error] Counter 1 after a wrap must order after counter 250");
+        assert_eq!(fsuid(10, 1, 0).cmp_within_node(&fsuid(10, 1, 128)), None);
+        assert_eq!(fsuid(10, 1, 0).cmp_within_node(&fsuid(10, 2, 1)), None, "[fsuid.cmp_within_node() Error] FSUID's of different nodes can't be ordered by their counter");
+    }
+
+    #[test]
+    fn test_fsdkuid_base58() {
+        let fsuid = FsdkUid::new(113131996488794368);
+        assert_eq!(fsuid.to_base58(), "GEQgiAPo3u", "[fsuid.to_base58() Error] FSUID 113131996488794368 must be encoded as GEQgiAPo3u");
+        assert_eq!(FsdkUid::from_base58("GEQgiAPo3u"), Ok(fsuid));
+        assert_eq!(FsdkUid::from_base58("1"), Ok(FsdkUid::new(0)));
+        assert_eq!(FsdkUid::from_base58("11"), Err(FsdkUidError::NonCanonical));
+        assert_eq!(FsdkUid::from_base58("0"), Err(FsdkUidError::InvalidCharacter { character: '0', position: 0 }));
+
+        for value in [57, 58, i64::MAX, i64::MIN, -1] {
+            let fsuid = FsdkUid::new(value);
+            assert_eq!(FsdkUid::from_base58(&fsuid.to_base58()), Ok(fsuid), "[FsdkUid::from_base58() Error] FSUID {} didn't round-trip through Base58", value);
+        }
+    }
+
+    #[test]
+    fn test_fsdkuid_encoding_generic() {
+        fn round_trip<E: Encoding>(fsuid: FsdkUid) -> Result<FsdkUid, FsdkUidError> {
+            FsdkUid::decode::<E>(&fsuid.encode::<E>())
+        }
+
+        let fsuid = FsdkUid::new(113131996488794368);
+        assert_eq!(round_trip::<Base62>(fsuid), Ok(fsuid));
+        assert_eq!(round_trip::<Base58>(fsuid), Ok(fsuid));
+        assert_eq!(round_trip::<CrockfordBase32>(fsuid), Ok(fsuid));
+        assert_eq!(fsuid.encode::<Base62>(), fsuid.to_base62());
+    }
+
+    #[test]
+    fn test_fsdkuid_base64url() {
+        let fsuid = FsdkUid::new(113131996488794368);
+        assert_eq!(fsuid.to_base64url(), "AZHs849yAQA", "[fsuid.to_base64url() Error] FSUID 113131996488794368 must be encoded as AZHs849yAQA");
+        assert_eq!(FsdkUid::from_base64url("AZHs849yAQA"), Ok(fsuid));
+        assert_eq!(FsdkUid::new(-1).to_base64url(), "__________8");
+
+        for value in [0, i64::MAX, i64::MIN, -1, -65277] {
+            let fsuid = FsdkUid::new(value);
+            assert_eq!(FsdkUid::from_base64url(&fsuid.to_base64url()), Ok(fsuid), "[FsdkUid::from_base64url() Error] FSUID {} didn't round-trip through base64url", value);
+        }
+
+        assert_eq!(FsdkUid::from_base64url("AZHs849yAQA="), Err(FsdkUidError::InvalidCharacter { character: '=', position: 11 }));
+        assert_eq!(FsdkUid::from_base64url("AZHs849yAQB"), Err(FsdkUidError::NonCanonical), "[FsdkUid::from_base64url() Error] Texts with unused bits set must be rejected");
+        assert_eq!(FsdkUid::from_base64url("AZHs849yAQ"), Err(FsdkUidError::InvalidLength));
+        assert_eq!(FsdkUid::from_base64url("AZHs84+yAQA"), Err(FsdkUidError::InvalidCharacter { character: '+', position: 6 }));
+    }
+
+    #[test]
+    fn test_fsdkuid_hex_and_binary_formatting() {
+        let fsuid = FsdkUid::new(113131996488794368);
+        assert_eq!(format!("{:x}", fsuid), "191ecf38f720100", "[FsdkUid LowerHex Error] FSUID 113131996488794368 must be formatted as 191ecf38f720100");
+        assert_eq!(format!("{:#X}", fsuid), "0x191ECF38F720100", "[FsdkUid UpperHex Error] FSUID 113131996488794368 must be formatted as 0x191ECF38F720100");
+        assert_eq!(format!("{:b}", FsdkUid::new(-65277)), "1111111111111111111111111111111111111111111111110000000100000011", "[FsdkUid Binary Error] FSUID -65277 must be formatted with its 64 bits");
+        assert_eq!(format!("{:016x}", FsdkUid::new(255)), "00000000000000ff");
+
+        assert_eq!(FsdkUid::from_hex("0191ecf38f720100"), Ok(fsuid));
+        assert_eq!(FsdkUid::from_hex("0x191ECF38F720100"), Ok(fsuid));
+        assert_eq!(FsdkUid::from_hex("00000000000000ff"), Ok(FsdkUid::new(255)));
+        assert_eq!(FsdkUid::from_hex(&format!("{:x}", FsdkUid::new(-65277))), Ok(FsdkUid::new(-65277)));
+        assert_eq!(FsdkUid::from_hex("0x"), Err(FsdkUidError::Empty));
+        assert_eq!(FsdkUid::from_hex("0x1g"), Err(FsdkUidError::InvalidCharacter { character: 'g', position: 3 }));
+        assert_eq!(FsdkUid::from_hex("10000000000000000"), Err(FsdkUidError::Overflow));
+    }
 
     #[test]
     fn test_fsdkuid_generator() {
@@ -183,6 +763,17 @@ mod tests {
         assert_eq!(fsuid_1_first.node_identifier(), fsuid_2_first.node_identifier());
     }
 
+    #[test]
+    fn test_fsdkuid_generator_spin_exhaustion_policy() {
+        let fsuid_generator = FsdkUidGenerator::new(0).with_exhaustion_policy(FsdkUidExhaustionPolicy::Spin);
+        assert_eq!(fsuid_generator.exhaustion_policy(), FsdkUidExhaustionPolicy::Spin);
+
+        let fsuids: Vec<i64> = (0..1000).map(|_| fsuid_generator.generate_i64()).collect();
+        for pair in fsuids.windows(2) {
+            assert!(pair[0] < pair[1], "[FsdkUidGenerator.generate_i64() Error] Spinning generator FSUID's must increase: {:?}", pair);
+        }
+    }
+
     #[test]
     fn test_fsdkuid_generator_differentnode_sequencecollision() {
         let fsuid_generator = FsdkUidGenerator::new(0);
@@ -191,4 +782,10 @@ mod tests {
         let fsuid2 = fsuid_generator2.generate_i64();
         assert_ne!(fsuid1, fsuid2, "[FsdkUidGenerator.generate_i64() Error] Two sequential generated FSUID on different nodes collided")
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_fsdkuid_generator_try_with_layout() {
+        assert_eq!(FsdkUidGenerator::try_with_layout(64, FsdkUidLayout::JS_SAFE).err(), Some(FsdkUidError::InvalidConfiguration), "[FsdkUidGenerator::try_with_layout() Error] Node identifier out of the layout range must be refused");
+        assert_eq!(FsdkUidGenerator::try_with_layout(63, FsdkUidLayout::JS_SAFE).map(|generator| generator.node_identifier), Ok(63));
+    }
+}
@@ -0,0 +1,191 @@
+use std::collections::{BTreeSet, HashMap};
+use std::hash::Hash;
+use std::ops::{Deref, RangeInclusive};
+use std::sync::{Arc, Mutex, RwLock};
+
+use crate::{FsdkUid, FsdkUidError, FsdkUidGenerator, FsdkUidLayout};
+
+type FsdkUidNodeAssigner<K> = Box<dyn Fn(&K) -> Result<u8, FsdkUidError> + Send + Sync>;
+type FsdkUidNodePool = Arc<Mutex<BTreeSet<u8>>>; // Free node identifiers, the lowest is assigned to each new generator
+
+enum FsdkUidNodeAssignment<K> {
+    Pool(FsdkUidNodePool),
+    Function(FsdkUidNodeAssigner<K>),
+}
+
+// Generator handed out by a GeneratorManager, dereferencing to FsdkUidGenerator. A generator of a pool gives its node identifier back to the pool
+// when its last clone is dropped, so removed keys never leak node identifiers while callers still use their generator
+pub struct ManagedGenerator {
+    generator: Option<FsdkUidGenerator>, // Only None while dropped, so the generator is unregistered before its node identifier is reassigned
+    pool: Option<FsdkUidNodePool>,
+}
+
+impl Deref for ManagedGenerator {
+    type Target = FsdkUidGenerator;
+
+    fn deref(&self) -> &FsdkUidGenerator {
+        self.generator.as_ref().expect("[ERROR in ManagedGenerator.deref()] Generator is only taken while dropped!")
+    }
+}
+
+impl Drop for ManagedGenerator {
+    fn drop(&mut self) {
+        if let (Some(generator), Some(pool)) = (self.generator.take(), &self.pool) {
+            let node_identifier = generator.node_identifier;
+            drop(generator);
+            pool.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).insert(node_identifier);
+        }
+    }
+}
+
+// Generators of a multi-tenant service keyed by tenant or logical stream, created on first use with the node identifier assigned to their key and dropped on shutdown
+pub struct GeneratorManager<K: Eq + Hash + Clone> {
+    layout: FsdkUidLayout,
+    assignment: FsdkUidNodeAssignment<K>,
+    generators: RwLock<Option<HashMap<K, Arc<ManagedGenerator>>>>, // None once shut down
+}
+
+impl<K: Eq + Hash + Clone> GeneratorManager<K> {
+    // Assigns the node identifiers of the range to the generators, so FSUID's of every key are unique within the process (and across processes using disjoint ranges)
+    pub fn with_node_identifiers(layout: FsdkUidLayout, node_identifiers: RangeInclusive<u8>) -> Self {
+        if node_identifiers.is_empty() || *node_identifiers.end() > layout.max_node_identifier() {
+            panic!("[ERROR in GeneratorManager.with_node_identifiers()] FSUID Node Identifiers should be a non empty range between 0 and {}, but {:?} was specified!", layout.max_node_identifier(), node_identifiers);
+        }
+
+        GeneratorManager { layout, assignment: FsdkUidNodeAssignment::Pool(Arc::new(Mutex::new(node_identifiers.collect()))), generators: RwLock::new(Some(HashMap::new())) }
+    }
+
+    // Assigns the node identifier returned for each key, ex: read from the tenant configuration or claimed from a NodeIdAllocator
+    pub fn with_assigner(layout: FsdkUidLayout, assigner: impl Fn(&K) -> Result<u8, FsdkUidError> + Send + Sync + 'static) -> Self {
+        GeneratorManager { layout, assignment: FsdkUidNodeAssignment::Function(Box::new(assigner)), generators: RwLock::new(Some(HashMap::new())) }
+    }
+
+    pub fn layout(&self) -> FsdkUidLayout {
+        self.layout
+    }
+
+    // Generator of the key, created on first use
+    pub fn generator(&self, key: &K) -> Result<Arc<ManagedGenerator>, FsdkUidError> {
+        if let Some(generators) = self.generators.read().unwrap_or_else(|poisoned| poisoned.into_inner()).as_ref() {
+            if let Some(generator) = generators.get(key) {
+                return Ok(generator.clone());
+            }
+        }
+
+        let mut generators = self.generators.write().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let generators = generators.as_mut().ok_or(FsdkUidError::ShutDown)?;
+
+        if let Some(generator) = generators.get(key) {
+            return Ok(generator.clone());
+        }
+
+        let generator = match &self.assignment {
+            FsdkUidNodeAssignment::Pool(pool) => {
+                let node_identifier = pool.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).pop_first().ok_or(FsdkUidError::NodeIdentifierUnavailable)?;
+
+                match FsdkUidGenerator::try_with_layout(node_identifier, self.layout) {
+                    Ok(generator) => ManagedGenerator { generator: Some(generator), pool: Some(pool.clone()) },
+                    Err(error) => {
+                        pool.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).insert(node_identifier);
+                        return Err(error);
+                    }
+                }
+            }
+            FsdkUidNodeAssignment::Function(assigner) => {
+                let node_identifier = assigner(key)?;
+
+                if node_identifier > self.layout.max_node_identifier() {
+                    return Err(FsdkUidError::NodeIdentifierUnavailable);
+                }
+
+                ManagedGenerator { generator: Some(FsdkUidGenerator::try_with_layout(node_identifier, self.layout)?), pool: None }
+            }
+        };
+
+        let generator = Arc::new(generator);
+        generators.insert(key.clone(), generator.clone());
+        Ok(generator)
+    }
+
+    pub fn generate_i64(&self, key: &K) -> Result<i64, FsdkUidError> {
+        self.generator(key).map(|generator| generator.generate_i64())
+    }
+
+    pub fn generate_fsuid(&self, key: &K) -> Result<FsdkUid, FsdkUidError> {
+        self.generate_i64(key).map(FsdkUid::new)
+    }
+
+    // Drops the generator of the key (ex: an offboarded tenant), its node identifier goes back to the pool once the last clone of the generator is dropped
+    pub fn remove(&self, key: &K) -> bool {
+        self.generators.write().unwrap_or_else(|poisoned| poisoned.into_inner()).as_mut().and_then(|generators| generators.remove(key)).is_some()
+    }
+
+    pub fn len(&self) -> usize {
+        self.generators.read().unwrap_or_else(|poisoned| poisoned.into_inner()).as_ref().map_or(0, HashMap::len)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn is_shut_down(&self) -> bool {
+        self.generators.read().unwrap_or_else(|poisoned| poisoned.into_inner()).is_none()
+    }
+
+    // Drops every generator, FSUID's requested afterwards fail with FsdkUidError::ShutDown
+    pub fn shutdown(&self) {
+        self.generators.write().unwrap_or_else(|poisoned| poisoned.into_inner()).take();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    #[test]
+    fn test_fsdkuid_generator_manager_pool() {
+        let manager: Arc<GeneratorManager<String>> = Arc::new(GeneratorManager::with_node_identifiers(FsdkUidLayout::FSUID, 10..=11));
+
+        let workers: Vec<thread::JoinHandle<Vec<FsdkUid>>> = (0..4)
+            .map(|worker| {
+                let manager = manager.clone();
+                thread::spawn(move || (0..100).map(|_| manager.generate_fsuid(&format!("tenant-{}", worker % 2)).unwrap()).collect())
+            })
+            .collect();
+        let fsuids: Vec<FsdkUid> = workers.into_iter().flat_map(|worker| worker.join().unwrap()).collect();
+
+        assert_eq!(manager.len(), 2, "[GeneratorManager.generator() Error] Generators must be created once per key");
+        assert_eq!(fsuids.iter().collect::<std::collections::HashSet<_>>().len(), 400, "[GeneratorManager.generate_fsuid() Error] FSUID's of every key must be unique");
+        assert_eq!(manager.generate_fsuid(&"tenant-2".to_string()), Err(FsdkUidError::NodeIdentifierUnavailable), "[GeneratorManager.generator() Error] Keys must fail when every node identifier is assigned");
+
+        let node_identifier = manager.generator(&"tenant-0".to_string()).unwrap().generate_fsuid().node_identifier();
+        assert!(manager.remove(&"tenant-0".to_string()));
+        assert_eq!(manager.generate_fsuid(&"tenant-2".to_string()).unwrap().node_identifier(), node_identifier, "[GeneratorManager.remove() Error] Node identifier of a removed key must be reassigned");
+
+        let held = manager.generator(&"tenant-1".to_string()).unwrap();
+        let node_identifier = held.node_identifier;
+        assert!(manager.remove(&"tenant-1".to_string()));
+        assert_eq!(manager.generate_fsuid(&"tenant-3".to_string()), Err(FsdkUidError::NodeIdentifierUnavailable), "[GeneratorManager.remove() Error] Node identifier of a removed key must not be reassigned while a clone of its generator is alive");
+        assert!(held.generate_fsuid().node_identifier() == node_identifier);
+
+        drop(held);
+        assert_eq!(manager.generate_fsuid(&"tenant-3".to_string()).unwrap().node_identifier(), node_identifier, "[GeneratorManager.remove() Error] Node identifier must be reassigned once the last clone of the removed generator is dropped");
+
+        manager.shutdown();
+        assert!(manager.is_shut_down() && manager.is_empty());
+        assert_eq!(manager.generate_i64(&"tenant-1".to_string()), Err(FsdkUidError::ShutDown));
+    }
+
+    #[test]
+    fn test_fsdkuid_generator_manager_assigner() {
+        let manager = GeneratorManager::with_assigner(FsdkUidLayout::JS_SAFE, |tenant: &u32| match tenant {
+            0..=63 => Ok(*tenant as u8),
+            _ => Err(FsdkUidError::NodeIdentifierUnavailable),
+        });
+
+        assert_eq!(FsdkUidLayout::JS_SAFE.node_identifier(&manager.generate_fsuid(&42).unwrap()), 42);
+        assert_eq!(manager.generate_fsuid(&64), Err(FsdkUidError::NodeIdentifierUnavailable));
+        assert!(!manager.remove(&7));
+    }
+}
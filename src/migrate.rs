@@ -0,0 +1,115 @@
+use crate::{FsdkUid, FsdkUidError, FsdkUidLayout};
+
+// Rewrites FSUID's from a source layout to a target layout (ex: adopting a custom epoch on an existing dataset), keeping the node identifier.
+// FSUID's colliding in the target layout (smaller counter or coarser resolution) are re-sequenced right after the previous FSUID, so the output keeps the input order and stays unique
+pub struct FsdkUidMigrator {
+    source: FsdkUidLayout,
+    target: FsdkUidLayout,
+    last: Option<(u64, u8, u8)>,
+    migrated: u64,
+    resequenced: u64,
+}
+
+impl FsdkUidMigrator {
+    pub fn new(source: FsdkUidLayout, target: FsdkUidLayout) -> Self {
+        FsdkUidMigrator { source, target, last: None, migrated: 0, resequenced: 0 }
+    }
+
+    // FSUID's must be given in ascending order, an FSUID smaller than the previous one is re-sequenced after it.
+    // Fails with FsdkUidError::Overflow when the generation time or node identifier can't be represented in the target layout
+    pub fn migrate(&mut self, fsuid: FsdkUid) -> Result<FsdkUid, FsdkUidError> {
+        let milliseconds = self.source.epoch_milliseconds() + self.source.timestamp_ticks(&fsuid) * self.source.resolution_milliseconds();
+        let timestamp_ticks = milliseconds.checked_sub(self.target.epoch_milliseconds()).ok_or(FsdkUidError::Overflow)? / self.target.resolution_milliseconds();
+        let node_identifier = self.source.node_identifier(&fsuid);
+        let node_counter = self.source.node_counter(&fsuid).min(self.target.max_node_counter());
+
+        if timestamp_ticks > self.target.max_timestamp_ticks() || node_identifier > self.target.max_node_identifier() {
+            return Err(FsdkUidError::Overflow);
+        }
+
+        let candidate = (timestamp_ticks, node_identifier, node_counter);
+        let fields = match self.last {
+            Some(last) if candidate <= last => {
+                self.resequenced += 1;
+                self.next_after(last, node_identifier)?
+            }
+            _ => candidate,
+        };
+
+        self.last = Some(fields);
+        self.migrated += 1;
+
+        Ok(FsdkUid::new(self.target.compose(fields.0, fields.1, fields.2)))
+    }
+
+    pub fn migrated(&self) -> u64 {
+        self.migrated
+    }
+
+    // Number of FSUID's moved after the previous one because they collided in the target layout
+    pub fn resequenced(&self) -> u64 {
+        self.resequenced
+    }
+
+    // Smallest fields of the node greater than the last migrated fields
+    fn next_after(&self, (last_ticks, last_node, last_counter): (u64, u8, u8), node_identifier: u8) -> Result<(u64, u8, u8), FsdkUidError> {
+        let fields = if node_identifier > last_node {
+            (last_ticks, node_identifier, 0)
+        } else if node_identifier == last_node && last_counter < self.target.max_node_counter() {
+            (last_ticks, node_identifier, last_counter + 1)
+        } else {
+            (last_ticks + 1, node_identifier, 0)
+        };
+
+        if fields.0 > self.target.max_timestamp_ticks() {
+            return Err(FsdkUidError::Overflow);
+        }
+
+        Ok(fields)
+    }
+}
+
+// Migrates every FSUID of the iterator, stopping at the first FSUID that can't be represented in the target layout
+pub fn fsdkuid_migrate(source: FsdkUidLayout, target: FsdkUidLayout, fsuids: impl IntoIterator<Item = FsdkUid>) -> Result<Vec<FsdkUid>, FsdkUidError> {
+    let mut migrator = FsdkUidMigrator::new(source, target);
+    fsuids.into_iter().map(|fsuid| migrator.migrate(fsuid)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_fsdkuid_migrate_to_custom_epoch() {
+        let fsuids = [FsdkUid::from_parts(1726257270642, 1, 0), FsdkUid::from_parts(1726257270642, 1, 1), FsdkUid::from_parts(1726257270643, 2, 0)];
+        let migrated = fsdkuid_migrate(FsdkUidLayout::FSUID, FsdkUidLayout::JS_SAFE, fsuids).unwrap();
+
+        let layout = FsdkUidLayout::JS_SAFE;
+        assert_eq!(layout.timestamp_ticks(&migrated[0]), 1726257270642 - 1704067200000, "[fsdkuid_migrate() Error] Timestamp must be rebased on the target epoch");
+        assert_eq!(layout.utc_datetime(&migrated[0]), fsuids[0].utc_datetime());
+        assert_eq!((layout.node_identifier(&migrated[2]), layout.node_counter(&migrated[1])), (2, 1));
+        assert!(migrated.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn test_fsdkuid_migrate_resequences_collisions() {
+        // 300 FSUID's of the same node and second collapse into the same SHORT_CODE tick, which only has 32 counters
+        let fsuids: Vec<FsdkUid> = (0..300).map(|index| FsdkUid::from_parts(1726257270000 + index / 256 * 3, 1, (index % 256) as u8)).collect();
+
+        let mut migrator = FsdkUidMigrator::new(FsdkUidLayout::FSUID, FsdkUidLayout::SHORT_CODE);
+        let migrated: Vec<FsdkUid> = fsuids.iter().map(|&fsuid| migrator.migrate(fsuid).unwrap()).collect();
+
+        assert!(migrated.windows(2).all(|pair| pair[0] < pair[1]), "[FsdkUidMigrator.migrate() Error] Migrated FSUID's must keep the input order");
+        assert_eq!(migrated.iter().collect::<HashSet<_>>().len(), fsuids.len(), "[FsdkUidMigrator.migrate() Error] Migrated FSUID's must stay unique");
+        assert!(migrated.iter().all(|fsuid| FsdkUidLayout::SHORT_CODE.node_identifier(fsuid) == 1), "[FsdkUidMigrator.migrate() Error] Node identifier must be kept");
+        assert_eq!(migrator.migrated(), 300);
+        assert!(migrator.resequenced() > 0);
+    }
+
+    #[test]
+    fn test_fsdkuid_migrate_rejects_unrepresentable_fsuids() {
+        assert_eq!(fsdkuid_migrate(FsdkUidLayout::FSUID, FsdkUidLayout::JS_SAFE, [FsdkUid::from_parts(1600000000000, 1, 0)]), Err(FsdkUidError::Overflow), "[fsdkuid_migrate() Error] FSUID's before the target epoch must be rejected");
+        assert_eq!(fsdkuid_migrate(FsdkUidLayout::FSUID, FsdkUidLayout::JS_SAFE, [FsdkUid::from_parts(1726257270642, 64, 0)]), Err(FsdkUidError::Overflow), "[fsdkuid_migrate() Error] Node identifiers outside of the target layout must be rejected");
+    }
+}
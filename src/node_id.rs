@@ -0,0 +1,574 @@
+use std::path::PathBuf;
+use std::time::Duration;
+use std::{env, fs, io};
+
+#[cfg(feature = "cloud-metadata")]
+use crate::cloud::{fsdkuid_cloud_metadata, CloudMetadataItem};
+use crate::ring::fsdkuid_mix64;
+use crate::{fsdkuid_get_current_unix_timestamp_milliseconds, FsdkUidError, FsdkUidGeneratorConfig, FsdkUidLayout};
+
+const FSDK_FSUID_SQL_LEASE_TABLE: &str = "fsdk_fsuid_node_leases"; // Default coordination table of SqlAllocator, one row per node identifier
+const FSDK_FSUID_NODE_ID_ENV: &str = "FSDK_NODE_ID"; // Environment variable read by the default node identifier source
+const FSDK_FSUID_HOSTNAME_PATH: &str = "/proc/sys/kernel/hostname"; // Hostname of the linux kernel, read before the HOSTNAME and COMPUTERNAME environment variables
+const FSDK_FSUID_CGROUP_PATH: &str = "/proc/self/cgroup"; // Control groups of the process, naming the container with cgroup v1 and cgroup v2 without cgroup namespace
+const FSDK_FSUID_MOUNTINFO_PATH: &str = "/proc/self/mountinfo"; // Mounts of the process, naming the container through the files the runtime mounts (ex: /etc/hostname) when the cgroup is namespaced
+const FSDK_FSUID_NETWORK_INTERFACES_PATH: &str = "/sys/class/net"; // One directory per linux network interface, with its MAC address in the address file
+
+// Where the node identifier of a process comes from when it isn't assigned by hand
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum NodeIdSource {
+    Environment(String), // Decimal node identifier in the environment variable, FSDK_NODE_ID by default
+    ConfigFile(PathBuf), // Node identifier of a FsdkUidGeneratorConfig TOML file, ignored when the file doesn't exist
+    StatefulSetOrdinal, // Ordinal of a kubernetes StatefulSet pod, read from the end of its HOSTNAME (ex: ids-3 is node identifier 3)
+    ContainerId, // Hash of the container identifier of the process (docker, containerd, cri-o), distinct for sidecars of the same pod but two containers may share a node identifier
+    HostFingerprint, // Hash of the hostname and primary MAC address, stable across restarts without coordination but two hosts may share a node identifier
+    #[cfg(feature = "cloud-metadata")]
+    CloudInstanceId, // Hash of the EC2 or GCE instance identifier, stable for the lifetime of the instance but two instances may share a node identifier
+    #[cfg(feature = "cloud-metadata")]
+    CloudInstanceTag(String), // Decimal node identifier in an EC2 instance tag or GCE instance metadata attribute, assigned by the provisioning of the fleet
+    Fixed(u8), // Node identifier used as the last fallback of a chain
+    Chain(Vec<NodeIdSource>), // First source providing a node identifier
+}
+
+impl Default for NodeIdSource {
+    fn default() -> Self {
+        NodeIdSource::Environment(FSDK_FSUID_NODE_ID_ENV.to_string())
+    }
+}
+
+impl NodeIdSource {
+    // Returns None when the source doesn't provide a node identifier in this environment.
+    // A provided node identifier which isn't valid for the layout fails instead of falling back to the next source of a chain, since it's a deployment mistake
+    pub fn resolve(&self, layout: &FsdkUidLayout) -> Result<Option<u8>, FsdkUidError> {
+        self.resolve_with(layout, &|name| env::var(name).ok())
+    }
+
+    // Same as resolve(), failing with FsdkUidError::InvalidConfiguration when no source provides a node identifier
+    pub fn resolve_required(&self, layout: &FsdkUidLayout) -> Result<u8, FsdkUidError> {
+        self.resolve(layout)?.ok_or(FsdkUidError::InvalidConfiguration)
+    }
+
+    fn resolve_with(&self, layout: &FsdkUidLayout, lookup: &dyn Fn(&str) -> Option<String>) -> Result<Option<u8>, FsdkUidError> {
+        let node_identifier = match self {
+            NodeIdSource::Environment(name) => match lookup(name).as_deref().map(str::trim) {
+                None | Some("") => return Ok(None),
+                Some(value) => value.parse::<u64>().map_err(|_| FsdkUidError::InvalidConfiguration)?,
+            },
+            NodeIdSource::ConfigFile(path) => match fs::read_to_string(path) {
+                Ok(text) => FsdkUidGeneratorConfig::from_toml(&text)?.node_identifier as u64,
+                Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(None),
+                Err(_) => return Err(FsdkUidError::InvalidConfiguration),
+            },
+            NodeIdSource::StatefulSetOrdinal => match lookup("HOSTNAME").as_deref().and_then(fsdkuid_statefulset_ordinal) {
+                Some(ordinal) => ordinal,
+                None => return Ok(None),
+            },
+            NodeIdSource::ContainerId => {
+                let container_identifier = [FSDK_FSUID_CGROUP_PATH, FSDK_FSUID_MOUNTINFO_PATH]
+                    .iter()
+                    .filter_map(|path| fs::read_to_string(path).ok())
+                    .find_map(|text| fsdkuid_container_identifier(&text).map(str::to_string));
+
+                match container_identifier {
+                    Some(container_identifier) => fsdkuid_hash_bytes(container_identifier.bytes()) % (layout.max_node_identifier() as u64 + 1),
+                    None => return Ok(None),
+                }
+            }
+            NodeIdSource::HostFingerprint => {
+                let hostname = fsdkuid_hostname();
+                let mac_address = fsdkuid_primary_mac_address();
+
+                if hostname.is_none() && mac_address.is_none() {
+                    return Ok(None);
+                }
+
+                fsdkuid_host_fingerprint(hostname.as_deref().unwrap_or(""), mac_address, layout) as u64
+            }
+            #[cfg(feature = "cloud-metadata")]
+            NodeIdSource::CloudInstanceId => match fsdkuid_cloud_metadata(CloudMetadataItem::InstanceId).map_err(|_| FsdkUidError::InvalidConfiguration)? {
+                Some(instance_identifier) => fsdkuid_hash_bytes(instance_identifier.bytes()) % (layout.max_node_identifier() as u64 + 1),
+                None => return Ok(None),
+            },
+            #[cfg(feature = "cloud-metadata")]
+            NodeIdSource::CloudInstanceTag(tag) => {
+                if tag.is_empty() || !tag.bytes().all(|symbol| symbol.is_ascii_alphanumeric() || b"-_.".contains(&symbol)) {
+                    return Err(FsdkUidError::InvalidConfiguration);
+                }
+
+                match fsdkuid_cloud_metadata(CloudMetadataItem::Tag(tag)).map_err(|_| FsdkUidError::InvalidConfiguration)? {
+                    Some(value) => value.parse::<u64>().map_err(|_| FsdkUidError::InvalidConfiguration)?,
+                    None => return Ok(None),
+                }
+            }
+            NodeIdSource::Fixed(node_identifier) => *node_identifier as u64,
+            NodeIdSource::Chain(sources) => {
+                for source in sources {
+                    if let Some(node_identifier) = source.resolve_with(layout, lookup)? {
+                        return Ok(Some(node_identifier));
+                    }
+                }
+
+                return Ok(None);
+            }
+        };
+
+        if node_identifier > layout.max_node_identifier() as u64 {
+            return Err(FsdkUidError::InvalidConfiguration);
+        }
+
+        Ok(Some(node_identifier as u8))
+    }
+}
+
+// StatefulSet pods are named <statefulset>-<ordinal>, hostnames without an ordinal suffix aren't StatefulSet pods
+fn fsdkuid_statefulset_ordinal(hostname: &str) -> Option<u64> {
+    let (name, ordinal) = hostname.trim().rsplit_once('-')?;
+
+    if name.is_empty() || ordinal.is_empty() || !ordinal.bytes().all(|symbol| symbol.is_ascii_digit()) || (ordinal.len() > 1 && ordinal.starts_with('0')) {
+        return None;
+    }
+
+    ordinal.parse().ok()
+}
+
+// Container runtimes name containers with 64 hexadecimal digits, found in the cgroup paths (ex: /docker/<id>, cri-containerd-<id>.scope) or mount sources (ex: /var/lib/docker/containers/<id>/hostname)
+fn fsdkuid_container_identifier(text: &str) -> Option<&str> {
+    text.lines()
+        .filter(|line| !line.contains("/sandboxes/"))
+        .flat_map(|line| line.split(|symbol: char| !symbol.is_ascii_hexdigit()))
+        .find(|candidate| candidate.len() == 64)
+}
+
+pub(crate) fn fsdkuid_hash_bytes(bytes: impl IntoIterator<Item = u8>) -> u64 {
+    fsdkuid_mix64(bytes.into_iter().fold(0, |hash, byte| fsdkuid_mix64(hash ^ byte as u64)))
+}
+
+// Hostnames are compared case insensitively, and the MAC address tells apart hosts sharing a hostname (ex: cloned virtual machines)
+fn fsdkuid_host_fingerprint(hostname: &str, mac_address: Option<[u8; 6]>, layout: &FsdkUidLayout) -> u8 {
+    let hostname = hostname.trim().to_ascii_lowercase();
+    let hash = fsdkuid_hash_bytes(hostname.bytes().chain([0]).chain(mac_address.into_iter().flatten()));
+
+    (hash % (layout.max_node_identifier() as u64 + 1)) as u8
+}
+
+fn fsdkuid_hostname() -> Option<String> {
+    fs::read_to_string(FSDK_FSUID_HOSTNAME_PATH)
+        .ok()
+        .or_else(|| env::var("HOSTNAME").ok())
+        .or_else(|| env::var("COMPUTERNAME").ok())
+        .map(|hostname| hostname.trim().to_string())
+        .filter(|hostname| !hostname.is_empty())
+}
+
+// MAC address of the first interface by name, skipping the loopback and interfaces without an address
+fn fsdkuid_primary_mac_address() -> Option<[u8; 6]> {
+    let mut interfaces: Vec<_> = fs::read_dir(FSDK_FSUID_NETWORK_INTERFACES_PATH).ok()?.filter_map(|entry| entry.ok()).map(|entry| entry.path()).collect();
+    interfaces.sort();
+
+    interfaces.iter().find_map(|interface| fsdkuid_parse_mac_address(&fs::read_to_string(interface.join("address")).ok()?))
+}
+
+fn fsdkuid_parse_mac_address(text: &str) -> Option<[u8; 6]> {
+    let mut mac_address = [0u8; 6];
+    let mut octets = text.trim().split(':');
+
+    for octet in mac_address.iter_mut() {
+        *octet = u8::from_str_radix(octets.next()?, 16).ok()?;
+    }
+
+    (octets.next().is_none() && mac_address != [0; 6]).then_some(mac_address)
+}
+
+// Claims a node identifier no other live process holds, for deployments where node identifiers aren't assigned by hand.
+// The claim is a lease: it must be renewed before it expires, and released on shutdown so another process can reuse it
+pub trait NodeIdAllocator: Send {
+    fn claim(&mut self) -> io::Result<u8>;
+
+    // Extends the lease, failing when it was lost (ex: expired and claimed by another process), in which case the node identifier must stop being used
+    fn renew(&mut self) -> io::Result<()>;
+
+    fn release(&mut self) -> io::Result<()>;
+
+    // Node identifier currently held, None before claim() and after release() or a lost lease
+    fn node_identifier(&self) -> Option<u8>;
+
+    // Duration after which a lease not renewed expires
+    fn lease_duration(&self) -> Duration;
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SqlDialect {
+    Postgres, // $1 placeholders, INSERT ... ON CONFLICT DO NOTHING
+    MySql, // ? placeholders, INSERT IGNORE
+    Sqlite, // ? placeholders, INSERT ... ON CONFLICT DO NOTHING
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SqlParameter<'a> {
+    Integer(i64),
+    Text(&'a str),
+}
+
+// Runs the statements of SqlAllocator on the application database driver (ex: a sqlx, postgres or rusqlite connection)
+pub trait SqlExecutor: Send {
+    // Executes the statement with its positional parameters and returns the number of affected rows
+    fn execute(&mut self, statement: &str, parameters: &[SqlParameter]) -> io::Result<u64>;
+}
+
+// Leases node identifiers from rows of a coordination table (node_identifier, owner, expires_at in unix milliseconds) of a database every process reaches.
+// Each claim is a conditional UPDATE, so two processes can't hold the same node identifier while their leases are renewed.
+// Lease expiry is compared with the clock of each process, the lease duration must be much greater than the clock skew between them
+pub struct SqlAllocator<E: SqlExecutor> {
+    executor: E,
+    dialect: SqlDialect,
+    table: String,
+    owner: String,
+    lease_duration: Duration,
+    max_node_identifier: u8,
+    node_identifier: Option<u8>,
+}
+
+impl<E: SqlExecutor> SqlAllocator<E> {
+    // The owner must be unique among processes (ex: hostname and process id), leases held by the same owner are reclaimed after a restart
+    pub fn new(executor: E, dialect: SqlDialect, owner: &str, lease_duration: Duration) -> Self {
+        if owner.is_empty() || lease_duration.is_zero() {
+            panic!("[ERROR in SqlAllocator.new()] Lease owner should not be empty and lease duration should be greater than 0!");
+        }
+
+        SqlAllocator {
+            executor,
+            dialect,
+            table: FSDK_FSUID_SQL_LEASE_TABLE.to_string(),
+            owner: owner.to_string(),
+            lease_duration,
+            max_node_identifier: FsdkUidLayout::FSUID.max_node_identifier(),
+            node_identifier: None,
+        }
+    }
+
+    pub fn with_table(mut self, table: &str) -> Self {
+        if table.is_empty() || !table.bytes().all(|symbol| symbol.is_ascii_lowercase() || symbol.is_ascii_digit() || symbol == b'_' || symbol == b'.') {
+            panic!("[ERROR in SqlAllocator.with_table()] Lease table name should only contain lowercase ascii letters, digits, underscores and dots, but {:?} was specified!", table);
+        }
+
+        self.table = table.to_string();
+        self
+    }
+
+    // Only leases the node identifiers of the layout
+    pub fn with_layout(mut self, layout: &FsdkUidLayout) -> Self {
+        self.max_node_identifier = layout.max_node_identifier();
+        self
+    }
+
+    // Statement with ? placeholders rewritten for the dialect
+    fn statement(&self, statement: &str) -> String {
+        let statement = statement.replace("{table}", &self.table);
+
+        match self.dialect {
+            SqlDialect::MySql | SqlDialect::Sqlite => statement,
+            SqlDialect::Postgres => {
+                let mut numbered = String::with_capacity(statement.len() + 8);
+                let mut index = 0;
+
+                for character in statement.chars() {
+                    if character == '?' {
+                        index += 1;
+                        numbered.push_str(&format!("${}", index));
+                    } else {
+                        numbered.push(character);
+                    }
+                }
+
+                numbered
+            }
+        }
+    }
+
+    fn prepare(&mut self) -> io::Result<()> {
+        let create = self.statement("CREATE TABLE IF NOT EXISTS {table} (node_identifier INTEGER PRIMARY KEY, owner VARCHAR(255) NOT NULL, expires_at BIGINT NOT NULL)");
+        self.executor.execute(&create, &[])?;
+
+        let insert = self.statement(match self.dialect {
+            SqlDialect::MySql => "INSERT IGNORE INTO {table} (node_identifier, owner, expires_at) VALUES (?, '', 0)",
+            SqlDialect::Postgres | SqlDialect::Sqlite => "INSERT INTO {table} (node_identifier, owner, expires_at) VALUES (?, '', 0) ON CONFLICT (node_identifier) DO NOTHING",
+        });
+
+        for node_identifier in 0..=self.max_node_identifier {
+            self.executor.execute(&insert, &[SqlParameter::Integer(node_identifier as i64)])?;
+        }
+
+        Ok(())
+    }
+
+    fn expires_at(&self) -> i64 {
+        (fsdkuid_get_current_unix_timestamp_milliseconds() + self.lease_duration.as_millis() as u64) as i64
+    }
+}
+
+impl<E: SqlExecutor> NodeIdAllocator for SqlAllocator<E> {
+    // Creates and fills the table when missing, then tries the node identifiers starting from one derived from the owner, so concurrent processes rarely race for the same row
+    fn claim(&mut self) -> io::Result<u8> {
+        if let Some(node_identifier) = self.node_identifier {
+            return Ok(node_identifier);
+        }
+
+        self.prepare()?;
+
+        let update = self.statement("UPDATE {table} SET owner = ?, expires_at = ? WHERE node_identifier = ? AND (expires_at < ? OR owner = ?)");
+        let node_identifiers = self.max_node_identifier as u64 + 1;
+        let start = fsdkuid_hash_bytes(self.owner.bytes()) % node_identifiers;
+
+        for offset in 0..node_identifiers {
+            let node_identifier = ((start + offset) % node_identifiers) as u8;
+            let now = fsdkuid_get_current_unix_timestamp_milliseconds() as i64;
+            let parameters = [
+                SqlParameter::Text(&self.owner),
+                SqlParameter::Integer(self.expires_at()),
+                SqlParameter::Integer(node_identifier as i64),
+                SqlParameter::Integer(now),
+                SqlParameter::Text(&self.owner),
+            ];
+
+            if self.executor.execute(&update, &parameters)? == 1 {
+                self.node_identifier = Some(node_identifier);
+                return Ok(node_identifier);
+            }
+        }
+
+        Err(io::Error::other(format!("Every node identifier of {} is leased by another process", self.table)))
+    }
+
+    fn renew(&mut self) -> io::Result<()> {
+        let node_identifier = self.node_identifier.ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "No node identifier is leased"))?;
+
+        let update = self.statement("UPDATE {table} SET expires_at = ? WHERE node_identifier = ? AND owner = ?");
+        let parameters = [SqlParameter::Integer(self.expires_at()), SqlParameter::Integer(node_identifier as i64), SqlParameter::Text(&self.owner)];
+
+        if self.executor.execute(&update, &parameters)? != 1 {
+            self.node_identifier = None;
+            return Err(io::Error::new(io::ErrorKind::NotFound, format!("Lease of node identifier {} was lost", node_identifier)));
+        }
+
+        Ok(())
+    }
+
+    fn release(&mut self) -> io::Result<()> {
+        if let Some(node_identifier) = self.node_identifier.take() {
+            let update = self.statement("UPDATE {table} SET owner = '', expires_at = 0 WHERE node_identifier = ? AND owner = ?");
+            self.executor.execute(&update, &[SqlParameter::Integer(node_identifier as i64), SqlParameter::Text(&self.owner)])?;
+        }
+
+        Ok(())
+    }
+
+    fn node_identifier(&self) -> Option<u8> {
+        self.node_identifier
+    }
+
+    fn lease_duration(&self) -> Duration {
+        self.lease_duration
+    }
+}
+
+// Releases the lease on a best effort basis, an unreleased lease is reclaimable once expired
+impl<E: SqlExecutor> Drop for SqlAllocator<E> {
+    fn drop(&mut self) {
+        let _ = self.release();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex};
+
+    type SqlLeaseRows = Arc<Mutex<HashMap<i64, (String, i64)>>>;
+
+    // Executes the allocator statements on rows kept in memory, recording every statement
+    struct MemoryExecutor {
+        rows: SqlLeaseRows,
+        statements: Vec<String>,
+    }
+
+    impl SqlExecutor for MemoryExecutor {
+        fn execute(&mut self, statement: &str, parameters: &[SqlParameter]) -> io::Result<u64> {
+            self.statements.push(statement.to_string());
+            let mut rows = self.rows.lock().unwrap();
+            let integer = |index: usize| match parameters[index] {
+                SqlParameter::Integer(value) => value,
+                SqlParameter::Text(_) => panic!("Parameter {} of {} should be an integer", index, statement),
+            };
+            let text = |index: usize| match parameters[index] {
+                SqlParameter::Text(value) => value.to_string(),
+                SqlParameter::Integer(_) => panic!("Parameter {} of {} should be a text", index, statement),
+            };
+
+            if statement.starts_with("CREATE") {
+                return Ok(0);
+            }
+
+            if statement.starts_with("INSERT") {
+                return Ok(match rows.contains_key(&integer(0)) {
+                    true => 0,
+                    false => {
+                        rows.insert(integer(0), (String::new(), 0));
+                        1
+                    }
+                });
+            }
+
+            let (node_identifier, condition) = match parameters.len() {
+                5 => (integer(2), Box::new(|row: &(String, i64)| row.1 < integer(3) || row.0 == text(4)) as Box<dyn Fn(&(String, i64)) -> bool>),
+                3 => (integer(1), Box::new(|row: &(String, i64)| row.0 == text(2)) as Box<dyn Fn(&(String, i64)) -> bool>),
+                _ => (integer(0), Box::new(|row: &(String, i64)| row.0 == text(1)) as Box<dyn Fn(&(String, i64)) -> bool>),
+            };
+
+            let row = rows.get_mut(&node_identifier).unwrap();
+            if !condition(row) {
+                return Ok(0);
+            }
+
+            *row = match parameters.len() {
+                5 => (text(0), integer(1)),
+                3 => (row.0.clone(), integer(0)),
+                _ => (String::new(), 0),
+            };
+
+            Ok(1)
+        }
+    }
+
+    fn allocator(rows: &SqlLeaseRows, owner: &str, layout: &FsdkUidLayout) -> SqlAllocator<MemoryExecutor> {
+        SqlAllocator::new(MemoryExecutor { rows: rows.clone(), statements: Vec::new() }, SqlDialect::Sqlite, owner, Duration::from_secs(30)).with_layout(layout)
+    }
+
+    #[test]
+    fn test_fsdkuid_node_id_source_host_fingerprint() {
+        let mac_address = fsdkuid_parse_mac_address("02:42:ac:11:00:02\n");
+        assert_eq!(mac_address, Some([0x02, 0x42, 0xac, 0x11, 0x00, 0x02]));
+        assert_eq!(fsdkuid_parse_mac_address("00:00:00:00:00:00"), None, "[fsdkuid_parse_mac_address() Error] Interfaces without MAC address must be skipped");
+        assert_eq!(fsdkuid_parse_mac_address("02:42:ac:11:00"), None);
+
+        let layout = FsdkUidLayout::FSUID;
+        let node_identifier = fsdkuid_host_fingerprint("ids-api-1", mac_address, &layout);
+        assert_eq!(fsdkuid_host_fingerprint("IDS-API-1\n", mac_address, &layout), node_identifier, "[fsdkuid_host_fingerprint() Error] Host fingerprint must be deterministic and case insensitive");
+
+        let node_identifiers: std::collections::HashSet<u8> = (0..64).map(|index| fsdkuid_host_fingerprint(&format!("ids-api-{}", index), mac_address, &layout)).collect();
+        assert!(node_identifiers.len() > 48, "[fsdkuid_host_fingerprint() Error] Host fingerprints must spread over the node identifiers");
+
+        let short_code = FsdkUidLayout::SHORT_CODE;
+        assert!((0..64).all(|index| fsdkuid_host_fingerprint(&format!("ids-api-{}", index), None, &short_code) <= short_code.max_node_identifier()));
+
+        if let Some(node_identifier) = NodeIdSource::HostFingerprint.resolve(&short_code).unwrap() {
+            assert!(node_identifier <= short_code.max_node_identifier(), "[NodeIdSource.resolve() Error] Node identifier must fit in the layout");
+        }
+    }
+
+    #[test]
+    fn test_fsdkuid_node_id_source_chain() {
+        let layout = FsdkUidLayout::SHORT_CODE; // Node identifiers 0 to 7
+        let config_path = env::temp_dir().join(format!("fsdk_fsuid_node_id_{}.toml", std::process::id()));
+        let chain = NodeIdSource::Chain(vec![NodeIdSource::default(), NodeIdSource::ConfigFile(config_path.clone()), NodeIdSource::Fixed(0)]);
+        let environment = |value: &'static str| move |name: &str| (name == "FSDK_NODE_ID").then(|| value.to_string());
+
+        assert_eq!(chain.resolve_with(&layout, &environment(" 5 ")), Ok(Some(5)), "[NodeIdSource.resolve() Error] Node identifier must be read from FSDK_NODE_ID first");
+        assert_eq!(chain.resolve_with(&layout, &environment("")), Ok(Some(0)), "[NodeIdSource.resolve() Error] Chain must fall back to the last source");
+        assert_eq!(chain.resolve_with(&layout, &environment("8")), Err(FsdkUidError::InvalidConfiguration), "[NodeIdSource.resolve() Error] Node identifiers outside of the layout must be rejected");
+        assert_eq!(chain.resolve_with(&layout, &environment("node-1")), Err(FsdkUidError::InvalidConfiguration));
+
+        fs::write(&config_path, "node_identifier = 3\nexhaustion_policy = \"spin\"\n").unwrap();
+        let from_file = chain.resolve_with(&layout, &|_| None);
+        fs::remove_file(&config_path).unwrap();
+        assert_eq!(from_file, Ok(Some(3)), "[NodeIdSource.resolve() Error] Node identifier must be read from the config file when the environment variable isn't set");
+
+        assert_eq!(NodeIdSource::Fixed(8).resolve(&layout), Err(FsdkUidError::InvalidConfiguration));
+        assert_eq!(NodeIdSource::Chain(Vec::new()).resolve_required(&layout), Err(FsdkUidError::InvalidConfiguration));
+    }
+
+    #[test]
+    fn test_fsdkuid_node_id_source_statefulset_ordinal() {
+        let layout = FsdkUidLayout::FSUID;
+        let hostname = |value: &'static str| move |name: &str| (name == "HOSTNAME").then(|| value.to_string());
+
+        assert_eq!(NodeIdSource::StatefulSetOrdinal.resolve_with(&layout, &hostname("ids-3")), Ok(Some(3)), "[NodeIdSource.resolve() Error] Node identifier must be the pod ordinal");
+        assert_eq!(NodeIdSource::StatefulSetOrdinal.resolve_with(&layout, &hostname("fsuid-api-0")), Ok(Some(0)));
+        assert_eq!(NodeIdSource::StatefulSetOrdinal.resolve_with(&layout, &hostname("ids-256")), Err(FsdkUidError::InvalidConfiguration), "[NodeIdSource.resolve() Error] Pod ordinals outside of the layout must be rejected");
+
+        for hostname_value in ["ids-7f9c6d8b5-x2x4k", "ids", "ids-", "-3", "ids-03"] {
+            assert_eq!(NodeIdSource::StatefulSetOrdinal.resolve_with(&layout, &hostname(hostname_value)), Ok(None), "[NodeIdSource.resolve() Error] {} isn't a StatefulSet pod hostname", hostname_value);
+        }
+
+        assert_eq!(NodeIdSource::StatefulSetOrdinal.resolve_with(&layout, &|_| None), Ok(None));
+    }
+
+    #[test]
+    fn test_fsdkuid_node_id_source_container_id() {
+        let docker = "12:memory:/docker/3f4e8a9d2c1b0e7f6a5d4c3b2a1f0e9d8c7b6a5f4e3d2c1b0a9f8e7d6c5b4a39\n0::/docker/3f4e8a9d2c1b0e7f6a5d4c3b2a1f0e9d8c7b6a5f4e3d2c1b0a9f8e7d6c5b4a39\n";
+        assert_eq!(fsdkuid_container_identifier(docker), Some("3f4e8a9d2c1b0e7f6a5d4c3b2a1f0e9d8c7b6a5f4e3d2c1b0a9f8e7d6c5b4a39"));
+
+        let kubernetes = "0::/kubepods.slice/kubepods-besteffort.slice/kubepods-besteffort-pod7c9e.slice/cri-containerd-a1b2c3d4e5f60718293a4b5c6d7e8f90a1b2c3d4e5f60718293a4b5c6d7e8f90.scope\n";
+        assert_eq!(fsdkuid_container_identifier(kubernetes), Some("a1b2c3d4e5f60718293a4b5c6d7e8f90a1b2c3d4e5f60718293a4b5c6d7e8f90"), "[fsdkuid_container_identifier() Error] Container identifier must be read from cgroup v2 paths");
+
+        let mountinfo = "706 688 0:51 / / rw,relatime - overlay overlay rw\n732 706 259:1 /var/lib/docker/containers/9d8c7b6a5f4e3d2c1b0a9f8e7d6c5b4a3f4e8a9d2c1b0e7f6a5d4c3b2a1f0e9d/hostname /etc/hostname rw - ext4 /dev/nvme0n1p1 rw\n";
+        assert_eq!(fsdkuid_container_identifier(mountinfo), Some("9d8c7b6a5f4e3d2c1b0a9f8e7d6c5b4a3f4e8a9d2c1b0e7f6a5d4c3b2a1f0e9d"), "[fsdkuid_container_identifier() Error] Container identifier must be read from mounts when the cgroup is namespaced");
+
+        assert_eq!(fsdkuid_container_identifier("0::/\n"), None);
+        assert_eq!(fsdkuid_container_identifier("0::/user.slice/user-1000.slice/session-2.scope\n"), None);
+
+        if let Some(node_identifier) = NodeIdSource::ContainerId.resolve(&FsdkUidLayout::SHORT_CODE).unwrap() {
+            assert!(node_identifier <= FsdkUidLayout::SHORT_CODE.max_node_identifier(), "[NodeIdSource.resolve() Error] Node identifier must fit in the layout");
+        }
+    }
+
+    #[test]
+    fn test_fsdkuid_sql_allocator_claims_distinct_node_identifiers() {
+        let rows: SqlLeaseRows = Arc::new(Mutex::new(HashMap::new()));
+        let layout = FsdkUidLayout::SHORT_CODE; // 8 node identifiers
+
+        let mut allocators: Vec<SqlAllocator<MemoryExecutor>> = (0..8).map(|index| allocator(&rows, &format!("host-{}", index), &layout)).collect();
+        let mut node_identifiers: Vec<u8> = allocators.iter_mut().map(|allocator| allocator.claim().unwrap()).collect();
+        node_identifiers.sort();
+
+        assert_eq!(node_identifiers, (0..8).collect::<Vec<u8>>(), "[SqlAllocator.claim() Error] Every process must lease a different node identifier");
+        assert!(allocator(&rows, "host-8", &layout).claim().is_err(), "[SqlAllocator.claim() Error] Claim must fail when every node identifier is leased");
+
+        let released = allocators[3].node_identifier().unwrap();
+        allocators[3].release().unwrap();
+        assert_eq!(allocators[3].node_identifier(), None);
+        assert_eq!(allocator(&rows, "host-8", &layout).claim().unwrap(), released, "[SqlAllocator.claim() Error] Released node identifier must be claimable");
+    }
+
+    #[test]
+    fn test_fsdkuid_sql_allocator_lease_expiry_and_renewal() {
+        let rows: SqlLeaseRows = Arc::new(Mutex::new(HashMap::new()));
+        let layout = FsdkUidLayout::new(0, 1, 48, 0, 8); // Single node identifier
+
+        let mut first = allocator(&rows, "host-a", &layout);
+        assert_eq!(first.claim().unwrap(), 0);
+        first.renew().unwrap();
+        assert_eq!(first.claim().unwrap(), 0, "[SqlAllocator.claim() Error] Claim must return the node identifier already leased");
+
+        rows.lock().unwrap().get_mut(&0).unwrap().1 = 0; // Lease expired without renewal
+        let mut second = allocator(&rows, "host-b", &layout);
+        assert_eq!(second.claim().unwrap(), 0, "[SqlAllocator.claim() Error] Expired lease must be claimable");
+
+        assert_eq!(first.renew().unwrap_err().kind(), io::ErrorKind::NotFound, "[SqlAllocator.renew() Error] Renewal of a lost lease must fail");
+        assert_eq!(first.node_identifier(), None);
+        second.renew().unwrap();
+
+        let mut restarted = allocator(&rows, "host-b", &layout);
+        assert_eq!(restarted.claim().unwrap(), 0, "[SqlAllocator.claim() Error] Owner must reclaim its own lease after a restart");
+    }
+
+    #[test]
+    fn test_fsdkuid_sql_allocator_postgres_placeholders() {
+        let rows: SqlLeaseRows = Arc::new(Mutex::new(HashMap::new()));
+        let mut postgres = SqlAllocator::new(MemoryExecutor { rows, statements: Vec::new() }, SqlDialect::Postgres, "host", Duration::from_secs(30)).with_table("ids.leases");
+        postgres.claim().unwrap();
+
+        assert!(postgres.executor.statements.iter().any(|statement| statement == "UPDATE ids.leases SET owner = $1, expires_at = $2 WHERE node_identifier = $3 AND (expires_at < $4 OR owner = $5)"));
+        assert!(postgres.executor.statements[1].ends_with("VALUES ($1, '', 0) ON CONFLICT (node_identifier) DO NOTHING"));
+    }
+}
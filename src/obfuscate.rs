@@ -0,0 +1,69 @@
+use crate::ring::fsdkuid_mix64;
+use crate::FsdkUid;
+
+const FSDK_FSUID_FEISTEL_ROUNDS: u64 = 8; // Number of Feistel rounds, enough for every output bit to depend on every input and key bit
+
+// Keyed round function of the Feistel network, mixing the right half with the round key
+fn fsdkuid_feistel_round(half: u32, key: u64, round: u64) -> u32 {
+    fsdkuid_mix64(half as u64 ^ fsdkuid_mix64(key ^ round)) as u32
+}
+
+impl FsdkUid {
+    // Maps the FSUID to an opaque public value with a keyed Feistel permutation of its 64 bits, so public values don't reveal the generation order or volume.
+    // Reversible with FsdkUid::deobfuscate() and the same key, it hinders enumeration but isn't encryption (the key must still be kept secret)
+    pub fn obfuscate(&self, key: u64) -> u64 {
+        let mut left = (self.i64() as u64 >> 32) as u32;
+        let mut right = self.i64() as u32;
+
+        for round in 0..FSDK_FSUID_FEISTEL_ROUNDS {
+            (left, right) = (right, left ^ fsdkuid_feistel_round(right, key, round));
+        }
+
+        ((left as u64) << 32) | right as u64
+    }
+
+    pub fn deobfuscate(value: u64, key: u64) -> FsdkUid {
+        let mut left = (value >> 32) as u32;
+        let mut right = value as u32;
+
+        for round in (0..FSDK_FSUID_FEISTEL_ROUNDS).rev() {
+            (left, right) = (right ^ fsdkuid_feistel_round(left, key, round), left);
+        }
+
+        FsdkUid::new((((left as u64) << 32) | right as u64) as i64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    const KEY: u64 = 0x5eed_f5d4_0000_c0de;
+
+    #[test]
+    fn test_fsdkuid_obfuscate_round_trip() {
+        for value in [0, 1, -1, i64::MIN, i64::MAX, 113131996488794368] {
+            let fsuid = FsdkUid::new(value);
+            assert_eq!(FsdkUid::deobfuscate(fsuid.obfuscate(KEY), KEY), fsuid, "[FsdkUid::deobfuscate() Error] FSUID {} didn't round-trip", value);
+        }
+
+        let fsuid = FsdkUid::new(113131996488794368);
+        assert_ne!(fsuid.obfuscate(KEY), fsuid.i64() as u64);
+        assert_ne!(fsuid.obfuscate(KEY), fsuid.obfuscate(KEY + 1), "[fsuid.obfuscate() Error] Different keys must give different public values");
+        assert_ne!(FsdkUid::deobfuscate(fsuid.obfuscate(KEY), KEY + 1), fsuid);
+    }
+
+    #[test]
+    fn test_fsdkuid_obfuscate_hides_order() {
+        let public_values: Vec<u64> = (0..1000).map(|counter| FsdkUid::new(113131996488794368 + counter).obfuscate(KEY)).collect();
+
+        assert_eq!(public_values.iter().collect::<HashSet<_>>().len(), public_values.len(), "[fsuid.obfuscate() Error] Public values of different FSUID's must differ");
+
+        let increasing = public_values.windows(2).filter(|pair| pair[0] < pair[1]).count();
+        assert!((400..600).contains(&increasing), "[fsuid.obfuscate() Error] Public values of consecutive FSUID's must not keep their order, {} of 999 increased", increasing);
+
+        let high_bits_changed = public_values.windows(2).filter(|pair| (pair[0] ^ pair[1]) >> 32 != 0).count();
+        assert!(high_bits_changed > 990, "[fsuid.obfuscate() Error] Consecutive FSUID's must change the whole public value");
+    }
+}
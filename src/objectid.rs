@@ -0,0 +1,74 @@
+use crate::{FsdkUid, FsdkUidError};
+
+const FSDK_FSUID_OBJECT_ID_BYTES: usize = 12; // Number of bytes of a MongoDB ObjectId: 4 seconds bytes, 5 machine and process bytes and 3 counter bytes
+
+impl FsdkUid {
+    // Maps a MongoDB ObjectId (ex: bson::oid::ObjectId::bytes()) to a FSUID generated at its second: the machine and process bytes are folded (xor) into the node identifier
+    // and the low counter byte becomes the node counter. Lossy (several ObjectId's can map to the same FSUID), intended for querying legacy collections by time
+    pub fn from_object_id(object_id: [u8; FSDK_FSUID_OBJECT_ID_BYTES]) -> Self {
+        let seconds = u32::from_be_bytes([object_id[0], object_id[1], object_id[2], object_id[3]]) as i64;
+        let node_identifier = object_id[4..9].iter().fold(0, |folded, byte| folded ^ byte);
+
+        FsdkUid::from_parts(seconds * 1000, node_identifier, object_id[11])
+    }
+
+    // Same as FsdkUid::from_object_id() from the 24 hexadecimal digits text of the ObjectId, in any case
+    pub fn from_object_id_hex(text: &str) -> Result<Self, FsdkUidError> {
+        if text.is_empty() {
+            return Err(FsdkUidError::Empty);
+        }
+
+        if text.len() != FSDK_FSUID_OBJECT_ID_BYTES * 2 {
+            return Err(FsdkUidError::InvalidLength);
+        }
+
+        let mut object_id = [0u8; FSDK_FSUID_OBJECT_ID_BYTES];
+
+        for (position, character) in text.chars().enumerate() {
+            let digit = character.to_digit(16).ok_or(FsdkUidError::InvalidCharacter { character, position })?;
+            object_id[position / 2] = (object_id[position / 2] << 4) | digit as u8;
+        }
+
+        Ok(FsdkUid::from_object_id(object_id))
+    }
+
+    // Smallest ObjectId of the second the FSUID was generated at, so legacy collections can be queried by time (ex: {_id: {$gte: lower_bound}}).
+    // Fails with FsdkUidError::Overflow for FSUID's generated after the last ObjectId second (2106-02-07)
+    pub fn object_id_lower_bound(&self) -> Result<[u8; FSDK_FSUID_OBJECT_ID_BYTES], FsdkUidError> {
+        let seconds = u32::try_from(self.timestamp_delta() / 1000).map_err(|_| FsdkUidError::Overflow)?;
+
+        let mut object_id = [0u8; FSDK_FSUID_OBJECT_ID_BYTES];
+        object_id[..4].copy_from_slice(&seconds.to_be_bytes());
+        Ok(object_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    #[test]
+    fn test_fsdkuid_from_object_id() {
+        let fsuid = FsdkUid::from_object_id_hex("66e498765f3a2b1c0d000102").unwrap();
+
+        assert_eq!(fsuid.utc_datetime(), Utc.with_ymd_and_hms(2024, 9, 13, 19, 54, 30).unwrap(), "[FsdkUid::from_object_id() Error] ObjectId seconds must become the timestamp delta");
+        assert_eq!(fsuid.node_identifier(), 0x5f ^ 0x3a ^ 0x2b ^ 0x1c ^ 0x0d, "[FsdkUid::from_object_id() Error] Machine and process bytes must be folded into the node identifier");
+        assert_eq!(fsuid.node_counter(), 0x02);
+        assert_eq!(FsdkUid::from_object_id_hex("66E498765F3A2B1C0D000102"), Ok(fsuid));
+
+        assert_eq!(FsdkUid::from_object_id_hex(""), Err(FsdkUidError::Empty));
+        assert_eq!(FsdkUid::from_object_id_hex("66e498765f3a2b1c0d0001"), Err(FsdkUidError::InvalidLength));
+        assert_eq!(FsdkUid::from_object_id_hex("66e498765f3a2b1c0d00010g"), Err(FsdkUidError::InvalidCharacter { character: 'g', position: 23 }));
+    }
+
+    #[test]
+    fn test_fsdkuid_object_id_lower_bound() {
+        let fsuid = FsdkUid::new(113131996488794368);
+        let lower_bound = fsuid.object_id_lower_bound().unwrap();
+
+        assert_eq!(lower_bound, [0x66, 0xe4, 0x98, 0x76, 0, 0, 0, 0, 0, 0, 0, 0], "[fsuid.object_id_lower_bound() Error] Lower bound must be the FSUID second followed by zeros");
+        assert!(FsdkUid::from_object_id(lower_bound) <= fsuid);
+        assert_eq!(FsdkUid::from_parts((u32::MAX as i64 + 1) * 1000, 0, 0).object_id_lower_bound(), Err(FsdkUidError::Overflow));
+    }
+}
@@ -0,0 +1,52 @@
+// OpenTelemetry correlation without depending on the opentelemetry crates: span ids derived from FSUID's (SpanId::from_bytes(fsuid.to_otel_span_id()))
+// and the attribute recording the FSUID (span.set_attribute(KeyValue::new(FSDK_FSUID_OTEL_ATTRIBUTE_KEY, fsuid.to_string())))
+
+use crate::ring::fsdkuid_mix64;
+use crate::FsdkUid;
+
+pub const FSDK_FSUID_OTEL_ATTRIBUTE_KEY: &str = "fsdk.fsuid"; // Span attribute key of the FSUID of the entity a span handles
+
+impl FsdkUid {
+    // Stable 64 bits span id: the mixing is bijective so different FSUID's give different span ids, and spreads FSUID's generated in sequence over the whole range as tracing backends expect.
+    // The single FSUID mixed to the invalid zero span id gets the span id of the zero FSUID remixed instead
+    pub fn to_otel_span_id(&self) -> [u8; 8] {
+        match fsdkuid_mix64(self.i64() as u64) {
+            0 => fsdkuid_mix64(fsdkuid_mix64(0)).to_be_bytes(),
+            span_id => span_id.to_be_bytes(),
+        }
+    }
+
+    // Lowercase hexadecimal form of the span id used by the W3C traceparent header and tracing backends
+    pub fn to_otel_span_id_hex(&self) -> String {
+        format!("{:016x}", u64::from_be_bytes(self.to_otel_span_id()))
+    }
+
+    // Span attribute recording the FSUID in its Base62 format
+    pub fn to_otel_attribute(&self) -> (&'static str, String) {
+        (FSDK_FSUID_OTEL_ATTRIBUTE_KEY, self.to_base62())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_fsdkuid_otel_span_id() {
+        let fsuid = FsdkUid::new(113131996488794368);
+
+        assert_eq!(fsuid.to_otel_span_id(), FsdkUid::new(113131996488794368).to_otel_span_id(), "[fsuid.to_otel_span_id() Error] Span id must be stable");
+        assert_eq!(fsuid.to_otel_span_id_hex(), format!("{:016x}", u64::from_be_bytes(fsuid.to_otel_span_id())));
+        assert_eq!(fsuid.to_otel_span_id_hex().len(), 16);
+
+        let span_ids: HashSet<[u8; 8]> = (0..10000).map(|counter| FsdkUid::from_parts(1726257270642 + counter / 256, 1, (counter % 256) as u8).to_otel_span_id()).collect();
+        assert_eq!(span_ids.len(), 10000, "[fsuid.to_otel_span_id() Error] Different FSUID's must give different span ids");
+        assert!(!span_ids.contains(&[0; 8]), "[fsuid.to_otel_span_id() Error] Span id must never be the invalid zero span id");
+    }
+
+    #[test]
+    fn test_fsdkuid_otel_attribute() {
+        assert_eq!(FsdkUid::new(113131996488794368).to_otel_attribute(), ("fsdk.fsuid", "8M92EuiIHw".to_string()));
+    }
+}
@@ -0,0 +1,134 @@
+use std::str::FromStr;
+
+use crate::{fsdkuid_parse_decimal, Encoding, FsdkUid, FsdkUidError};
+
+const FSDK_FSUID_PREFIX_SEPARATOR: char = '_'; // Character between the prefix and the Base62 FSUID of prefixed texts
+
+impl FsdkUid {
+    // Parses the text only in the given encoding, for callers that must reject texts another format could also read (ex: "12345" is valid decimal and Base62)
+    pub fn parse_strict<E: Encoding>(text: &str) -> Result<Self, FsdkUidError> {
+        E::decode(text)
+    }
+
+    // Parses a raw JSON value (ex: serde_json::value::RawValue.get()) that is either a number (123) or a string ("123", "usr_8M92EuiIHw"),
+    // since producers send FSUID's in both forms depending on their language. Numbers must be integers and strings are read like FsdkUid::from_str()
+    pub fn from_json_token(token: &str) -> Result<Self, FsdkUidError> {
+        let trimmed = token.trim_start();
+        let offset = token.len() - trimmed.len();
+        let trimmed = trimmed.trim_end();
+
+        let (text, offset) = match trimmed.strip_prefix('"') {
+            Some(quoted) => (quoted.strip_suffix('"').ok_or(FsdkUidError::InvalidLength)?, offset + 1),
+            None => {
+                let digits = trimmed.strip_prefix('-').unwrap_or(trimmed);
+                if let Some((position, character)) = digits.char_indices().find(|(_, character)| !character.is_ascii_digit()) {
+                    return Err(FsdkUidError::InvalidCharacter { character, position: offset + trimmed.len() - digits.len() + position });
+                }
+
+                (trimmed, offset)
+            }
+        };
+
+        if let Some(position) = text.find('\\') {
+            return Err(FsdkUidError::InvalidCharacter { character: '\\', position: offset + position });
+        }
+
+        text.parse().map_err(|error| match error {
+            FsdkUidError::InvalidCharacter { character, position } => FsdkUidError::InvalidCharacter { character, position: position + offset },
+            error => error,
+        })
+    }
+}
+
+// Detects the format of the text, tried in this order:
+// fsuid: URI or urn:fsuid: URN, hexadecimal with 0x prefix, decimal (optional minus sign, only digits), prefixed (<prefix>_<base62>) and Base62.
+// Texts made of digits are always read as decimal, use FsdkUid::parse_strict() when they may be Base62
+impl FromStr for FsdkUid {
+    type Err = FsdkUidError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        if text.is_empty() {
+            return Err(FsdkUidError::Empty);
+        }
+
+        if let Some((scheme, _)) = text.split_once(':') {
+            if scheme.eq_ignore_ascii_case("fsuid") || scheme.eq_ignore_ascii_case("urn") {
+                return FsdkUid::from_uri(text);
+            }
+        }
+
+        if text.starts_with("0x") || text.starts_with("0X") {
+            return FsdkUid::from_hex(text);
+        }
+
+        let digits = text.strip_prefix('-').unwrap_or(text);
+        if !digits.is_empty() && digits.bytes().all(|symbol| symbol.is_ascii_digit()) {
+            return fsdkuid_parse_decimal(text.as_bytes()).map(FsdkUid::new);
+        }
+
+        if let Some((prefix, base62)) = text.rsplit_once(FSDK_FSUID_PREFIX_SEPARATOR) {
+            if prefix.is_empty() || !prefix.bytes().all(|symbol| symbol.is_ascii_lowercase() || symbol.is_ascii_digit()) {
+                return Err(FsdkUidError::PrefixMismatch);
+            }
+
+            return FsdkUid::from_base62(base62).map_err(|error| match error {
+                FsdkUidError::InvalidCharacter { character, position } => FsdkUidError::InvalidCharacter { character, position: position + prefix.len() + 1 },
+                error => error,
+            });
+        }
+
+        FsdkUid::from_base62(text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Base62, PrefixedFsdkUid};
+
+    #[test]
+    fn test_fsdkuid_from_str() {
+        let fsuid = FsdkUid::new(113131996488794368);
+
+        assert_eq!("113131996488794368".parse(), Ok(fsuid), "[FsdkUid::from_str() Error] Digits must be read as decimal");
+        assert_eq!("-1".parse(), Ok(FsdkUid::new(-1)));
+        assert_eq!("0x191ecf38f720100".parse(), Ok(fsuid), "[FsdkUid::from_str() Error] 0x prefixed text must be read as hexadecimal");
+        assert_eq!("8M92EuiIHw".parse(), Ok(fsuid), "[FsdkUid::from_str() Error] Other text must be read as Base62");
+        assert_eq!("usr_8M92EuiIHw".parse(), Ok(fsuid), "[FsdkUid::from_str() Error] Prefixed text must be read without its prefix");
+        assert_eq!(PrefixedFsdkUid::new("org2", fsuid).to_string().parse(), Ok(fsuid));
+        assert_eq!(fsuid.to_uri().parse(), Ok(fsuid));
+        assert_eq!(fsuid.to_urn().parse(), Ok(fsuid));
+
+        assert_eq!("".parse::<FsdkUid>(), Err(FsdkUidError::Empty));
+        assert_eq!("0113131996488794368".parse::<FsdkUid>(), Err(FsdkUidError::NonCanonical));
+        assert_eq!("99999999999999999999".parse::<FsdkUid>(), Err(FsdkUidError::Overflow));
+        assert_eq!("User_8M92EuiIHw".parse::<FsdkUid>(), Err(FsdkUidError::PrefixMismatch));
+        assert_eq!("usr_8M92-uiIHw".parse::<FsdkUid>(), Err(FsdkUidError::InvalidCharacter { character: '-', position: 8 }));
+    }
+
+    #[test]
+    fn test_fsdkuid_from_json_token() {
+        let fsuid = FsdkUid::new(113131996488794368);
+
+        assert_eq!(FsdkUid::from_json_token("113131996488794368"), Ok(fsuid), "[FsdkUid::from_json_token() Error] JSON numbers must be read as decimal");
+        assert_eq!(FsdkUid::from_json_token("\"113131996488794368\""), Ok(fsuid), "[FsdkUid::from_json_token() Error] JSON strings must be read like from_str()");
+        assert_eq!(FsdkUid::from_json_token(" \"usr_8M92EuiIHw\"\n"), Ok(fsuid));
+        assert_eq!(FsdkUid::from_json_token("\"8M92EuiIHw\""), Ok(fsuid));
+        assert_eq!(FsdkUid::from_json_token("-1"), Ok(FsdkUid::new(-1)));
+
+        assert_eq!(FsdkUid::from_json_token("1.5e3"), Err(FsdkUidError::InvalidCharacter { character: '.', position: 1 }), "[FsdkUid::from_json_token() Error] JSON numbers must be integers");
+        assert_eq!(FsdkUid::from_json_token("\"8M92EuiIHw"), Err(FsdkUidError::InvalidLength));
+        assert_eq!(FsdkUid::from_json_token("\"8M92\\u0045uiIHw\""), Err(FsdkUidError::InvalidCharacter { character: '\\', position: 5 }));
+        assert_eq!(FsdkUid::from_json_token("\"8M92-uiIHw\""), Err(FsdkUidError::InvalidCharacter { character: '-', position: 5 }));
+        assert_eq!(FsdkUid::from_json_token("\"\""), Err(FsdkUidError::Empty));
+        assert_eq!(FsdkUid::from_json_token("null"), Err(FsdkUidError::InvalidCharacter { character: 'n', position: 0 }));
+    }
+
+    #[test]
+    fn test_fsdkuid_parse_strict() {
+        assert_eq!(FsdkUid::parse_strict::<Base62>("12345"), Ok(FsdkUid::new(15264777)), "[FsdkUid::parse_strict() Error] Digits must be read in the given encoding");
+        assert_eq!("12345".parse(), Ok(FsdkUid::new(12345)));
+        assert_eq!(FsdkUid::parse_strict::<Base62>("usr_8M92EuiIHw"), Err(FsdkUidError::InvalidCharacter { character: '_', position: 3 }));
+        assert_eq!(FsdkUid::parse_strict::<Base62>("0x1f"), Err(FsdkUidError::NonCanonical));
+    }
+}
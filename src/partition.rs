@@ -0,0 +1,45 @@
+use crate::FsdkUid;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Granularity {
+    Month, // yyyymm (ex: 202503)
+    Day, // yyyymmdd (ex: 20250301)
+    Hour, // yyyymmddhh (ex: 2025030114)
+}
+
+impl Granularity {
+    fn format(&self) -> &'static str {
+        match self {
+            Granularity::Month => "%Y%m",
+            Granularity::Day => "%Y%m%d",
+            Granularity::Hour => "%Y%m%d%H",
+        }
+    }
+}
+
+impl FsdkUid {
+    // UTC time partition of the FSUID generation time, so time-partitioned tables and object storage prefixes can be computed from the FSUID alone.
+    // Keys of the same granularity sort in time order as text
+    pub fn partition_key(&self, granularity: Granularity) -> String {
+        self.utc_datetime().format(granularity.format()).to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fsdkuid_partition_key() {
+        let fsuid = FsdkUid::new(113131996488794368); // 2024-09-13T19:54:30.642Z
+
+        assert_eq!(fsuid.partition_key(Granularity::Month), "202409", "[fsuid.partition_key() Error] Month partition key must be yyyymm");
+        assert_eq!(fsuid.partition_key(Granularity::Day), "20240913", "[fsuid.partition_key() Error] Day partition key must be yyyymmdd");
+        assert_eq!(fsuid.partition_key(Granularity::Hour), "2024091319", "[fsuid.partition_key() Error] Hour partition key must be yyyymmddhh");
+        assert_eq!(FsdkUid::new(0).partition_key(Granularity::Hour), "1970010100");
+
+        let before_midnight = FsdkUid::from_parts(1726271999999, 1, 255);
+        let after_midnight = FsdkUid::from_parts(1726272000000, 1, 0);
+        assert_eq!((before_midnight.partition_key(Granularity::Day), after_midnight.partition_key(Granularity::Day)), ("20240913".to_string(), "20240914".to_string()));
+    }
+}
@@ -0,0 +1,82 @@
+use std::fmt;
+
+use crate::{FsdkUid, FsdkUidError};
+
+const FSDK_FSUID_PREFIX_SEPARATOR: char = '_'; // Character between the prefix and the Base62 FSUID
+
+// Self-describing FSUID rendered as <prefix>_<base62> (ex: usr_8M92EuiIHw), the prefix names the kind of entity identified by the FSUID
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PrefixedFsdkUid {
+    prefix: &'static str,
+    fsuid: FsdkUid,
+}
+
+impl PrefixedFsdkUid {
+    pub fn new(prefix: &'static str, fsuid: FsdkUid) -> Self {
+        if prefix.is_empty() || !prefix.bytes().all(|symbol| symbol.is_ascii_lowercase() || symbol.is_ascii_digit()) {
+            panic!("[ERROR in PrefixedFsdkUid.new()] FSUID prefix should only contain lowercase ascii letters and digits, but {:?} was specified!", prefix);
+        }
+
+        PrefixedFsdkUid { prefix, fsuid }
+    }
+
+    // Parses a prefixed FSUID, rejecting texts with a prefix other than the expected one
+    pub fn parse(text: &str, expected_prefix: &'static str) -> Result<Self, FsdkUidError> {
+        let (prefix, base62) = text.rsplit_once(FSDK_FSUID_PREFIX_SEPARATOR).ok_or(FsdkUidError::PrefixMismatch)?;
+
+        if prefix != expected_prefix {
+            return Err(FsdkUidError::PrefixMismatch);
+        }
+
+        FsdkUid::from_base62(base62)
+            .map(|fsuid| PrefixedFsdkUid::new(expected_prefix, fsuid))
+            .map_err(|error| match error {
+                FsdkUidError::InvalidCharacter { character, position } => FsdkUidError::InvalidCharacter { character, position: position + prefix.len() + 1 },
+                error => error,
+            })
+    }
+
+    pub fn prefix(&self) -> &'static str {
+        self.prefix
+    }
+
+    pub fn fsuid(&self) -> FsdkUid {
+        self.fsuid
+    }
+}
+
+impl fmt::Display for PrefixedFsdkUid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}{}", self.prefix, FSDK_FSUID_PREFIX_SEPARATOR, self.fsuid.to_base62())
+    }
+}
+
+impl From<PrefixedFsdkUid> for FsdkUid {
+    fn from(prefixed: PrefixedFsdkUid) -> Self {
+        prefixed.fsuid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fsdkuid_prefixed() {
+        let prefixed = PrefixedFsdkUid::new("usr", FsdkUid::new(113131996488794368));
+        assert_eq!(prefixed.to_string(), "usr_8M92EuiIHw", "[PrefixedFsdkUid Display Error] Prefixed FSUID must be rendered as usr_8M92EuiIHw");
+        assert_eq!(PrefixedFsdkUid::parse("usr_8M92EuiIHw", "usr"), Ok(prefixed));
+        assert_eq!(FsdkUid::from(prefixed), FsdkUid::new(113131996488794368));
+
+        assert_eq!(PrefixedFsdkUid::parse("org_8M92EuiIHw", "usr"), Err(FsdkUidError::PrefixMismatch), "[PrefixedFsdkUid::parse() Error] Texts with another prefix must be rejected");
+        assert_eq!(PrefixedFsdkUid::parse("8M92EuiIHw", "usr"), Err(FsdkUidError::PrefixMismatch));
+        assert_eq!(PrefixedFsdkUid::parse("usr_", "usr"), Err(FsdkUidError::Empty));
+        assert_eq!(PrefixedFsdkUid::parse("usr_8M92-uiIHw", "usr"), Err(FsdkUidError::InvalidCharacter { character: '-', position: 8 }));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_fsdkuid_prefixed_rejects_invalid_prefix() {
+        PrefixedFsdkUid::new("User_", FsdkUid::new(0));
+    }
+}
@@ -0,0 +1,105 @@
+// Protocol Buffers interoperability without depending on prost: FSUID's are sfixed64 (or int64) fields, converted with From<i64> and From<FsdkUid> for i64,
+// or the Fsuid message below, whose wire format is encoded and decoded here so services don't hand-write the glue
+
+use crate::{FsdkUid, FsdkUidError, FsdkUidVarintMode};
+
+pub const FSDK_FSUID_PROTO_DEFINITION: &str = "syntax = \"proto3\";\n\npackage fsdk.uid.v1;\n\nmessage Fsuid {\n  sfixed64 value = 1;\n}\n"; // Definition of the Fsuid message to compile with prost-build or protoc
+pub const FSDK_FSUID_PROTO_LENGTH: usize = 9; // Number of bytes of an encoded Fsuid message (1 byte key and 8 bytes sfixed64)
+
+const FSDK_FSUID_PROTO_VALUE_KEY: u8 = (1 << 3) | 1; // Key of the Fsuid.value field (field number 1, wire type 1 for 64 bits)
+
+impl From<i64> for FsdkUid {
+    fn from(fsuid: i64) -> Self {
+        FsdkUid::new(fsuid)
+    }
+}
+
+impl From<FsdkUid> for i64 {
+    fn from(fsuid: FsdkUid) -> Self {
+        fsuid.i64()
+    }
+}
+
+impl FsdkUid {
+    // Fsuid message in protobuf wire format, always written even when 0 so the bytes have a fixed length
+    pub fn encode_proto(&self) -> [u8; FSDK_FSUID_PROTO_LENGTH] {
+        let mut bytes = [0u8; FSDK_FSUID_PROTO_LENGTH];
+        bytes[0] = FSDK_FSUID_PROTO_VALUE_KEY;
+        bytes[1..].copy_from_slice(&self.to_le_bytes());
+        bytes
+    }
+
+    // Decodes a Fsuid message following proto3 rules: unknown fields are skipped, the last value wins and a missing value is 0
+    pub fn decode_proto(bytes: &[u8]) -> Result<Self, FsdkUidError> {
+        let mut fsuid = FsdkUid::new(0);
+        let mut position = 0;
+
+        while position < bytes.len() {
+            let (key, length) = fsdkuid_proto_read_varint(&bytes[position..])?;
+            position += length;
+
+            let field_length = match key & 0x07 {
+                0 => fsdkuid_proto_read_varint(&bytes[position..])?.1,
+                1 => 8,
+                2 => {
+                    let (value, length) = fsdkuid_proto_read_varint(&bytes[position..])?;
+                    position += length;
+                    usize::try_from(value).map_err(|_| FsdkUidError::InvalidLength)?
+                }
+                5 => 4,
+                _ => return Err(FsdkUidError::NonCanonical),
+            };
+
+            let field = bytes.get(position..position.checked_add(field_length).ok_or(FsdkUidError::InvalidLength)?).ok_or(FsdkUidError::InvalidLength)?;
+
+            if key == FSDK_FSUID_PROTO_VALUE_KEY as u64 {
+                fsuid = FsdkUid::from_le_bytes(field.try_into().unwrap());
+            }
+
+            position += field_length;
+        }
+
+        Ok(fsuid)
+    }
+}
+
+fn fsdkuid_proto_read_varint(bytes: &[u8]) -> Result<(u64, usize), FsdkUidError> {
+    let (value, length) = FsdkUid::decode_varint(bytes, FsdkUidVarintMode::Unsigned).map_err(|error| match error {
+        FsdkUidError::Empty => FsdkUidError::InvalidLength,
+        error => error,
+    })?;
+
+    Ok((value.i64() as u64, length))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fsdkuid_i64_conversions() {
+        let fsuid = FsdkUid::from(113131996488794368);
+        assert_eq!(fsuid, FsdkUid::new(113131996488794368));
+        assert_eq!(i64::from(fsuid), 113131996488794368, "[i64::from() Error] FSUID must convert to its i64 value");
+    }
+
+    #[test]
+    fn test_fsdkuid_proto() {
+        let fsuid = FsdkUid::new(113131996488794368);
+        let bytes = fsuid.encode_proto();
+
+        assert_eq!(bytes, [0x09, 0x00, 0x01, 0x72, 0x8f, 0xf3, 0xec, 0x91, 0x01], "[fsuid.encode_proto() Error] Fsuid message must be a sfixed64 field 1");
+        assert_eq!(FsdkUid::decode_proto(&bytes), Ok(fsuid));
+        assert_eq!(FsdkUid::decode_proto(&FsdkUid::new(-1).encode_proto()), Ok(FsdkUid::new(-1)));
+        assert_eq!(FsdkUid::decode_proto(&[]), Ok(FsdkUid::new(0)), "[FsdkUid::decode_proto() Error] Missing value must default to 0");
+
+        // Unknown varint, length-delimited and 32 bits fields before the value
+        let mut message = vec![0x10, 0x96, 0x01, 0x1a, 0x02, 0x61, 0x62, 0x25, 0x01, 0x02, 0x03, 0x04];
+        message.extend_from_slice(&bytes);
+        assert_eq!(FsdkUid::decode_proto(&message), Ok(fsuid), "[FsdkUid::decode_proto() Error] Unknown fields must be skipped");
+
+        assert_eq!(FsdkUid::decode_proto(&bytes[..8]), Err(FsdkUidError::InvalidLength));
+        assert_eq!(FsdkUid::decode_proto(&[0x1a, 0x05, 0x61]), Err(FsdkUidError::InvalidLength));
+        assert_eq!(FsdkUid::decode_proto(&[0x0b]), Err(FsdkUidError::NonCanonical));
+    }
+}
@@ -0,0 +1,69 @@
+use chrono::{DateTime, Utc};
+
+use crate::{FsdkUid, FsdkUidError, FSDK_FSUID_MAX_NODE_COUNTER, FSDK_FSUID_MAX_NODE_IDENTIFIER, FSDK_FSUID_MAX_TIMESTAMP_DELTA};
+
+impl FsdkUid {
+    // Smallest FSUID any node can generate at the millisecond of the datetime, the inclusive lower bound of time range queries on FSUID columns.
+    // Fails with FsdkUidError::Overflow for datetimes before the unix epoch or after the last FSUID millisecond
+    pub fn min_at(datetime: DateTime<Utc>) -> Result<Self, FsdkUidError> {
+        Ok(FsdkUid::from_parts(fsdkuid_timestamp_delta_at(datetime)?, 0, 0))
+    }
+
+    // Greatest FSUID any node can generate at the millisecond of the datetime, the inclusive upper bound of time range queries on FSUID columns
+    pub fn max_at(datetime: DateTime<Utc>) -> Result<Self, FsdkUidError> {
+        Ok(FsdkUid::from_parts(fsdkuid_timestamp_delta_at(datetime)?, FSDK_FSUID_MAX_NODE_IDENTIFIER, FSDK_FSUID_MAX_NODE_COUNTER))
+    }
+}
+
+fn fsdkuid_timestamp_delta_at(datetime: DateTime<Utc>) -> Result<i64, FsdkUidError> {
+    match datetime.timestamp_millis() {
+        timestamp_delta if (0..=FSDK_FSUID_MAX_TIMESTAMP_DELTA as i64).contains(&timestamp_delta) => Ok(timestamp_delta),
+        _ => Err(FsdkUidError::Overflow),
+    }
+}
+
+// MongoDB filter in canonical Extended JSON matching the FSUID's of the field generated in [start, end), the FSUID's being stored as Int64
+// (ex: Document::try_from(serde_json::from_str::<Value>(&filter)?) or the mongosh find() argument)
+pub fn fsdkuid_mongodb_time_range_filter(field: &str, start: DateTime<Utc>, end: DateTime<Utc>) -> Result<String, FsdkUidError> {
+    let (lower_bound, upper_bound) = (FsdkUid::min_at(start)?, FsdkUid::min_at(end)?);
+    let field = field.replace('\\', "\\\\").replace('"', "\\\"");
+
+    Ok(format!(
+        "{{\"{}\":{{\"$gte\":{{\"$numberLong\":\"{}\"}},\"$lt\":{{\"$numberLong\":\"{}\"}}}}}}",
+        field,
+        lower_bound.i64(),
+        upper_bound.i64()
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_fsdkuid_time_bounds() {
+        let datetime = Utc.timestamp_millis_opt(1726257270642).unwrap();
+        let fsuid = FsdkUid::new(113131996488794368);
+
+        assert_eq!(FsdkUid::min_at(datetime), Ok(FsdkUid::from_parts(1726257270642, 0, 0)), "[FsdkUid::min_at() Error] Lower bound must have the minimum node identifier and counter");
+        assert_eq!(FsdkUid::max_at(datetime), Ok(FsdkUid::from_parts(1726257270642, 255, 255)), "[FsdkUid::max_at() Error] Upper bound must have the maximum node identifier and counter");
+        assert!(FsdkUid::min_at(datetime).unwrap() <= fsuid && fsuid <= FsdkUid::max_at(datetime).unwrap());
+
+        assert_eq!(FsdkUid::min_at(Utc.timestamp_millis_opt(-1).unwrap()), Err(FsdkUidError::Overflow));
+        assert_eq!(FsdkUid::max_at(Utc.timestamp_millis_opt(1 << 48).unwrap()), Err(FsdkUidError::Overflow));
+    }
+
+    #[test]
+    fn test_fsdkuid_mongodb_time_range_filter() {
+        let start = Utc.with_ymd_and_hms(2024, 9, 13, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 9, 14, 0, 0, 0).unwrap();
+
+        assert_eq!(
+            fsdkuid_mongodb_time_range_filter("_id", start, end),
+            Ok(format!("{{\"_id\":{{\"$gte\":{{\"$numberLong\":\"{}\"}},\"$lt\":{{\"$numberLong\":\"{}\"}}}}}}", 1726185600000i64 << 16, 1726272000000i64 << 16)),
+            "[fsdkuid_mongodb_time_range_filter() Error] Filter must match the FSUID's generated within the day"
+        );
+        assert!(fsdkuid_mongodb_time_range_filter("a\"b", start, end).unwrap().starts_with("{\"a\\\"b\":"));
+    }
+}
@@ -0,0 +1,300 @@
+use std::io;
+use std::sync::{Condvar, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::redis::{RedisConnection, RedisValue};
+use crate::{FsdkUid, FsdkUidError, FsdkUidGenerator};
+
+const FSDK_FSUID_RATE_REDIS_TIMEOUT: Duration = Duration::from_millis(50); // Max time waiting for redis before falling back to the local burst allowance
+const FSDK_FSUID_RATE_RETRY_INTERVAL: Duration = Duration::from_secs(1); // Time waited after a redis failure before trying to reach it again
+
+// Atomic token bucket kept in a redis hash, refilled from the redis clock so every replica sees the same quota
+const FSDK_FSUID_RATE_REDIS_SCRIPT: &str = r"
+local capacity = tonumber(ARGV[1])
+local refill_per_second = tonumber(ARGV[2])
+local requested = tonumber(ARGV[3])
+local time = redis.call('TIME')
+local now = tonumber(time[1]) * 1000 + math.floor(tonumber(time[2]) / 1000)
+local bucket = redis.call('HMGET', KEYS[1], 'tokens', 'updated')
+local tokens = tonumber(bucket[1]) or capacity
+local updated = tonumber(bucket[2]) or now
+tokens = math.min(capacity, tokens + math.max(0, now - updated) * refill_per_second / 1000)
+local granted = math.min(requested, math.floor(tokens))
+redis.call('HSET', KEYS[1], 'tokens', tostring(tokens - granted), 'updated', tostring(now))
+redis.call('PEXPIRE', KEYS[1], math.ceil(capacity / refill_per_second * 1000) + 1000)
+return granted
+";
+
+// Shared issuance quota, take() returns how many of the requested tokens were granted (0 when the quota is exhausted)
+pub trait FsdkUidTokenBucket: Send + Sync {
+    fn take(&self, tokens: u32) -> io::Result<u32>;
+}
+
+pub struct FsdkUidRedisTokenBucket {
+    address: String,
+    key: String,
+    capacity: u32,
+    refill_per_second: u32,
+    connection: Mutex<Option<RedisConnection>>,
+}
+
+impl FsdkUidRedisTokenBucket {
+    pub fn new(address: &str, key: &str, capacity: u32, refill_per_second: u32) -> Self {
+        if capacity == 0 || refill_per_second == 0 {
+            panic!("[ERROR in FsdkUidRedisTokenBucket.new()] Token bucket capacity and refill rate should be greater than 0!");
+        }
+
+        FsdkUidRedisTokenBucket {
+            address: address.to_string(),
+            key: key.to_string(),
+            capacity,
+            refill_per_second,
+            connection: Mutex::new(None),
+        }
+    }
+}
+
+impl FsdkUidTokenBucket for FsdkUidRedisTokenBucket {
+    fn take(&self, tokens: u32) -> io::Result<u32> {
+        let mut connection = self.connection.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        if connection.is_none() {
+            *connection = Some(RedisConnection::connect(&self.address, FSDK_FSUID_RATE_REDIS_TIMEOUT)?);
+        }
+
+        let reply = connection.as_mut().map(|connection| {
+            connection.command(&[
+                b"EVAL",
+                FSDK_FSUID_RATE_REDIS_SCRIPT.as_bytes(),
+                b"1",
+                self.key.as_bytes(),
+                self.capacity.to_string().as_bytes(),
+                self.refill_per_second.to_string().as_bytes(),
+                tokens.to_string().as_bytes(),
+            ])
+        });
+
+        match reply {
+            Some(Ok(RedisValue::Integer(granted))) => Ok(granted.clamp(0, tokens as i64) as u32),
+            Some(Ok(reply)) => {
+                *connection = None;
+                Err(io::Error::new(io::ErrorKind::InvalidData, format!("Unexpected redis token bucket reply: {:?}", reply)))
+            }
+            Some(Err(error)) => {
+                *connection = None;
+                Err(error)
+            }
+            None => Err(io::Error::new(io::ErrorKind::NotConnected, "Redis connection isn't available")),
+        }
+    }
+}
+
+struct FsdkUidRateState {
+    prefetched: u32, // Tokens already granted by the shared bucket and not used yet
+    local_tokens: f64, // Tokens of the local burst allowance, used while the shared bucket is unreachable
+    local_updated: Instant,
+    unavailable_until: Option<Instant>,
+    refilling: bool, // A thread is taking tokens from the shared bucket, without holding the state lock
+}
+
+// Generates FSUID's only while a shared (cross replica) quota allows it, prefetching tokens in batches to avoid a round trip per FSUID
+pub struct FsdkUidRateCoordinator<B: FsdkUidTokenBucket> {
+    generator: FsdkUidGenerator,
+    bucket: B,
+    batch: u32,
+    local_burst: u32,
+    state: Mutex<FsdkUidRateState>,
+    refilled: Condvar,
+}
+
+impl<B: FsdkUidTokenBucket> FsdkUidRateCoordinator<B> {
+    // local_burst is the number of FSUID's per second each replica may still generate while the shared bucket is unreachable (0 fails closed)
+    pub fn new(generator: FsdkUidGenerator, bucket: B, batch: u32, local_burst: u32) -> Self {
+        if batch == 0 {
+            panic!("[ERROR in FsdkUidRateCoordinator.new()] Token batch should be greater than 0!");
+        }
+
+        FsdkUidRateCoordinator {
+            generator,
+            bucket,
+            batch,
+            local_burst,
+            state: Mutex::new(FsdkUidRateState {
+                prefetched: 0,
+                local_tokens: local_burst as f64,
+                local_updated: Instant::now(),
+                unavailable_until: None,
+                refilling: false,
+            }),
+            refilled: Condvar::new(),
+        }
+    }
+
+    pub fn is_degraded(&self) -> bool {
+        let state = self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        state.unavailable_until.is_some_and(|until| Instant::now() < until)
+    }
+
+    pub fn generate_i64(&self) -> Result<i64, FsdkUidError> {
+        self.acquire()?;
+        Ok(self.generator.generate_i64())
+    }
+
+    pub fn generate_fsuid(&self) -> Result<FsdkUid, FsdkUidError> {
+        self.generate_i64().map(FsdkUid::new)
+    }
+
+    // A single thread refills from the shared bucket, the others wait for its batch, or use the local burst allowance while the shared bucket is unavailable
+    fn acquire(&self) -> Result<(), FsdkUidError> {
+        let mut state = self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        loop {
+            if state.prefetched > 0 {
+                state.prefetched -= 1;
+                return Ok(());
+            }
+
+            if state.refilling && state.unavailable_until.is_none() {
+                state = self.refilled.wait(state).unwrap_or_else(|poisoned| poisoned.into_inner());
+                continue;
+            }
+
+            if state.refilling || state.unavailable_until.is_some_and(|until| Instant::now() < until) {
+                break;
+            }
+
+            // The round trip can take up to the redis timeout, generating threads must not queue behind the state lock meanwhile
+            state.refilling = true;
+            drop(state);
+            let taken = self.bucket.take(self.batch);
+            state = self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+            state.refilling = false;
+            self.refilled.notify_all();
+
+            match taken {
+                Ok(0) => return Err(FsdkUidError::RateLimited),
+                Ok(granted) => {
+                    state.unavailable_until = None;
+                    state.prefetched += granted - 1;
+                    return Ok(());
+                }
+                Err(_) => {
+                    state.unavailable_until = Some(Instant::now() + FSDK_FSUID_RATE_RETRY_INTERVAL);
+                    break;
+                }
+            }
+        }
+
+        let now = Instant::now();
+        let elapsed = now.duration_since(state.local_updated).as_secs_f64();
+        state.local_tokens = (state.local_tokens + elapsed * self.local_burst as f64).min(self.local_burst as f64);
+        state.local_updated = now;
+
+        if state.local_tokens < 1.0 {
+            return Err(FsdkUidError::RateLimited);
+        }
+
+        state.local_tokens -= 1.0;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+    use std::sync::Arc;
+    use std::thread;
+
+    struct MemoryTokenBucket {
+        tokens: AtomicU32,
+        calls: AtomicU32,
+        available: AtomicBool,
+    }
+
+    impl FsdkUidTokenBucket for MemoryTokenBucket {
+        fn take(&self, tokens: u32) -> io::Result<u32> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+
+            if !self.available.load(Ordering::SeqCst) {
+                return Err(io::Error::new(io::ErrorKind::ConnectionRefused, "unavailable"));
+            }
+
+            let granted = tokens.min(self.tokens.load(Ordering::SeqCst));
+            self.tokens.fetch_sub(granted, Ordering::SeqCst);
+            Ok(granted)
+        }
+    }
+
+    // Grants every requested token once the test opens it, to hold a refill in flight
+    struct GatedTokenBucket {
+        open: Mutex<bool>,
+        opened: Condvar,
+        calls: AtomicU32,
+    }
+
+    impl FsdkUidTokenBucket for GatedTokenBucket {
+        fn take(&self, tokens: u32) -> io::Result<u32> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            let open = self.open.lock().unwrap();
+            drop(self.opened.wait_while(open, |open| !*open).unwrap());
+            Ok(tokens)
+        }
+    }
+
+    fn memory_bucket(tokens: u32, available: bool) -> MemoryTokenBucket {
+        MemoryTokenBucket { tokens: AtomicU32::new(tokens), calls: AtomicU32::new(0), available: AtomicBool::new(available) }
+    }
+
+    #[test]
+    fn test_fsdkuid_rate_coordinator_shared_quota() {
+        let coordinator = FsdkUidRateCoordinator::new(FsdkUidGenerator::new(1), memory_bucket(10, true), 4, 100);
+
+        for _ in 0..10 {
+            assert!(coordinator.generate_i64().is_ok(), "[FsdkUidRateCoordinator.generate_i64() Error] FSUID's within the shared quota must be generated");
+        }
+
+        assert_eq!(coordinator.generate_i64(), Err(FsdkUidError::RateLimited), "[FsdkUidRateCoordinator.generate_i64() Error] FSUID's over the shared quota must be refused");
+        assert_eq!(coordinator.bucket.calls.load(Ordering::SeqCst), 4, "[FsdkUidRateCoordinator.generate_i64() Error] Tokens must be taken from the shared bucket in batches");
+        assert!(!coordinator.is_degraded());
+    }
+
+    #[test]
+    fn test_fsdkuid_rate_coordinator_degrades_to_local_burst() {
+        let coordinator = FsdkUidRateCoordinator::new(FsdkUidGenerator::new(1), memory_bucket(0, false), 4, 3);
+
+        for _ in 0..3 {
+            assert!(coordinator.generate_i64().is_ok(), "[FsdkUidRateCoordinator.generate_i64() Error] FSUID's within the local burst must be generated while the shared bucket is unavailable");
+        }
+
+        assert_eq!(coordinator.generate_i64(), Err(FsdkUidError::RateLimited));
+        assert!(coordinator.is_degraded());
+        assert_eq!(coordinator.bucket.calls.load(Ordering::SeqCst), 1, "[FsdkUidRateCoordinator.generate_i64() Error] An unavailable shared bucket must not be retried before the retry interval");
+    }
+
+    #[test]
+    fn test_fsdkuid_rate_coordinator_refills_without_holding_the_state_lock() {
+        let bucket = GatedTokenBucket { open: Mutex::new(false), opened: Condvar::new(), calls: AtomicU32::new(0) };
+        let coordinator = Arc::new(FsdkUidRateCoordinator::new(FsdkUidGenerator::new(1), bucket, 4, 0));
+        let generators: Vec<_> = (0..2)
+            .map(|_| {
+                let coordinator = coordinator.clone();
+                thread::spawn(move || coordinator.generate_i64())
+            })
+            .collect();
+
+        let deadline = Instant::now() + Duration::from_secs(10);
+        while coordinator.bucket.calls.load(Ordering::SeqCst) == 0 && Instant::now() < deadline {
+            thread::sleep(Duration::from_millis(1));
+        }
+        assert!(!coordinator.is_degraded(), "[FsdkUidRateCoordinator.generate_i64() Error] State must not be locked while tokens are taken from the shared bucket");
+
+        *coordinator.bucket.open.lock().unwrap() = true;
+        coordinator.bucket.opened.notify_all();
+
+        for generator in generators {
+            assert!(generator.join().unwrap().is_ok());
+        }
+        assert_eq!(coordinator.bucket.calls.load(Ordering::SeqCst), 1, "[FsdkUidRateCoordinator.generate_i64() Error] A single thread must refill from the shared bucket");
+    }
+}
@@ -0,0 +1,97 @@
+use std::fmt;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use crate::ring::fsdkuid_mix64;
+use crate::{FsdkUid, FSDK_FSUID_NODE_COUNTER_BITS, FSDK_FSUID_NODE_IDENTIFIER_BITS};
+
+const FSDK_FSUID_REDACTED_MASK: &str = "****"; // Text replacing the node identifier and node counter of masked FSUID's
+
+static FSDK_FSUID_DEFAULT_REDACTION: AtomicU8 = AtomicU8::new(FsdkUidRedaction::Hash as u8); // Crate-wide redaction used by FsdkUid.redacted()
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum FsdkUidRedaction {
+    #[default]
+    Hash, // Short stable unkeyed hash (ex: #1a2b3c4d), the same FSUID always gives the same text but neither its time nor its order can be read
+    MaskNodeAndCounter, // Base62 of the FSUID with node identifier and node counter cleared followed by ****, keeping the millisecond but not the order within it nor the node
+}
+
+impl FsdkUidRedaction {
+    // Sets the redaction used crate-wide by FsdkUid.redacted(), ex: at startup from the service configuration
+    pub fn set_default(redaction: FsdkUidRedaction) {
+        FSDK_FSUID_DEFAULT_REDACTION.store(redaction as u8, Ordering::Relaxed);
+    }
+
+    pub fn default_redaction() -> FsdkUidRedaction {
+        match FSDK_FSUID_DEFAULT_REDACTION.load(Ordering::Relaxed) {
+            value if value == FsdkUidRedaction::MaskNodeAndCounter as u8 => FsdkUidRedaction::MaskNodeAndCounter,
+            _ => FsdkUidRedaction::Hash,
+        }
+    }
+}
+
+// Log-safe display of a FSUID, rendered without allocating until formatted
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RedactedFsdkUid {
+    fsuid: FsdkUid,
+    redaction: FsdkUidRedaction,
+}
+
+impl FsdkUid {
+    // Log-safe display using the crate-wide redaction (see FsdkUidRedaction::set_default())
+    pub fn redacted(&self) -> RedactedFsdkUid {
+        self.redacted_with(FsdkUidRedaction::default_redaction())
+    }
+
+    pub fn redacted_with(&self, redaction: FsdkUidRedaction) -> RedactedFsdkUid {
+        RedactedFsdkUid { fsuid: *self, redaction }
+    }
+}
+
+impl fmt::Display for RedactedFsdkUid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.redaction {
+            FsdkUidRedaction::Hash => write!(f, "#{:08x}", fsdkuid_mix64(self.fsuid.i64() as u64) >> 32),
+            FsdkUidRedaction::MaskNodeAndCounter => {
+                let masked = self.fsuid.i64() >> (FSDK_FSUID_NODE_IDENTIFIER_BITS + FSDK_FSUID_NODE_COUNTER_BITS) << (FSDK_FSUID_NODE_IDENTIFIER_BITS + FSDK_FSUID_NODE_COUNTER_BITS);
+                write!(f, "{}{}", FsdkUid::new(masked).to_base62(), FSDK_FSUID_REDACTED_MASK)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fsdkuid_redacted_hash() {
+        let fsuid = FsdkUid::new(113131996488794368);
+        let redacted = fsuid.redacted_with(FsdkUidRedaction::Hash).to_string();
+
+        assert_eq!(redacted.len(), 9);
+        assert!(redacted.starts_with('#') && redacted[1..].bytes().all(|symbol| symbol.is_ascii_hexdigit()), "[fsuid.redacted_with() Error] Hash redaction must be # followed by 8 hexadecimal digits: {}", redacted);
+        assert_eq!(FsdkUid::new(113131996488794368).redacted_with(FsdkUidRedaction::Hash).to_string(), redacted, "[fsuid.redacted_with() Error] Hash redaction must be stable");
+        assert_ne!(FsdkUid::new(113131996488794369).redacted_with(FsdkUidRedaction::Hash).to_string(), redacted);
+    }
+
+    #[test]
+    fn test_fsdkuid_redacted_mask() {
+        let first = FsdkUid::from_parts(1726257270642, 1, 0).redacted_with(FsdkUidRedaction::MaskNodeAndCounter).to_string();
+        let second = FsdkUid::from_parts(1726257270642, 7, 42).redacted_with(FsdkUidRedaction::MaskNodeAndCounter).to_string();
+
+        assert_eq!(first, format!("{}****", FsdkUid::from_parts(1726257270642, 0, 0).to_base62()), "[fsuid.redacted_with() Error] Mask redaction must clear node identifier and node counter");
+        assert_eq!(first, second, "[fsuid.redacted_with() Error] FSUID's of the same millisecond must not be told apart");
+        assert_eq!(FsdkUid::new(-1).redacted_with(FsdkUidRedaction::MaskNodeAndCounter).to_string(), format!("{}****", FsdkUid::new(-65536).to_base62()));
+    }
+
+    #[test]
+    fn test_fsdkuid_redaction_default() {
+        let fsuid = FsdkUid::new(113131996488794368);
+        assert_eq!(fsuid.redacted(), fsuid.redacted_with(FsdkUidRedaction::Hash), "[fsuid.redacted() Error] Hash must be the default redaction");
+
+        FsdkUidRedaction::set_default(FsdkUidRedaction::MaskNodeAndCounter);
+        assert_eq!(fsuid.redacted(), fsuid.redacted_with(FsdkUidRedaction::MaskNodeAndCounter));
+        FsdkUidRedaction::set_default(FsdkUidRedaction::Hash);
+        assert_eq!(FsdkUidRedaction::default_redaction(), FsdkUidRedaction::Hash);
+    }
+}
@@ -0,0 +1,140 @@
+// Minimal RESP2 client, only implementing what the FSUID coordination features need, so this crate doesn't pull a redis client dependency
+
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::time::Duration;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum RedisValue {
+    Nil,
+    Status(String),
+    Integer(i64),
+    Bulk(Vec<u8>),
+    Array(Vec<RedisValue>),
+}
+
+pub(crate) struct RedisConnection {
+    reader: BufReader<TcpStream>,
+}
+
+impl RedisConnection {
+    pub(crate) fn connect(address: &str, timeout: Duration) -> io::Result<Self> {
+        let socket_address = address
+            .to_socket_addrs()?
+            .next()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, format!("Redis address {} doesn't resolve to any socket address", address)))?;
+
+        let stream = TcpStream::connect_timeout(&socket_address, timeout)?;
+        stream.set_read_timeout(Some(timeout))?;
+        stream.set_write_timeout(Some(timeout))?;
+        stream.set_nodelay(true)?;
+
+        Ok(RedisConnection { reader: BufReader::new(stream) })
+    }
+
+    pub(crate) fn command(&mut self, arguments: &[&[u8]]) -> io::Result<RedisValue> {
+        self.reader.get_mut().write_all(&fsdkuid_redis_encode_command(arguments))?;
+        fsdkuid_redis_read_value(&mut self.reader)
+    }
+}
+
+pub(crate) fn fsdkuid_redis_encode_command(arguments: &[&[u8]]) -> Vec<u8> {
+    let mut command = format!("*{}\r\n", arguments.len()).into_bytes();
+
+    for argument in arguments {
+        command.extend_from_slice(format!("${}\r\n", argument.len()).as_bytes());
+        command.extend_from_slice(argument);
+        command.extend_from_slice(b"\r\n");
+    }
+
+    command
+}
+
+// Reads a reply, redis error replies are returned as io::ErrorKind::Other with the error message
+pub(crate) fn fsdkuid_redis_read_value(reader: &mut impl BufRead) -> io::Result<RedisValue> {
+    let mut line = String::new();
+    if reader.read_line(&mut line)? == 0 {
+        return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "Redis closed the connection"));
+    }
+
+    let line = line.trim_end_matches("\r\n");
+    let invalid = || io::Error::new(io::ErrorKind::InvalidData, format!("Invalid redis reply: {}", line));
+    let (kind, content) = line.split_at_checked(1).ok_or_else(invalid)?;
+
+    match kind {
+        "+" => Ok(RedisValue::Status(content.to_string())),
+        "-" => Err(io::Error::other(format!("Redis replied with an error: {}", content))),
+        ":" => content.parse().map(RedisValue::Integer).map_err(|_| invalid()),
+        "$" => {
+            let length: i64 = content.parse().map_err(|_| invalid())?;
+            if length < 0 {
+                return Ok(RedisValue::Nil);
+            }
+
+            let mut bulk = vec![0u8; length as usize + 2];
+            reader.read_exact(&mut bulk)?;
+            bulk.truncate(length as usize);
+            Ok(RedisValue::Bulk(bulk))
+        }
+        "*" => {
+            let length: i64 = content.parse().map_err(|_| invalid())?;
+            if length < 0 {
+                return Ok(RedisValue::Nil);
+            }
+
+            (0..length).map(|_| fsdkuid_redis_read_value(reader)).collect::<io::Result<Vec<_>>>().map(RedisValue::Array)
+        }
+        _ => Err(invalid()),
+    }
+}
+
+// Serves the replies in order on a single connection, one per command, returning the arguments of the commands received
+#[cfg(test)]
+pub(crate) fn fsdkuid_redis_test_server(replies: Vec<&'static [u8]>) -> (String, std::thread::JoinHandle<Vec<Vec<Vec<u8>>>>) {
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap().to_string();
+
+    let handle = std::thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+        replies
+            .into_iter()
+            .map(|reply| {
+                let RedisValue::Array(arguments) = fsdkuid_redis_read_value(&mut reader).unwrap() else {
+                    panic!("[fsdkuid_redis_test_server() Error] Redis commands must be arrays");
+                };
+
+                stream.write_all(reply).unwrap();
+                arguments
+                    .into_iter()
+                    .map(|argument| match argument {
+                        RedisValue::Bulk(argument) => argument,
+                        argument => panic!("[fsdkuid_redis_test_server() Error] Redis command arguments must be bulk strings, not {:?}", argument),
+                    })
+                    .collect()
+            })
+            .collect()
+    });
+
+    (address, handle)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fsdkuid_redis_protocol() {
+        assert_eq!(fsdkuid_redis_encode_command(&[b"SET", b"key", b"value"]), b"*3\r\n$3\r\nSET\r\n$3\r\nkey\r\n$5\r\nvalue\r\n".to_vec());
+
+        let mut replies: &[u8] = b"+OK\r\n:42\r\n$5\r\nhello\r\n$-1\r\n*2\r\n:1\r\n$0\r\n\r\n-ERR wrong\r\n";
+        assert_eq!(fsdkuid_redis_read_value(&mut replies).unwrap(), RedisValue::Status("OK".to_string()));
+        assert_eq!(fsdkuid_redis_read_value(&mut replies).unwrap(), RedisValue::Integer(42));
+        assert_eq!(fsdkuid_redis_read_value(&mut replies).unwrap(), RedisValue::Bulk(b"hello".to_vec()));
+        assert_eq!(fsdkuid_redis_read_value(&mut replies).unwrap(), RedisValue::Nil);
+        assert_eq!(fsdkuid_redis_read_value(&mut replies).unwrap(), RedisValue::Array(vec![RedisValue::Integer(1), RedisValue::Bulk(Vec::new())]));
+        assert!(fsdkuid_redis_read_value(&mut replies).is_err(), "[fsdkuid_redis_read_value() Error] Error replies must be returned as errors");
+        assert_eq!(fsdkuid_redis_read_value(&mut replies).unwrap_err().kind(), io::ErrorKind::UnexpectedEof);
+    }
+}
@@ -0,0 +1,89 @@
+use std::collections::BTreeMap;
+
+use crate::{FsdkUid, FsdkUidLayout};
+
+// Names of the region (or tenant) codes kept in the datacenter sub-field of the node identifier of a layout (see FsdkUidLayout.with_datacenter_bits()),
+// so the region where an entity lives (ex: its data residency) is read from its FSUID alone
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FsdkUidRegionRegistry {
+    layout: FsdkUidLayout,
+    names: BTreeMap<u8, String>,
+}
+
+impl FsdkUidRegionRegistry {
+    pub fn new(layout: FsdkUidLayout) -> Self {
+        if layout.datacenter_bits() == 0 {
+            panic!("[ERROR in FsdkUidRegionRegistry.new()] FSUID Layout should reserve datacenter bits for the region code, but it doesn't split its node identifier!");
+        }
+
+        FsdkUidRegionRegistry { layout, names: BTreeMap::new() }
+    }
+
+    pub fn with_region(mut self, code: u8, name: &str) -> Self {
+        if code > self.layout.max_datacenter_id() || name.is_empty() || self.names.contains_key(&code) || self.code(name).is_some() {
+            panic!("[ERROR in FsdkUidRegionRegistry.with_region()] Region code should be at most {} and region code and name should be unique, but {} ({:?}) was specified!", self.layout.max_datacenter_id(), code, name);
+        }
+
+        self.names.insert(code, name.to_string());
+        self
+    }
+
+    pub fn layout(&self) -> FsdkUidLayout {
+        self.layout
+    }
+
+    pub fn name(&self, code: u8) -> Option<&str> {
+        self.names.get(&code).map(String::as_str)
+    }
+
+    pub fn code(&self, name: &str) -> Option<u8> {
+        self.names.iter().find(|(_, registered)| registered.as_str() == name).map(|(code, _)| *code)
+    }
+
+    // Region name of the FSUID, None when its region code isn't registered
+    pub fn region(&self, fsuid: &FsdkUid) -> Option<&str> {
+        self.name(self.layout.datacenter_id(fsuid))
+    }
+
+    // Node identifier of the worker of the region, to create its generator, None when the region isn't registered
+    pub fn node_identifier_for(&self, name: &str, worker_id: u8) -> Option<u8> {
+        self.code(name).map(|code| self.layout.node_identifier_for(code, worker_id))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (u8, &str)> {
+        self.names.iter().map(|(code, name)| (*code, name.as_str()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FsdkUidGenerator;
+
+    #[test]
+    fn test_fsdkuid_region_registry() {
+        let registry = FsdkUidRegionRegistry::new(FsdkUidLayout::DATACENTER_WORKER).with_region(0, "eu-west-1").with_region(1, "us-east-1").with_region(5, "ap-southeast-2");
+
+        let generator = FsdkUidGenerator::with_layout(registry.node_identifier_for("us-east-1", 9).unwrap(), registry.layout());
+        let fsuid = generator.generate_fsuid();
+        assert_eq!(registry.region(&fsuid), Some("us-east-1"), "[FsdkUidRegionRegistry.region() Error] Region must be read from the FSUID");
+        assert_eq!(fsuid.worker_id(), 9);
+
+        assert_eq!(registry.code("ap-southeast-2"), Some(5));
+        assert_eq!(registry.node_identifier_for("sa-east-1", 0), None);
+        assert_eq!(registry.region(&FsdkUidLayout::DATACENTER_WORKER.from_parts(1, FsdkUidLayout::DATACENTER_WORKER.node_identifier_for(7, 0), 0)), None, "[FsdkUidRegionRegistry.region() Error] Unregistered region codes must not have a name");
+        assert_eq!(registry.iter().map(|(code, _)| code).collect::<Vec<u8>>(), vec![0, 1, 5]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_fsdkuid_region_registry_rejects_duplicate_name() {
+        FsdkUidRegionRegistry::new(FsdkUidLayout::DATACENTER_WORKER).with_region(0, "eu-west-1").with_region(1, "eu-west-1");
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_fsdkuid_region_registry_rejects_layout_without_region_bits() {
+        FsdkUidRegionRegistry::new(FsdkUidLayout::FSUID);
+    }
+}
@@ -0,0 +1,112 @@
+use std::sync::{Mutex, RwLock};
+
+use crate::FsdkUidError;
+
+static FSDK_FSUID_NODE_REGISTRY: FsdkUidNodeRegistry = FsdkUidNodeRegistry::new(); // Live generators of the process per node identifier
+static FSDK_FSUID_DUPLICATE_POLICY: RwLock<FsdkUidDuplicatePolicy> = RwLock::new(FsdkUidDuplicatePolicy::Allow); // Crate-wide policy applied when a generator reuses a node identifier
+
+// What happens when a FsdkUidGenerator is created with the node identifier of another live generator of the process, which makes both generate the same FSUID's
+#[derive(Clone, Copy, Debug, Default)]
+pub enum FsdkUidDuplicatePolicy {
+    #[default]
+    Allow, // Generators may share node identifiers (ex: generators replacing each other)
+    Warn(fn(u8)), // Calls the hook with the duplicated node identifier, ex: to log a warning
+    Panic, // Panics in the generator constructor (FsdkUidGenerator::try_with_layout() fails with FsdkUidError::DuplicateNodeIdentifier), ex: in tests
+}
+
+impl FsdkUidDuplicatePolicy {
+    // Sets the policy applied crate-wide to the generators created afterwards, ex: at startup or at the beginning of a test suite
+    pub fn set_default(policy: FsdkUidDuplicatePolicy) {
+        *FSDK_FSUID_DUPLICATE_POLICY.write().unwrap_or_else(|poisoned| poisoned.into_inner()) = policy;
+    }
+
+    pub fn default_policy() -> FsdkUidDuplicatePolicy {
+        *FSDK_FSUID_DUPLICATE_POLICY.read().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+struct FsdkUidNodeRegistry {
+    generators: Mutex<[u32; 256]>,
+}
+
+impl FsdkUidNodeRegistry {
+    const fn new() -> Self {
+        FsdkUidNodeRegistry { generators: Mutex::new([0; 256]) }
+    }
+
+    // A generator refused by the panic policy is never constructed, so it isn't counted (its Drop wouldn't unregister it). The hook is called without the lock held
+    fn register(&self, node_identifier: u8, policy: FsdkUidDuplicatePolicy) -> Result<(), FsdkUidError> {
+        let duplicated = {
+            let mut generators = self.generators.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+            let duplicated = generators[node_identifier as usize] > 0;
+
+            if duplicated && matches!(policy, FsdkUidDuplicatePolicy::Panic) {
+                return Err(FsdkUidError::DuplicateNodeIdentifier);
+            }
+
+            generators[node_identifier as usize] += 1;
+            duplicated
+        };
+
+        if let (true, FsdkUidDuplicatePolicy::Warn(hook)) = (duplicated, policy) {
+            hook(node_identifier);
+        }
+
+        Ok(())
+    }
+
+    fn unregister(&self, node_identifier: u8) {
+        let mut generators = self.generators.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        generators[node_identifier as usize] = generators[node_identifier as usize].saturating_sub(1);
+    }
+}
+
+// Fails when the node identifier is used by another live generator and the policy is FsdkUidDuplicatePolicy::Panic, the caller decides whether to panic
+pub(crate) fn fsdkuid_register_generator(node_identifier: u8) -> Result<(), FsdkUidError> {
+    FSDK_FSUID_NODE_REGISTRY.register(node_identifier, FsdkUidDuplicatePolicy::default_policy())
+}
+
+pub(crate) fn fsdkuid_unregister_generator(node_identifier: u8) {
+    FSDK_FSUID_NODE_REGISTRY.unregister(node_identifier);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    static DUPLICATES: AtomicU32 = AtomicU32::new(0);
+
+    #[test]
+    fn test_fsdkuid_node_registry_duplicates() {
+        let registry = FsdkUidNodeRegistry::new();
+        let warn = FsdkUidDuplicatePolicy::Warn(|node_identifier| {
+            assert_eq!(node_identifier, 7);
+            DUPLICATES.fetch_add(1, Ordering::SeqCst);
+        });
+
+        registry.register(7, warn).unwrap();
+        registry.register(8, warn).unwrap();
+        assert_eq!(DUPLICATES.load(Ordering::SeqCst), 0, "[FsdkUidNodeRegistry Error] Distinct node identifiers must not be reported");
+
+        registry.register(7, warn).unwrap();
+        assert_eq!(DUPLICATES.load(Ordering::SeqCst), 1, "[FsdkUidNodeRegistry Error] Duplicated node identifier must call the hook");
+
+        registry.unregister(7);
+        registry.unregister(7);
+        registry.register(7, FsdkUidDuplicatePolicy::Panic).unwrap();
+        assert_eq!(registry.register(7, FsdkUidDuplicatePolicy::Panic), Err(FsdkUidError::DuplicateNodeIdentifier), "[FsdkUidNodeRegistry Error] Duplicated node identifier must be refused with the panic policy");
+        assert_eq!(registry.generators.lock().unwrap()[7], 1, "[FsdkUidNodeRegistry Error] Refused generator must not be counted");
+    }
+
+    #[test]
+    fn test_fsdkuid_node_registry_reuse_after_refused_duplicate() {
+        let registry = FsdkUidNodeRegistry::new();
+
+        registry.register(9, FsdkUidDuplicatePolicy::Panic).unwrap();
+        assert_eq!(registry.register(9, FsdkUidDuplicatePolicy::Panic), Err(FsdkUidError::DuplicateNodeIdentifier));
+
+        registry.unregister(9);
+        assert_eq!(registry.register(9, FsdkUidDuplicatePolicy::Panic), Ok(()), "[FsdkUidNodeRegistry Error] Node identifier must be registered again once the live generator is dropped");
+    }
+}
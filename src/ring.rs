@@ -0,0 +1,103 @@
+use std::collections::BTreeMap;
+
+use crate::FsdkUid;
+
+// Stable 64 bit mixer (splitmix64 finalizer), used instead of std hashers since ring placement must be identical across processes and rust versions
+pub(crate) fn fsdkuid_mix64(value: u64) -> u64 {
+    let mut mixed = value.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    mixed = (mixed ^ (mixed >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    mixed = (mixed ^ (mixed >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    mixed ^ (mixed >> 31)
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct FsdkUidHashRing {
+    points: BTreeMap<u64, u8>,
+}
+
+impl FsdkUidHashRing {
+    pub fn new() -> Self {
+        FsdkUidHashRing { points: BTreeMap::new() }
+    }
+
+    // Places the virtual nodes of a FSUID Node on the ring, a greater number of virtual nodes gives the node a greater share of the ring
+    pub fn add_node(&mut self, node_identifier: u8, virtual_nodes: u16) {
+        for virtual_node in 0..virtual_nodes {
+            let point = fsdkuid_mix64(((node_identifier as u64) << 16) | virtual_node as u64);
+            self.points.entry(point).or_insert(node_identifier);
+        }
+    }
+
+    pub fn remove_node(&mut self, node_identifier: u8) {
+        self.points.retain(|_, owner| *owner != node_identifier);
+    }
+
+    pub fn contains_node(&self, node_identifier: u8) -> bool {
+        self.points.values().any(|&owner| owner == node_identifier)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    // Returns the node identifier owning the FSUID, which is the first virtual node found clockwise from the FSUID position on the ring
+    pub fn owner(&self, fsuid: &FsdkUid) -> Option<u8> {
+        let position = fsdkuid_mix64(fsuid.i64() as u64);
+
+        self.points
+            .range(position..)
+            .next()
+            .or_else(|| self.points.iter().next())
+            .map(|(_, &owner)| owner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fsdkuid_hash_ring_owner() {
+        let mut ring = FsdkUidHashRing::new();
+        assert_eq!(ring.owner(&FsdkUid::new(113131996488794368)), None, "[ring.owner() Error] An empty ring must not have owners");
+
+        ring.add_node(1, 64);
+        ring.add_node(2, 64);
+        ring.add_node(3, 64);
+
+        let mut owners = [0usize; 4];
+        for value in 0..3000 {
+            let fsuid = FsdkUid::new(113131996488794368 + (value << 16));
+            let owner = ring.owner(&fsuid).unwrap();
+            assert_eq!(ring.owner(&fsuid), Some(owner), "[ring.owner() Error] The same FSUID must always be owned by the same node");
+            owners[owner as usize] += 1;
+        }
+
+        for (node_identifier, owned) in owners.iter().enumerate().skip(1) {
+            assert!(*owned > 500, "[ring.owner() Error] Node {} owns only {} of 3000 FSUID's", node_identifier, owned);
+        }
+    }
+
+    #[test]
+    fn test_fsdkuid_hash_ring_remove_node_only_moves_its_fsuids() {
+        let mut ring = FsdkUidHashRing::new();
+        ring.add_node(1, 64);
+        ring.add_node(2, 64);
+        ring.add_node(3, 64);
+
+        let fsuids: Vec<FsdkUid> = (0..1000).map(|value| FsdkUid::new(value << 16)).collect();
+        let before: Vec<u8> = fsuids.iter().map(|fsuid| ring.owner(fsuid).unwrap()).collect();
+
+        ring.remove_node(2);
+        assert!(!ring.contains_node(2));
+
+        for (fsuid, owner) in fsuids.iter().zip(before) {
+            let new_owner = ring.owner(fsuid).unwrap();
+            if owner != 2 {
+                assert_eq!(new_owner, owner, "[ring.remove_node() Error] FSUID's not owned by the removed node must keep their owner");
+            } else {
+                assert_ne!(new_owner, 2, "[ring.remove_node() Error] FSUID's owned by the removed node must move to another node");
+            }
+        }
+    }
+}
@@ -0,0 +1,26 @@
+// JSON Schemas of the FSUID wire representations without depending on schemars, to paste in OpenAPI documents or return from JsonSchema::json_schema()
+
+pub const FSDK_FSUID_JSON_SCHEMA_INTEGER: &str = "{\"type\":\"integer\",\"format\":\"int64\",\"description\":\"FSUID\",\"examples\":[113131996488794368]}"; // FSUID's sent as JSON numbers, which JavaScript clients can't read exactly
+pub const FSDK_FSUID_JSON_SCHEMA_DECIMAL: &str = "{\"type\":\"string\",\"pattern\":\"^(0|-?[1-9][0-9]{0,18})$\",\"description\":\"FSUID (decimal)\",\"examples\":[\"113131996488794368\"]}"; // FSUID's sent as decimal strings
+pub const FSDK_FSUID_JSON_SCHEMA_BASE62: &str = "{\"type\":\"string\",\"pattern\":\"^(0|[1-9A-Za-z][0-9A-Za-z]{0,10})$\",\"minLength\":1,\"maxLength\":11,\"description\":\"FSUID (Base62)\",\"examples\":[\"8M92EuiIHw\"]}"; // FSUID's sent in their Base62 format
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FsdkUid;
+
+    fn example(schema: &str) -> &str {
+        let (_, examples) = schema.split_once("\"examples\":[").unwrap();
+        examples.trim_end_matches("]}").trim_matches('"')
+    }
+
+    #[test]
+    fn test_fsdkuid_json_schema_examples() {
+        let fsuid = FsdkUid::new(113131996488794368);
+
+        assert_eq!(example(FSDK_FSUID_JSON_SCHEMA_INTEGER).parse(), Ok(fsuid), "[FSDK_FSUID_JSON_SCHEMA_INTEGER Error] Example must be a valid FSUID");
+        assert_eq!(example(FSDK_FSUID_JSON_SCHEMA_DECIMAL).parse(), Ok(fsuid), "[FSDK_FSUID_JSON_SCHEMA_DECIMAL Error] Example must be a valid FSUID");
+        assert_eq!(FsdkUid::from_base62(example(FSDK_FSUID_JSON_SCHEMA_BASE62)), Ok(fsuid), "[FSDK_FSUID_JSON_SCHEMA_BASE62 Error] Example must be a valid FSUID");
+        assert_eq!(FsdkUid::new(-1).to_base62().len(), 11);
+    }
+}
@@ -0,0 +1,71 @@
+// Redis sorted set conversions without depending on redis-rs. Sorted set scores are doubles, exact only up to 2^53, which default layout FSUID's exceed:
+// scoring by generation millisecond with the Sortable member keeps the full FSUID order, since members of equal score are ordered by their bytes
+
+use crate::{Encoding, FsdkUid, FsdkUidError, Sortable, FSDK_FSUID_JS_MAX_SAFE_INTEGER};
+
+impl FsdkUid {
+    // Generation millisecond, always exact as a double, for ZRANGE BYSCORE queries by time
+    pub fn to_redis_score(&self) -> f64 {
+        self.timestamp_delta() as f64
+    }
+
+    // Sortable format, ordering the members of the same millisecond like their FSUID's (ex: ZADD key fsuid.to_redis_score() fsuid.to_redis_member())
+    pub fn to_redis_member(&self) -> String {
+        Sortable::encode(self)
+    }
+
+    pub fn from_redis_member(text: &str) -> Result<Self, FsdkUidError> {
+        Sortable::decode(text)
+    }
+
+    // The whole FSUID as score, only for FSUID's exactly represented by a double (see fits_js_number(), ex: JS_SAFE layout), others fail with FsdkUidError::Overflow
+    pub fn to_redis_exact_score(&self) -> Result<f64, FsdkUidError> {
+        match self.fits_js_number() {
+            true => Ok(self.i64() as f64),
+            false => Err(FsdkUidError::Overflow),
+        }
+    }
+
+    // Rejects scores with a fractional part (NonCanonical) or outside of the exactly represented integers (Overflow)
+    pub fn from_redis_exact_score(score: f64) -> Result<Self, FsdkUidError> {
+        if !score.is_finite() || score.abs() > FSDK_FSUID_JS_MAX_SAFE_INTEGER as f64 {
+            return Err(FsdkUidError::Overflow);
+        }
+
+        if score.fract() != 0.0 {
+            return Err(FsdkUidError::NonCanonical);
+        }
+
+        Ok(FsdkUid::new(score as i64))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FsdkUidLayout;
+
+    #[test]
+    fn test_fsdkuid_redis_score_and_member() {
+        let fsuids = [FsdkUid::from_parts(1726257270642, 9, 0), FsdkUid::from_parts(1726257270642, 9, 1), FsdkUid::from_parts(1726257270643, 0, 0)];
+
+        assert_eq!(fsuids[0].to_redis_score(), 1726257270642.0, "[fsuid.to_redis_score() Error] Score must be the generation millisecond");
+        for pair in fsuids.windows(2) {
+            let (first, second) = ((pair[0].to_redis_score(), pair[0].to_redis_member()), (pair[1].to_redis_score(), pair[1].to_redis_member()));
+            assert!(first.0 < second.0 || (first.0 == second.0 && first.1 < second.1), "[fsuid.to_redis_member() Error] Sorted set order must be the FSUID order: {:?} {:?}", first, second);
+        }
+
+        assert_eq!(FsdkUid::from_redis_member(&fsuids[1].to_redis_member()), Ok(fsuids[1]));
+    }
+
+    #[test]
+    fn test_fsdkuid_redis_exact_score() {
+        let fsuid = FsdkUidLayout::JS_SAFE.from_parts(FsdkUidLayout::JS_SAFE.max_timestamp_ticks(), 63, 63);
+
+        assert_eq!(FsdkUid::from_redis_exact_score(fsuid.to_redis_exact_score().unwrap()), Ok(fsuid), "[FsdkUid::from_redis_exact_score() Error] JS_SAFE FSUID must round-trip as score");
+        assert_eq!(FsdkUid::new(113131996488794368).to_redis_exact_score(), Err(FsdkUidError::Overflow), "[fsuid.to_redis_exact_score() Error] Default layout FSUID's aren't exact doubles");
+        assert_eq!(FsdkUid::from_redis_exact_score(1.5), Err(FsdkUidError::NonCanonical));
+        assert_eq!(FsdkUid::from_redis_exact_score(f64::INFINITY), Err(FsdkUidError::Overflow));
+        assert_eq!(FsdkUid::from_redis_exact_score(1e17), Err(FsdkUidError::Overflow));
+    }
+}
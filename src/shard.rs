@@ -0,0 +1,69 @@
+use crate::ring::fsdkuid_mix64;
+use crate::FsdkUid;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum FsdkUidShardStrategy {
+    #[default]
+    Hash, // Stable hash of the whole FSUID, spreading FSUID's evenly whatever the node and time they were generated
+    NodeIdentifier, // FSUID Node Identifier modulo the number of shards, keeping FSUID's of a node together (ex: writes of a node to a single partition)
+    NodeCounter, // FSUID Node Counter modulo the number of shards, spreading the FSUID's of a burst of a node over the shards
+}
+
+impl FsdkUid {
+    // Stable shard index in 0..shards, identical across processes and rust versions (ex: Kafka partition or sharded table index)
+    pub fn shard_for(&self, shards: u32) -> u32 {
+        self.shard_for_with(shards, FsdkUidShardStrategy::Hash)
+    }
+
+    pub fn shard_for_with(&self, shards: u32, strategy: FsdkUidShardStrategy) -> u32 {
+        if shards == 0 {
+            panic!("[ERROR in FsdkUid.shard_for_with()] Number of shards should be at least 1, but 0 was specified!");
+        }
+
+        match strategy {
+            // Multiply-shift instead of modulo, so the hash range is split in equal parts for any number of shards
+            FsdkUidShardStrategy::Hash => ((fsdkuid_mix64(self.i64() as u64) as u128 * shards as u128) >> 64) as u32,
+            FsdkUidShardStrategy::NodeIdentifier => self.node_identifier() as u32 % shards,
+            FsdkUidShardStrategy::NodeCounter => self.node_counter() as u32 % shards,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fsdkuid_shard_for_hash() {
+        let mut shards = [0usize; 12];
+
+        for value in 0..12000 {
+            let fsuid = FsdkUid::from_parts(1726257270642 + value / 256, 1, (value % 256) as u8);
+            let shard = fsuid.shard_for(12);
+
+            assert_eq!(fsuid.shard_for_with(12, FsdkUidShardStrategy::Hash), shard, "[fsuid.shard_for() Error] The same FSUID must always be routed to the same shard");
+            shards[shard as usize] += 1;
+        }
+
+        for (shard, routed) in shards.iter().enumerate() {
+            assert!(*routed > 800, "[fsuid.shard_for() Error] Shard {} only received {} of 12000 FSUID's", shard, routed);
+        }
+
+        assert_eq!(FsdkUid::new(113131996488794368).shard_for(1), 0);
+    }
+
+    #[test]
+    fn test_fsdkuid_shard_for_node_bits() {
+        let fsuid = FsdkUid::from_parts(1726257270642, 13, 7);
+
+        assert_eq!(fsuid.shard_for_with(4, FsdkUidShardStrategy::NodeIdentifier), 1, "[fsuid.shard_for_with() Error] Node identifier strategy must route by node identifier");
+        assert_eq!(fsuid.shard_for_with(4, FsdkUidShardStrategy::NodeCounter), 3);
+        assert_eq!(FsdkUid::from_parts(1726257270643, 13, 0).shard_for_with(4, FsdkUidShardStrategy::NodeIdentifier), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_fsdkuid_shard_for_rejects_zero_shards() {
+        FsdkUid::new(113131996488794368).shard_for(0);
+    }
+}
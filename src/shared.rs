@@ -0,0 +1,118 @@
+use std::fs::{File, OpenOptions};
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::path::Path;
+use std::time::Duration;
+
+use crate::{FsdkUid, FsdkUidLayout};
+
+const FSDK_FSUID_SHARED_STATE_LENGTH: usize = 9; // Last timestamp ticks (u64 little-endian) followed by the last node counter of the state file
+
+// Generator whose last timestamp tick and node counter live in a state file locked on every FSUID, so several processes of the same host share one node identifier
+// without generating the same FSUID's (ex: one process per match of a game server). Placing the file in a memory filesystem (ex: /dev/shm) avoids disk writes.
+// The state isn't a memory mapping updated with atomic operations, since the standard library can't map files without a dependency (ex: libc or memmap2):
+// each FSUID costs a lock, a read and a write system call, about a microsecond on a memory filesystem, instead of a single atomic operation
+pub struct FsdkUidSharedGenerator {
+    file: File,
+    node_identifier: u8,
+    layout: FsdkUidLayout,
+}
+
+impl FsdkUidSharedGenerator {
+    // Every process sharing the state file must use the same node identifier and layout
+    pub fn open(path: impl AsRef<Path>, node_identifier: u8, layout: FsdkUidLayout) -> io::Result<Self> {
+        if node_identifier > layout.max_node_identifier() {
+            panic!("[ERROR in FsdkUidSharedGenerator.open()] FSUID Node Identifier should be between 0 and {}, but a greater value was specified!", layout.max_node_identifier());
+        }
+
+        let file = OpenOptions::new().read(true).write(true).create(true).truncate(false).open(path)?;
+
+        Ok(FsdkUidSharedGenerator { file, node_identifier, layout })
+    }
+
+    pub fn layout(&self) -> FsdkUidLayout {
+        self.layout
+    }
+
+    pub fn generate_i64(&self) -> io::Result<i64> {
+        self.file.lock()?;
+        let generated = self.next();
+        let unlocked = self.file.unlock();
+
+        let fsuid = generated?;
+        unlocked?;
+        Ok(fsuid)
+    }
+
+    pub fn generate_fsuid(&self) -> io::Result<FsdkUid> {
+        self.generate_i64().map(FsdkUid::new)
+    }
+
+    // Must be called while holding the lock. The timestamp never goes back, a clock moving backwards keeps the last timestamp tick until its node counter is exhausted
+    fn next(&self) -> io::Result<i64> {
+        let mut file = &self.file;
+        let mut state = [0u8; FSDK_FSUID_SHARED_STATE_LENGTH];
+
+        file.seek(SeekFrom::Start(0))?;
+        let last = match file.read_exact(&mut state) {
+            Ok(()) => Some((u64::from_le_bytes(state[..8].try_into().unwrap()), state[8])),
+            Err(error) if error.kind() == io::ErrorKind::UnexpectedEof => None,
+            Err(error) => return Err(error),
+        };
+
+        let mut timestamp_ticks = self.layout.current_timestamp_ticks();
+        let node_counter = match last {
+            Some((last_ticks, last_counter)) if timestamp_ticks <= last_ticks && last_counter < self.layout.max_node_counter() => {
+                timestamp_ticks = last_ticks;
+                last_counter + 1
+            }
+            Some((last_ticks, _)) if timestamp_ticks <= last_ticks => {
+                while timestamp_ticks <= last_ticks {
+                    std::thread::sleep(Duration::from_millis(self.layout.resolution_milliseconds()));
+                    timestamp_ticks = self.layout.current_timestamp_ticks();
+                }
+                0
+            }
+            _ => 0,
+        };
+
+        state[..8].copy_from_slice(&timestamp_ticks.to_le_bytes());
+        state[8] = node_counter;
+        file.seek(SeekFrom::Start(0))?;
+        file.write_all(&state)?;
+
+        Ok(self.layout.compose(timestamp_ticks, self.node_identifier, node_counter))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+    use std::thread;
+
+    #[test]
+    fn test_fsdkuid_shared_generator_unique_across_handles() {
+        let path = std::env::temp_dir().join(format!("fsdk_fsuid_shared_{}", std::process::id()));
+        let layout = FsdkUidLayout::JS_SAFE;
+
+        // Each handle opens the file separately, so they lock it like separate processes would
+        let workers: Vec<thread::JoinHandle<Vec<i64>>> = (0..4)
+            .map(|_| {
+                let generator = FsdkUidSharedGenerator::open(&path, 3, layout).unwrap();
+                thread::spawn(move || (0..200).map(|_| generator.generate_i64().unwrap()).collect())
+            })
+            .collect();
+
+        let mut fsuids = HashSet::new();
+        for worker in workers {
+            let generated = worker.join().unwrap();
+            assert!(generated.windows(2).all(|pair| pair[0] < pair[1]), "[FsdkUidSharedGenerator.generate_i64() Error] FSUID's of a handle must be increasing");
+            fsuids.extend(generated.iter().map(|fsuid| FsdkUid::new(*fsuid)));
+        }
+
+        assert_eq!(fsuids.len(), 800, "[FsdkUidSharedGenerator.generate_i64() Error] Handles sharing the state file must never generate the same FSUID");
+        assert!(fsuids.iter().all(|fsuid| layout.node_identifier(fsuid) == 3));
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
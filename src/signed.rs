@@ -0,0 +1,100 @@
+use std::fmt;
+
+use crate::encoding::{fsdkuid_decode_base64url_bytes, fsdkuid_encode_base64url_bytes};
+use crate::hmac::{fsdkuid_constant_time_eq, fsdkuid_hmac_sha256};
+use crate::{FsdkUid, FsdkUidError};
+
+const FSDK_FSUID_SIGNED_CONTEXT: &[u8] = b"fsdk_uid/signed/v1"; // Domain separation prefix hashed with every signed FSUID, so its signature can't be reused by cursors or other FSUID texts
+const FSDK_FSUID_SIGNED_SEPARATOR: char = '.'; // Character between the Base62 FSUID and its signature
+const FSDK_FSUID_SIGNATURE_BYTES: usize = 12; // Number of HMAC-SHA-256 bytes kept as signature (96 bits), rendered as 16 base64url characters
+
+// FSUID rendered with a truncated HMAC-SHA-256 as <base62>.<signature> (ex: 8M92EuiIHw followed by a dot and 16 base64url characters), so public endpoints can reject forged FSUID's before hitting the database
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SignedFsdkUid {
+    fsuid: FsdkUid,
+    signature: [u8; FSDK_FSUID_SIGNATURE_BYTES],
+}
+
+impl SignedFsdkUid {
+    pub fn sign(fsuid: FsdkUid, key: &[u8]) -> Self {
+        SignedFsdkUid { fsuid, signature: SignedFsdkUid::signature(fsuid, key) }
+    }
+
+    // Parses a signed FSUID, rejecting texts whose signature wasn't made with the given key
+    pub fn parse(text: &str, key: &[u8]) -> Result<Self, FsdkUidError> {
+        let (base62, signature) = text.split_once(FSDK_FSUID_SIGNED_SEPARATOR).ok_or(FsdkUidError::SignatureMismatch)?;
+        let fsuid = FsdkUid::from_base62(base62)?;
+
+        let signature = fsdkuid_decode_base64url_bytes(signature).map_err(|error| match error {
+            FsdkUidError::InvalidCharacter { character, position } => FsdkUidError::InvalidCharacter { character, position: position + base62.len() + 1 },
+            FsdkUidError::Empty => FsdkUidError::SignatureMismatch,
+            error => error,
+        })?;
+
+        if signature.len() != FSDK_FSUID_SIGNATURE_BYTES {
+            return Err(FsdkUidError::InvalidLength);
+        }
+
+        let signed = SignedFsdkUid::sign(fsuid, key);
+        if !fsdkuid_constant_time_eq(&signature, &signed.signature) {
+            return Err(FsdkUidError::SignatureMismatch);
+        }
+
+        Ok(signed)
+    }
+
+    pub fn fsuid(&self) -> FsdkUid {
+        self.fsuid
+    }
+
+    fn signature(fsuid: FsdkUid, key: &[u8]) -> [u8; FSDK_FSUID_SIGNATURE_BYTES] {
+        let tag = fsdkuid_hmac_sha256(key, &[FSDK_FSUID_SIGNED_CONTEXT, &fsuid.to_be_bytes()]);
+
+        let mut signature = [0u8; FSDK_FSUID_SIGNATURE_BYTES];
+        signature.copy_from_slice(&tag[..FSDK_FSUID_SIGNATURE_BYTES]);
+        signature
+    }
+}
+
+impl fmt::Display for SignedFsdkUid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}{}", self.fsuid.to_base62(), FSDK_FSUID_SIGNED_SEPARATOR, fsdkuid_encode_base64url_bytes(&self.signature))
+    }
+}
+
+impl From<SignedFsdkUid> for FsdkUid {
+    fn from(signed: SignedFsdkUid) -> Self {
+        signed.fsuid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: &[u8] = b"fsdk_uid signed test key";
+
+    #[test]
+    fn test_fsdkuid_signed() {
+        let fsuid = FsdkUid::new(113131996488794368);
+        let text = SignedFsdkUid::sign(fsuid, KEY).to_string();
+
+        assert!(text.starts_with("8M92EuiIHw."), "[SignedFsdkUid Display Error] Signed FSUID {} must start with the Base62 FSUID", text);
+        assert_eq!(text.len(), "8M92EuiIHw.".len() + 16);
+        assert_eq!(SignedFsdkUid::parse(&text, KEY).map(FsdkUid::from), Ok(fsuid), "[SignedFsdkUid::parse() Error] Signed FSUID must be verified with the signing key");
+        assert_ne!(SignedFsdkUid::sign(FsdkUid::new(113131996488794369), KEY).to_string()[11..], text[11..]);
+    }
+
+    #[test]
+    fn test_fsdkuid_signed_rejects_forgeries() {
+        let text = SignedFsdkUid::sign(FsdkUid::new(113131996488794368), KEY).to_string();
+        let signature = &text[11..];
+
+        assert_eq!(SignedFsdkUid::parse(&text, b"another key"), Err(FsdkUidError::SignatureMismatch), "[SignedFsdkUid::parse() Error] Signatures of another key must be rejected");
+        assert_eq!(SignedFsdkUid::parse(&format!("8M92EuiIHx.{}", signature), KEY), Err(FsdkUidError::SignatureMismatch), "[SignedFsdkUid::parse() Error] Signatures of another FSUID must be rejected");
+        assert_eq!(SignedFsdkUid::parse("8M92EuiIHw", KEY), Err(FsdkUidError::SignatureMismatch));
+        assert_eq!(SignedFsdkUid::parse("8M92EuiIHw.", KEY), Err(FsdkUidError::SignatureMismatch));
+        assert_eq!(SignedFsdkUid::parse(&format!("8M92EuiIHw.{}", &signature[..12]), KEY), Err(FsdkUidError::InvalidLength));
+        assert_eq!(SignedFsdkUid::parse("8M92EuiIHw.q1Vg3E*w7mVjL2tO", KEY), Err(FsdkUidError::InvalidCharacter { character: '*', position: 17 }));
+    }
+}
@@ -0,0 +1,94 @@
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+
+use crate::{FsdkUidError, FsdkUidGenerator, FsdkUidLayout};
+
+const FSDK_FSUID_SNAPSHOT_LENGTH: usize = 10; // Node identifier, last timestamp ticks (u64 little-endian) and node counter of a serialized snapshot
+
+// State of a generator persisted on shutdown, restoring it never generates FSUID's of a timestamp tick already used before the snapshot
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FsdkUidGeneratorSnapshot {
+    node_identifier: u8,
+    last_timestamp_ticks: u64,
+    counter: u8,
+}
+
+impl FsdkUidGeneratorSnapshot {
+    pub fn new(node_identifier: u8, last_timestamp_ticks: u64, counter: u8) -> Self {
+        FsdkUidGeneratorSnapshot { node_identifier, last_timestamp_ticks, counter }
+    }
+
+    pub fn node_identifier(&self) -> u8 {
+        self.node_identifier
+    }
+
+    // Timestamp ticks of the layout of the snapshotted generator
+    pub fn last_timestamp_ticks(&self) -> u64 {
+        self.last_timestamp_ticks
+    }
+
+    pub fn counter(&self) -> u8 {
+        self.counter
+    }
+
+    pub fn to_bytes(&self) -> [u8; FSDK_FSUID_SNAPSHOT_LENGTH] {
+        let mut bytes = [0u8; FSDK_FSUID_SNAPSHOT_LENGTH];
+        bytes[0] = self.node_identifier;
+        bytes[1..9].copy_from_slice(&self.last_timestamp_ticks.to_le_bytes());
+        bytes[9] = self.counter;
+        bytes
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, FsdkUidError> {
+        if bytes.len() != FSDK_FSUID_SNAPSHOT_LENGTH {
+            return Err(FsdkUidError::InvalidLength);
+        }
+
+        Ok(FsdkUidGeneratorSnapshot::new(bytes[0], u64::from_le_bytes(bytes[1..9].try_into().unwrap()), bytes[9]))
+    }
+}
+
+impl FsdkUidGenerator {
+    pub fn snapshot(&self) -> FsdkUidGeneratorSnapshot {
+        FsdkUidGeneratorSnapshot::new(self.node_identifier, self.last_timestamp_ticks.load(Ordering::Relaxed), self.counter.load(Ordering::SeqCst))
+    }
+
+    // Waits until the clock passes the last timestamp tick of the snapshot (usually already the case after a restart), so the restored generator can't
+    // generate a FSUID of the snapshot. The layout must be the one of the snapshotted generator
+    pub fn restore(snapshot: FsdkUidGeneratorSnapshot, layout: FsdkUidLayout) -> Self {
+        let generator = FsdkUidGenerator::with_layout(snapshot.node_identifier, layout);
+
+        while layout.current_timestamp_ticks() <= snapshot.last_timestamp_ticks {
+            std::thread::sleep(Duration::from_millis(layout.resolution_milliseconds()));
+        }
+
+        generator.counter.store(snapshot.counter, Ordering::SeqCst);
+        generator.last_timestamp_ticks.store(snapshot.last_timestamp_ticks, Ordering::Relaxed);
+        generator
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FsdkUid;
+
+    #[test]
+    fn test_fsdkuid_generator_snapshot_restore() {
+        let layout = FsdkUidLayout::JS_SAFE;
+        let generator = FsdkUidGenerator::with_layout(4, layout);
+        let last = (0..10).map(|_| generator.generate_fsuid()).last().unwrap();
+
+        let snapshot = generator.snapshot();
+        assert_eq!(snapshot, FsdkUidGeneratorSnapshot::new(4, layout.timestamp_ticks(&last), 10), "[FsdkUidGenerator.snapshot() Error] Snapshot must hold the node identifier, last timestamp tick and counter");
+        assert_eq!(FsdkUidGeneratorSnapshot::from_bytes(&snapshot.to_bytes()), Ok(snapshot));
+        assert_eq!(FsdkUidGeneratorSnapshot::from_bytes(&[0; 9]), Err(FsdkUidError::InvalidLength));
+        drop(generator);
+
+        let restored = FsdkUidGenerator::restore(snapshot, layout);
+        let fsuid: FsdkUid = restored.generate_fsuid();
+        assert!(layout.timestamp_ticks(&fsuid) > snapshot.last_timestamp_ticks(), "[FsdkUidGenerator::restore() Error] Restored generator must only generate FSUID's of later timestamp ticks");
+        assert_eq!(layout.node_identifier(&fsuid), 4);
+        assert_eq!(restored.snapshot().counter(), 11);
+    }
+}
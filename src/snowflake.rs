@@ -0,0 +1,158 @@
+use chrono::{DateTime, Utc};
+
+use crate::{FsdkUid, FsdkUidError};
+
+// Bit allocation of a Snowflake-style 64 bits identifier, from the most significant bits: milliseconds since the epoch, node bits and sequence bits
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ForeignLayout {
+    pub epoch_milliseconds: i64,
+    pub timestamp_bits: u8,
+    pub node_bits: u8,
+    pub sequence_bits: u8,
+}
+
+// Fields of a foreign identifier decoded with its layout
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ForeignId {
+    pub timestamp_milliseconds: i64, // Milliseconds since the unix epoch
+    pub node: u64,
+    pub sequence: u64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SnowflakeLayout {
+    Twitter, // 41 bits of milliseconds since 2010-11-04T01:42:54.657Z, 5 datacenter bits, 5 worker bits and 12 sequence bits
+    Discord, // 42 bits of milliseconds since 2015-01-01T00:00:00Z, 5 worker bits, 5 process bits and 12 increment bits
+}
+
+impl ForeignLayout {
+    pub const TWITTER: ForeignLayout = ForeignLayout { epoch_milliseconds: 1288834974657, timestamp_bits: 41, node_bits: 10, sequence_bits: 12 };
+
+    pub const DISCORD: ForeignLayout = ForeignLayout { epoch_milliseconds: 1420070400000, timestamp_bits: 42, node_bits: 10, sequence_bits: 12 };
+
+    pub fn new(epoch_milliseconds: i64, timestamp_bits: u8, node_bits: u8, sequence_bits: u8) -> Self {
+        if timestamp_bits == 0 || timestamp_bits as u32 + node_bits as u32 + sequence_bits as u32 > 64 {
+            panic!("[ERROR in ForeignLayout.new()] Foreign identifier fields should use between 1 and 64 bits in total with at least 1 timestamp bit, but {}, {} and {} bits were specified!", timestamp_bits, node_bits, sequence_bits);
+        }
+
+        ForeignLayout { epoch_milliseconds, timestamp_bits, node_bits, sequence_bits }
+    }
+
+    // Explains the identifier, reading each field with the layout bit widths
+    pub fn decode(&self, id: u64) -> ForeignId {
+        let node_shift = self.sequence_bits as u32;
+        let timestamp_shift = node_shift + self.node_bits as u32;
+
+        ForeignId {
+            timestamp_milliseconds: self.epoch_milliseconds.saturating_add((id.checked_shr(timestamp_shift).unwrap_or_default() & fsdkuid_foreign_mask(self.timestamp_bits)) as i64),
+            node: id.checked_shr(node_shift).unwrap_or_default() & fsdkuid_foreign_mask(self.node_bits),
+            sequence: id & fsdkuid_foreign_mask(self.sequence_bits),
+        }
+    }
+}
+
+fn fsdkuid_foreign_mask(bits: u8) -> u64 {
+    u64::MAX.checked_shr(64 - bits as u32).unwrap_or_default()
+}
+
+impl ForeignId {
+    pub fn utc_datetime(&self) -> Option<DateTime<Utc>> {
+        DateTime::<Utc>::from_timestamp_millis(self.timestamp_milliseconds)
+    }
+}
+
+impl SnowflakeLayout {
+    pub fn epoch_milliseconds(&self) -> i64 {
+        self.foreign_layout().epoch_milliseconds
+    }
+
+    pub fn foreign_layout(&self) -> ForeignLayout {
+        match self {
+            SnowflakeLayout::Twitter => ForeignLayout::TWITTER,
+            SnowflakeLayout::Discord => ForeignLayout::DISCORD,
+        }
+    }
+}
+
+impl FsdkUid {
+    // Remaps the snowflake fields into the FSUID fields: milliseconds since its epoch to timestamp delta, worker bits to node identifier and sequence to node counter.
+    // The translation is deterministic and collision-free, snowflakes whose worker bits or sequence don't fit in 8 bits fail with FsdkUidError::Overflow
+    pub fn from_snowflake(snowflake: u64, layout: SnowflakeLayout) -> Result<Self, FsdkUidError> {
+        FsdkUid::from_foreign(snowflake, &layout.foreign_layout())
+    }
+
+    // Same as FsdkUid::from_snowflake() for any Snowflake-style identifier, also failing with FsdkUidError::Overflow for timestamps outside of the FSUID range
+    pub fn from_foreign(id: u64, layout: &ForeignLayout) -> Result<Self, FsdkUidError> {
+        let foreign_id = layout.decode(id);
+
+        let node_identifier = u8::try_from(foreign_id.node).map_err(|_| FsdkUidError::Overflow)?;
+        let node_counter = u8::try_from(foreign_id.sequence).map_err(|_| FsdkUidError::Overflow)?;
+
+        if !(0..=(1 << 48) - 1).contains(&foreign_id.timestamp_milliseconds) {
+            return Err(FsdkUidError::Overflow);
+        }
+
+        Ok(FsdkUid::from_parts(foreign_id.timestamp_milliseconds, node_identifier, node_counter))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    #[test]
+    fn test_fsdkuid_from_discord_snowflake() {
+        // Snowflake of the Discord API documentation: 2016-04-30T11:18:25.796Z, worker 1, process 0, increment 7
+        let fsuid = FsdkUid::from_snowflake(175928847299117063, SnowflakeLayout::Discord).unwrap();
+
+        assert_eq!(fsuid.utc_datetime(), Utc.timestamp_millis_opt(1462015105796).unwrap(), "[FsdkUid::from_snowflake() Error] Snowflake timestamp must be moved to the unix epoch");
+        assert_eq!(fsuid.node_identifier(), 1 << 5);
+        assert_eq!(fsuid.node_counter(), 7);
+    }
+
+    #[test]
+    fn test_fsdkuid_from_twitter_snowflake() {
+        let snowflake = (1000u64 << 22) | (3 << 17) | (4 << 12) | 42;
+        let fsuid = FsdkUid::from_snowflake(snowflake, SnowflakeLayout::Twitter).unwrap();
+
+        assert_eq!(fsuid.timestamp_delta(), 1288834975657);
+        assert_eq!(fsuid.node_identifier(), (3 << 5) | 4, "[FsdkUid::from_snowflake() Error] Datacenter and worker bits must be kept in the node identifier");
+        assert_eq!(fsuid.node_counter(), 42);
+    }
+
+    #[test]
+    fn test_fsdkuid_foreign_layout_decode() {
+        // Sonyflake-like vendor scheme: 39 bits of milliseconds since 2014-09-01, 16 node bits and 8 sequence bits
+        let layout = ForeignLayout::new(1409529600000, 39, 16, 8);
+        let id = (1000u64 << 24) | (0xbeef << 8) | 42;
+
+        let foreign_id = layout.decode(id);
+        assert_eq!(foreign_id, ForeignId { timestamp_milliseconds: 1409529601000, node: 0xbeef, sequence: 42 }, "[ForeignLayout.decode() Error] Foreign identifier fields must be read with the layout bit widths");
+        assert_eq!(foreign_id.utc_datetime(), Some(Utc.timestamp_millis_opt(1409529601000).unwrap()));
+        assert_eq!(FsdkUid::from_foreign(id, &layout), Err(FsdkUidError::Overflow), "[FsdkUid::from_foreign() Error] Nodes greater than 8 bits must be rejected");
+
+        let fsuid = FsdkUid::from_foreign((1000u64 << 24) | (7 << 8) | 42, &layout).unwrap();
+        assert_eq!((fsuid.timestamp_delta(), fsuid.node_identifier(), fsuid.node_counter()), (1409529601000, 7, 42));
+
+        assert_eq!(ForeignLayout::new(0, 64, 0, 0).decode(u64::MAX).timestamp_milliseconds, -1);
+        assert_eq!(ForeignLayout::new(0, 48, 0, 16).decode(113131996488794368), ForeignId { timestamp_milliseconds: 1726257270642, node: 0, sequence: 256 });
+        assert_eq!(SnowflakeLayout::Discord.foreign_layout(), ForeignLayout::DISCORD);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_fsdkuid_foreign_layout_rejects_too_many_bits() {
+        ForeignLayout::new(0, 42, 12, 12);
+    }
+
+    #[test]
+    fn test_fsdkuid_from_snowflake_preserves_order_and_rejects_overflow() {
+        let snowflakes = [175928847299117063u64, 175928847299117064, 175928847299121159, 175928847303311360];
+        let fsuids: Vec<FsdkUid> = snowflakes.iter().map(|&snowflake| FsdkUid::from_snowflake(snowflake, SnowflakeLayout::Discord).unwrap()).collect();
+        assert!(fsuids.windows(2).all(|pair| pair[0] < pair[1]), "[FsdkUid::from_snowflake() Error] FSUID's must keep the snowflakes order");
+
+        assert_eq!(FsdkUid::from_snowflake((1000 << 22) | 256, SnowflakeLayout::Discord), Err(FsdkUidError::Overflow), "[FsdkUid::from_snowflake() Error] Sequences greater than 255 must be rejected");
+        assert_eq!(FsdkUid::from_snowflake((1000 << 22) | (256 << 12), SnowflakeLayout::Twitter), Err(FsdkUidError::Overflow));
+    }
+}
@@ -0,0 +1,88 @@
+use crate::{FsdkUid, FsdkUidError, FSDK_FSUID_MAX_TIMESTAMP_DELTA};
+
+const FSDK_FSUID_STREAM_ID_SEPARATOR: char = '-'; // Character between the milliseconds and the sequence of a Redis stream entry ID
+
+impl FsdkUid {
+    // Redis stream entry ID <milliseconds>-<sequence> with the FSUID timestamp delta as milliseconds and the node identifier and node counter as 16 bits sequence,
+    // so FSUID's can be used as XADD IDs and entries keep the FSUID order
+    pub fn to_stream_id(&self) -> String {
+        let sequence = ((self.node_identifier() as u16) << 8) | self.node_counter() as u16;
+        format!("{}{}{}", self.timestamp_delta(), FSDK_FSUID_STREAM_ID_SEPARATOR, sequence)
+    }
+
+    // Parses a Redis stream entry ID, only IDs whose milliseconds fit in 48 bits and sequence in 16 bits convert back, others fail with FsdkUidError::Overflow
+    pub fn from_stream_id(text: &str) -> Result<Self, FsdkUidError> {
+        if text.is_empty() {
+            return Err(FsdkUidError::Empty);
+        }
+
+        let (milliseconds, sequence) = text.split_once(FSDK_FSUID_STREAM_ID_SEPARATOR).ok_or(FsdkUidError::InvalidLength)?;
+
+        let timestamp_delta = fsdkuid_parse_stream_number(milliseconds, 0)?;
+        let sequence = fsdkuid_parse_stream_number(sequence, milliseconds.len() + 1)?;
+
+        if timestamp_delta > FSDK_FSUID_MAX_TIMESTAMP_DELTA || sequence > u16::MAX as u64 {
+            return Err(FsdkUidError::Overflow);
+        }
+
+        Ok(FsdkUid::from_parts(timestamp_delta as i64, (sequence >> 8) as u8, sequence as u8))
+    }
+}
+
+// Parses an unsigned decimal number of a stream entry ID, rejecting leading zeros, offset being the position of the number in the stream entry ID
+fn fsdkuid_parse_stream_number(text: &str, offset: usize) -> Result<u64, FsdkUidError> {
+    if text.is_empty() {
+        return Err(FsdkUidError::Empty);
+    }
+
+    if text.len() > 1 && text.starts_with('0') {
+        return Err(FsdkUidError::NonCanonical);
+    }
+
+    let mut value: u64 = 0;
+
+    for (position, character) in text.chars().enumerate() {
+        let digit = character.to_digit(10).ok_or(FsdkUidError::InvalidCharacter { character, position: position + offset })?;
+        value = value
+            .checked_mul(10)
+            .and_then(|value| value.checked_add(digit as u64))
+            .ok_or(FsdkUidError::Overflow)?;
+    }
+
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fsdkuid_stream_id() {
+        let fsuid = FsdkUid::new(113131996488794368);
+
+        assert_eq!(fsuid.to_stream_id(), "1726257270642-256", "[fsuid.to_stream_id() Error] Stream ID must be the timestamp delta followed by the node fields as sequence");
+        assert_eq!(FsdkUid::from_stream_id("1726257270642-256"), Ok(fsuid));
+
+        for value in [0, 65535, i64::MAX, -1] {
+            assert_eq!(FsdkUid::from_stream_id(&FsdkUid::new(value).to_stream_id()), Ok(FsdkUid::new(value)), "[FsdkUid::from_stream_id() Error] FSUID {} didn't round-trip", value);
+        }
+
+        // Redis orders stream entries by milliseconds then sequence, numerically
+        let order = |text: String| text.split_once('-').map(|(milliseconds, sequence)| (milliseconds.parse::<u64>().unwrap(), sequence.parse::<u64>().unwrap())).unwrap();
+        let values = [65535, 65536, 113131996488794368, 113131996488794369, 113131996488794624, 113131996488859904];
+        for pair in values.windows(2) {
+            assert!(order(FsdkUid::new(pair[0]).to_stream_id()) < order(FsdkUid::new(pair[1]).to_stream_id()), "[fsuid.to_stream_id() Error] Stream IDs must keep the FSUID order");
+        }
+    }
+
+    #[test]
+    fn test_fsdkuid_stream_id_rejects_invalid_ids() {
+        assert_eq!(FsdkUid::from_stream_id(""), Err(FsdkUidError::Empty));
+        assert_eq!(FsdkUid::from_stream_id("1726257270642"), Err(FsdkUidError::InvalidLength));
+        assert_eq!(FsdkUid::from_stream_id("1726257270642-"), Err(FsdkUidError::Empty));
+        assert_eq!(FsdkUid::from_stream_id("1726257270642-065"), Err(FsdkUidError::NonCanonical));
+        assert_eq!(FsdkUid::from_stream_id("1726257270642-65536"), Err(FsdkUidError::Overflow), "[FsdkUid::from_stream_id() Error] Sequences greater than 16 bits must be rejected");
+        assert_eq!(FsdkUid::from_stream_id("281474976710656-0"), Err(FsdkUidError::Overflow));
+        assert_eq!(FsdkUid::from_stream_id("1726257270642-2x6"), Err(FsdkUidError::InvalidCharacter { character: 'x', position: 15 }));
+    }
+}
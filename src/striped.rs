@@ -0,0 +1,246 @@
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::{registry, FsdkUid, FsdkUidLayout};
+
+static FSDK_FSUID_NEXT_STRIPED_GENERATOR: AtomicUsize = AtomicUsize::new(0); // Identifier of the next striped generator, keying the stripes assigned to each thread
+
+thread_local! {
+    // Stripe assigned to the current thread by each striped generator it used, entries of dropped generators are never looked up again
+    static FSDK_FSUID_THREAD_STRIPES: RefCell<Vec<(usize, usize)>> = const { RefCell::new(Vec::new()) };
+}
+
+// Utilization of a stripe, ex: to spot threads hoarding a stripe while others are exhausted
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FsdkUidStripeMetrics {
+    pub threads: usize, // Threads currently assigned to the stripe (threads that exited stay counted until the stripe is reclaimed)
+    pub issued: u64, // FSUID's generated from the stripe
+    pub exhausted: u64, // Times a thread waited for the next timestamp tick since the stripe and no idle stripe had node counters left
+    pub reclaimed: u64, // Times the stripe was reclaimed by a thread of an exhausted stripe after its quiet period
+    pub idle: Option<Duration>, // Time since the last FSUID of the stripe, None if it never generated one
+}
+
+struct FsdkUidStripeState {
+    last: Option<(u64, u8)>, // Last timestamp tick and sequence
+    last_used: Option<Instant>,
+    threads: usize,
+    issued: u64,
+    exhausted: u64,
+    reclaimed: u64,
+}
+
+// State of a stripe, aligned to a cache line so threads of different stripes never share one
+#[repr(align(64))]
+struct FsdkUidStripe {
+    state: Mutex<FsdkUidStripeState>,
+}
+
+// Generator splitting the node counter between stripes, each thread generating from its own stripe without contending on a shared atomic.
+// A stripe owns the node counters equal to its index modulo the number of stripes, so FSUID's of every stripe are unique and ordered by timestamp tick
+pub struct FsdkUidStripedGenerator {
+    identifier: usize,
+    node_identifier: u8,
+    layout: FsdkUidLayout,
+    stripes: Vec<FsdkUidStripe>,
+    quiet_period: Option<Duration>,
+}
+
+impl FsdkUidStripedGenerator {
+    // Each stripe generates (max node counter + 1) / stripes FSUID's per timestamp tick, so stripes should be close to the number of generating threads
+    pub fn new(node_identifier: u8, layout: FsdkUidLayout, stripes: usize) -> Self {
+        if node_identifier > layout.max_node_identifier() {
+            panic!("[ERROR in FsdkUidStripedGenerator.new()] FSUID Node Identifier should be between 0 and {}, but a greater value was specified!", layout.max_node_identifier());
+        }
+
+        if !stripes.is_power_of_two() || stripes > layout.max_node_counter() as usize + 1 {
+            panic!("[ERROR in FsdkUidStripedGenerator.new()] Stripes should be a power of two between 1 and {}, but {} was specified!", layout.max_node_counter() as usize + 1, stripes);
+        }
+
+        registry::fsdkuid_register_generator(node_identifier)
+            .unwrap_or_else(|_| panic!("[ERROR in FsdkUidStripedGenerator.new()] FSUID Node Identifier {} is already used by another generator of this process!", node_identifier));
+
+        FsdkUidStripedGenerator {
+            identifier: FSDK_FSUID_NEXT_STRIPED_GENERATOR.fetch_add(1, Ordering::Relaxed),
+            node_identifier,
+            layout,
+            stripes: (0..stripes)
+                .map(|_| FsdkUidStripe { state: Mutex::new(FsdkUidStripeState { last: None, last_used: None, threads: 0, issued: 0, exhausted: 0, reclaimed: 0 }) })
+                .collect(),
+            quiet_period: None,
+        }
+    }
+
+    // A thread whose stripe is exhausted moves to a stripe that didn't generate for the quiet period (ex: assigned to an idle or exited thread) instead of waiting
+    // for the next timestamp tick. Within that tick the moved thread may get FSUID's lower than its previous one, they stay ordered by timestamp tick
+    pub fn with_reclaim_after(mut self, quiet_period: Duration) -> Self {
+        self.quiet_period = Some(quiet_period);
+        self
+    }
+
+    pub fn layout(&self) -> FsdkUidLayout {
+        self.layout
+    }
+
+    pub fn stripes(&self) -> usize {
+        self.stripes.len()
+    }
+
+    pub fn stripe_metrics(&self) -> Vec<FsdkUidStripeMetrics> {
+        self.stripes
+            .iter()
+            .map(|stripe| {
+                let state = stripe.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+                FsdkUidStripeMetrics { threads: state.threads, issued: state.issued, exhausted: state.exhausted, reclaimed: state.reclaimed, idle: state.last_used.map(|last_used| last_used.elapsed()) }
+            })
+            .collect()
+    }
+
+    pub fn generate_i64(&self) -> i64 {
+        let mut stripe_index = self.thread_stripe();
+        let max_sequence = ((self.layout.max_node_counter() as usize + 1) / self.stripes.len() - 1) as u8;
+
+        loop {
+            let mut state = self.stripes[stripe_index].state.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+
+            // The timestamp of a stripe never goes back, a clock moving backwards keeps the last timestamp tick until its sequence is exhausted
+            let mut timestamp_ticks = self.layout.current_timestamp_ticks();
+            let sequence = match state.last {
+                Some((last_ticks, last_sequence)) if timestamp_ticks <= last_ticks && last_sequence < max_sequence => {
+                    timestamp_ticks = last_ticks;
+                    last_sequence + 1
+                }
+                Some((last_ticks, _)) if timestamp_ticks <= last_ticks => {
+                    if let Some(reclaimed) = self.reclaim(stripe_index) {
+                        state.threads = state.threads.saturating_sub(1);
+                        drop(state);
+                        stripe_index = reclaimed;
+                        continue;
+                    }
+
+                    state.exhausted += 1;
+                    while timestamp_ticks <= last_ticks {
+                        std::thread::sleep(Duration::from_millis(self.layout.resolution_milliseconds()));
+                        timestamp_ticks = self.layout.current_timestamp_ticks();
+                    }
+                    0
+                }
+                _ => 0,
+            };
+
+            state.last = Some((timestamp_ticks, sequence));
+            state.last_used = Some(Instant::now());
+            state.issued += 1;
+            let node_counter = (sequence as usize * self.stripes.len() + stripe_index) as u8;
+
+            return self.layout.compose(timestamp_ticks, self.node_identifier, node_counter);
+        }
+    }
+
+    pub fn generate_fsuid(&self) -> FsdkUid {
+        FsdkUid::new(self.generate_i64())
+    }
+
+    // Stripe of the current thread, assigned on first use to the stripe with the fewest threads
+    fn thread_stripe(&self) -> usize {
+        FSDK_FSUID_THREAD_STRIPES.with(|assigned| {
+            if let Some((_, stripe_index)) = assigned.borrow().iter().find(|(generator, _)| *generator == self.identifier) {
+                return *stripe_index;
+            }
+
+            let stripe_index = (0..self.stripes.len())
+                .min_by_key(|&index| self.stripes[index].state.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).threads)
+                .unwrap_or(0);
+            self.stripes[stripe_index].state.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).threads += 1;
+            assigned.borrow_mut().push((self.identifier, stripe_index));
+            stripe_index
+        })
+    }
+
+    // Moves the current thread from its exhausted stripe to a stripe idle for the quiet period, called with the exhausted stripe locked so other stripes are only tried
+    fn reclaim(&self, exhausted_index: usize) -> Option<usize> {
+        let quiet_period = self.quiet_period?;
+
+        let reclaimed = (0..self.stripes.len()).filter(|&index| index != exhausted_index).find(|&index| match self.stripes[index].state.try_lock() {
+            Ok(mut state) if state.last_used.is_none_or(|last_used| last_used.elapsed() >= quiet_period) => {
+                state.threads += 1;
+                state.reclaimed += 1;
+                true
+            }
+            _ => false,
+        })?;
+
+        FSDK_FSUID_THREAD_STRIPES.with(|assigned| {
+            if let Some(entry) = assigned.borrow_mut().iter_mut().find(|(generator, _)| *generator == self.identifier) {
+                entry.1 = reclaimed;
+            }
+        });
+
+        Some(reclaimed)
+    }
+}
+
+impl Drop for FsdkUidStripedGenerator {
+    fn drop(&mut self) {
+        registry::fsdkuid_unregister_generator(self.node_identifier);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fsdkuid_get_current_unix_timestamp_milliseconds;
+    use std::collections::HashSet;
+    use std::sync::Arc;
+    use std::thread;
+
+    #[test]
+    fn test_fsdkuid_striped_generator_unique_across_threads() {
+        let generator = Arc::new(FsdkUidStripedGenerator::new(5, FsdkUidLayout::FSUID, 4));
+
+        let workers: Vec<thread::JoinHandle<Vec<i64>>> = (0..8)
+            .map(|_| {
+                let generator = generator.clone();
+                thread::spawn(move || (0..2000).map(|_| generator.generate_i64()).collect())
+            })
+            .collect();
+
+        let mut fsuids = HashSet::new();
+        for worker in workers {
+            let generated = worker.join().unwrap();
+            assert!(generated.windows(2).all(|pair| pair[0] < pair[1]), "[FsdkUidStripedGenerator.generate_i64() Error] FSUID's of a thread must be increasing");
+            fsuids.extend(generated);
+        }
+
+        assert_eq!(fsuids.len(), 16000, "[FsdkUidStripedGenerator.generate_i64() Error] Threads of different stripes must never generate the same FSUID");
+        assert!(fsuids.iter().all(|fsuid| FsdkUid::new(*fsuid).node_identifier() == 5));
+    }
+
+    #[test]
+    fn test_fsdkuid_striped_generator_reclaims_idle_stripes() {
+        // SHORT_CODE node fields with 1 day timestamp ticks, the current one starting 12 hours ago, so every FSUID of the test has the same tick
+        let resolution_milliseconds = 86_400_000;
+        let layout = FsdkUidLayout::new(fsdkuid_get_current_unix_timestamp_milliseconds() - resolution_milliseconds / 2, resolution_milliseconds, 32, 3, 5);
+        let generator = Arc::new(FsdkUidStripedGenerator::new(6, layout, 4).with_reclaim_after(Duration::ZERO));
+
+        // The spawned thread gets stripe 0, generates once and exits, so its stripe is idle
+        let idle = generator.clone();
+        thread::spawn(move || idle.generate_i64()).join().unwrap();
+
+        // The current thread gets stripe 1, exhausts its 8 node counters of the tick and reclaims stripe 0
+        let fsuids: Vec<FsdkUid> = (0..12).map(|_| generator.generate_fsuid()).collect();
+        assert_eq!(fsuids.iter().collect::<HashSet<_>>().len(), 12, "[FsdkUidStripedGenerator.generate_fsuid() Error] FSUID's of a reclaimed stripe must stay unique");
+
+        let metrics = generator.stripe_metrics();
+        assert_eq!((metrics[0].threads, metrics[0].issued, metrics[0].reclaimed), (2, 5, 1), "[FsdkUidStripedGenerator.stripe_metrics() Error] Idle stripe must be reclaimed by the exhausted thread");
+        assert_eq!((metrics[1].threads, metrics[1].issued, metrics[1].exhausted), (0, 8, 0), "[FsdkUidStripedGenerator.stripe_metrics() Error] Thread moving to a reclaimed stripe must leave its exhausted stripe");
+        assert!(metrics[0].idle.is_some() && metrics[2].idle.is_none(), "[FsdkUidStripedGenerator.stripe_metrics() Error] Stripes must only be idle since their last FSUID");
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_fsdkuid_striped_generator_rejects_invalid_stripes() {
+        FsdkUidStripedGenerator::new(5, FsdkUidLayout::FSUID, 3);
+    }
+}
@@ -0,0 +1,98 @@
+use crate::uuid::{fsdkuid_format_uuid, fsdkuid_parse_uuid, FSDK_FSUID_UUID_VARIANT};
+use crate::{FsdkUid, FsdkUidError};
+
+const FSDK_FSUID_TIMEUUID_VERSION: u128 = 1; // UUID version of time-UUID's (RFC 9562 UUIDv1, Cassandra/Scylla timeuuid)
+const FSDK_FSUID_TIMEUUID_GREGORIAN_OFFSET: u64 = 0x01B2_1DD2_1381_4000; // Number of 100 nanoseconds intervals between the gregorian epoch (1582-10-15) and the unix epoch
+const FSDK_FSUID_TIMEUUID_TICKS_PER_MILLISECOND: u64 = 10000; // Number of 100 nanoseconds intervals per millisecond
+const FSDK_FSUID_TIMEUUID_NODE_MULTICAST: u128 = 1 << 40; // Multicast bit of the node field, set since the node field isn't a MAC address (RFC 9562 section 6.10)
+
+impl FsdkUid {
+    // Time-UUID (as u128) whose 100 nanoseconds timestamp is the FSUID timestamp delta plus the node counter as sub-millisecond intervals,
+    // with the node identifier in the node field and clock sequence 0, so Cassandra orders rows keyed by either identifier by generation time.
+    // Fails with FsdkUidError::Overflow for FSUID's generated after the last time-UUID timestamp (5236-03-31)
+    pub fn to_timeuuid(&self) -> Result<u128, FsdkUidError> {
+        let ticks = self.timestamp_delta() as u64 * FSDK_FSUID_TIMEUUID_TICKS_PER_MILLISECOND + self.node_counter() as u64 + FSDK_FSUID_TIMEUUID_GREGORIAN_OFFSET;
+
+        if ticks >> 60 != 0 {
+            return Err(FsdkUidError::Overflow);
+        }
+
+        let ticks = ticks as u128;
+
+        Ok(((ticks & 0xffff_ffff) << 96)
+            | (((ticks >> 32) & 0xffff) << 80)
+            | (FSDK_FSUID_TIMEUUID_VERSION << 76)
+            | (((ticks >> 48) & 0xfff) << 64)
+            | (FSDK_FSUID_UUID_VARIANT << 62)
+            | FSDK_FSUID_TIMEUUID_NODE_MULTICAST
+            | self.node_identifier() as u128)
+    }
+
+    // Only time-UUID's converted from FSUID's convert back, other UUID versions fail with FsdkUidError::InvalidVersion and other time-UUID's with FsdkUidError::Overflow
+    pub fn try_from_timeuuid(timeuuid: u128) -> Result<Self, FsdkUidError> {
+        if (timeuuid >> 76) & 0xf != FSDK_FSUID_TIMEUUID_VERSION || (timeuuid >> 62) & 0b11 != FSDK_FSUID_UUID_VARIANT {
+            return Err(FsdkUidError::InvalidVersion);
+        }
+
+        let node_field = timeuuid & ((1 << 62) - 1);
+        if node_field & !0xff != FSDK_FSUID_TIMEUUID_NODE_MULTICAST {
+            return Err(FsdkUidError::Overflow);
+        }
+
+        let ticks = ((timeuuid >> 96) | (((timeuuid >> 80) & 0xffff) << 32) | (((timeuuid >> 64) & 0xfff) << 48)) as u64;
+        let ticks = ticks.checked_sub(FSDK_FSUID_TIMEUUID_GREGORIAN_OFFSET).ok_or(FsdkUidError::Overflow)?;
+        let node_counter = u8::try_from(ticks % FSDK_FSUID_TIMEUUID_TICKS_PER_MILLISECOND).map_err(|_| FsdkUidError::Overflow)?;
+        let timestamp_delta = (ticks / FSDK_FSUID_TIMEUUID_TICKS_PER_MILLISECOND) as i64;
+
+        if timestamp_delta > (1 << 48) - 1 {
+            return Err(FsdkUidError::Overflow);
+        }
+
+        Ok(FsdkUid::from_parts(timestamp_delta, node_field as u8, node_counter))
+    }
+
+    pub fn to_timeuuid_string(&self) -> Result<String, FsdkUidError> {
+        self.to_timeuuid().map(fsdkuid_format_uuid)
+    }
+
+    pub fn try_from_timeuuid_str(text: &str) -> Result<Self, FsdkUidError> {
+        FsdkUid::try_from_timeuuid(fsdkuid_parse_uuid(text)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fsdkuid_timeuuid() {
+        let fsuid = FsdkUid::new(113131996488794368);
+
+        assert_eq!(fsuid.to_timeuuid_string().unwrap(), "fd949520-7209-11ef-8000-010000000001", "[fsuid.to_timeuuid() Error] Time-UUID must embed the FSUID generation time as 100 nanoseconds intervals");
+        assert_eq!(FsdkUid::try_from_timeuuid(fsuid.to_timeuuid().unwrap()), Ok(fsuid));
+        assert_eq!(FsdkUid::try_from_timeuuid_str("FD949520-7209-11EF-8000-010000000001"), Ok(fsuid));
+
+        for value in [0, 65535, 113131996488794623, 6754982799650652159] {
+            assert_eq!(FsdkUid::try_from_timeuuid(FsdkUid::new(value).to_timeuuid().unwrap()), Ok(FsdkUid::new(value)), "[FsdkUid::try_from_timeuuid() Error] FSUID {} didn't round-trip", value);
+        }
+    }
+
+    #[test]
+    fn test_fsdkuid_timeuuid_orders_by_time() {
+        // Cassandra compares time-UUID's by their timestamp first
+        let timestamp = |timeuuid: u128| (timeuuid >> 96) | (((timeuuid >> 80) & 0xffff) << 32) | (((timeuuid >> 64) & 0xfff) << 48);
+
+        let values = [113131996488794368, 113131996488794369, 113131996488794623, 113131996488859904];
+        for pair in values.windows(2) {
+            assert!(timestamp(FsdkUid::new(pair[0]).to_timeuuid().unwrap()) < timestamp(FsdkUid::new(pair[1]).to_timeuuid().unwrap()), "[fsuid.to_timeuuid() Error] Time-UUID timestamps must keep the FSUID's order within the node");
+        }
+    }
+
+    #[test]
+    fn test_fsdkuid_timeuuid_rejects_foreign_timeuuids() {
+        assert_eq!(FsdkUid::new(i64::MAX).to_timeuuid(), Err(FsdkUidError::Overflow), "[fsuid.to_timeuuid() Error] FSUID's after the last time-UUID timestamp must be rejected");
+        assert_eq!(FsdkUid::try_from_timeuuid(FsdkUid::new(0).to_uuid()), Err(FsdkUidError::InvalidVersion), "[FsdkUid::try_from_timeuuid() Error] UUIDv7 must be rejected");
+        assert_eq!(FsdkUid::try_from_timeuuid_str("fd949520-7209-11ef-8000-9c5c8e1a2b3c"), Err(FsdkUidError::Overflow), "[FsdkUid::try_from_timeuuid() Error] Time-UUID's with a MAC address must be rejected");
+        assert_eq!(FsdkUid::try_from_timeuuid_str("fd949620-7209-11ef-8000-010000000001"), Err(FsdkUidError::Overflow));
+    }
+}
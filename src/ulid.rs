@@ -0,0 +1,87 @@
+use crate::encoding::FSDK_FSUID_CROCKFORD_BASE32_ALPHABET;
+use crate::{FsdkUid, FsdkUidError};
+
+const FSDK_FSUID_ULID_LENGTH: usize = 26; // Number of Crockford Base32 characters of a ULID (128 bits, the first character only holds 3 bits)
+
+impl FsdkUid {
+    // ULID with the FSUID timestamp delta as the 48 bits timestamp and the randomness filled deterministically:
+    // node identifier and node counter in its first 16 bits and zeros after, so ULID's sort in the same order as the FSUID's
+    pub fn to_ulid(&self) -> String {
+        let ulid = ((self.timestamp_delta() as u128) << 80) | ((self.node_identifier() as u128) << 72) | ((self.node_counter() as u128) << 64);
+
+        (0..FSDK_FSUID_ULID_LENGTH)
+            .rev()
+            .map(|index| FSDK_FSUID_CROCKFORD_BASE32_ALPHABET[((ulid >> (5 * index)) & 0x1f) as usize] as char)
+            .collect()
+    }
+
+    // Reads a ULID case-insensitively (I and L read as 1, O as 0), only ULID's converted from FSUID's convert back, others fail with FsdkUidError::Overflow
+    pub fn try_from_ulid(text: &str) -> Result<Self, FsdkUidError> {
+        if text.is_empty() {
+            return Err(FsdkUidError::Empty);
+        }
+
+        if text.len() != FSDK_FSUID_ULID_LENGTH {
+            return Err(FsdkUidError::InvalidLength);
+        }
+
+        let mut ulid: u128 = 0;
+
+        for (position, character) in text.chars().enumerate() {
+            let symbol = match character.to_ascii_uppercase() {
+                'I' | 'L' => '1',
+                'O' => '0',
+                symbol => symbol,
+            };
+
+            let digit = FSDK_FSUID_CROCKFORD_BASE32_ALPHABET
+                .iter()
+                .position(|&alphabet_symbol| alphabet_symbol as char == symbol)
+                .ok_or(FsdkUidError::InvalidCharacter { character, position })?;
+
+            if position == 0 && digit > 7 {
+                return Err(FsdkUidError::Overflow);
+            }
+
+            ulid = (ulid << 5) | digit as u128;
+        }
+
+        if ulid as u64 != 0 {
+            return Err(FsdkUidError::Overflow);
+        }
+
+        Ok(FsdkUid::from_parts((ulid >> 80) as i64, (ulid >> 72) as u8, (ulid >> 64) as u8))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fsdkuid_ulid() {
+        let fsuid = FsdkUid::new(113131996488794368);
+
+        assert_eq!(fsuid.to_ulid(), "01J7PF73VJ0400000000000000", "[fsuid.to_ulid() Error] ULID must embed the timestamp delta and the node fields");
+        assert_eq!(FsdkUid::try_from_ulid(&fsuid.to_ulid()), Ok(fsuid));
+        assert_eq!(FsdkUid::try_from_ulid(&fsuid.to_ulid().to_lowercase()), Ok(fsuid), "[FsdkUid::try_from_ulid() Error] ULID must be read case-insensitively");
+
+        for value in [0, 65535, i64::MAX, i64::MIN, -1] {
+            assert_eq!(FsdkUid::try_from_ulid(&FsdkUid::new(value).to_ulid()), Ok(FsdkUid::new(value)), "[FsdkUid::try_from_ulid() Error] FSUID {} didn't round-trip", value);
+        }
+
+        let values = [0, 255, 65535, 113131996488794368, 113131996488794369, i64::MAX];
+        for pair in values.windows(2) {
+            assert!(FsdkUid::new(pair[0]).to_ulid() < FsdkUid::new(pair[1]).to_ulid(), "[fsuid.to_ulid() Error] ULID's must sort in the same order as the FSUID's");
+        }
+    }
+
+    #[test]
+    fn test_fsdkuid_ulid_rejects_foreign_ulids() {
+        assert_eq!(FsdkUid::try_from_ulid("01ARZ3NDEKTSV4RRFFQ69G5FAV"), Err(FsdkUidError::Overflow), "[FsdkUid::try_from_ulid() Error] ULID's with random bits must be rejected");
+        assert_eq!(FsdkUid::try_from_ulid("81J7PF73VJ0G0000000000000"), Err(FsdkUidError::InvalidLength));
+        assert_eq!(FsdkUid::try_from_ulid("81J7PF73VJ0G00000000000000"), Err(FsdkUidError::Overflow));
+        assert_eq!(FsdkUid::try_from_ulid("01J7PF73VJ0G000000000000U0"), Err(FsdkUidError::InvalidCharacter { character: 'U', position: 24 }));
+        assert_eq!(FsdkUid::try_from_ulid(""), Err(FsdkUidError::Empty));
+    }
+}
@@ -0,0 +1,67 @@
+use crate::{Base58, Base62, Base64Url, CrockfordBase32, Encoding, FixedDecimal, FsdkUid, FsdkUidError, Sortable};
+
+const FSDK_FSUID_URI_SCHEME: &str = "fsuid:"; // Scheme of the FSUID URI (fsuid:<encoding>:<value>)
+const FSDK_FSUID_URN_PREFIX: &str = "urn:fsuid:"; // Prefix of the FSUID URN (urn:fsuid:<encoding>:<value>)
+
+impl FsdkUid {
+    // Canonical self-describing representation, fsuid:base62:<base62>
+    pub fn to_uri(&self) -> String {
+        self.to_uri_with::<Base62>()
+    }
+
+    pub fn to_uri_with<E: Encoding>(&self) -> String {
+        format!("{}{}:{}", FSDK_FSUID_URI_SCHEME, E::NAME, E::encode(self))
+    }
+
+    pub fn to_urn(&self) -> String {
+        format!("{}{}:{}", FSDK_FSUID_URN_PREFIX, Base62::NAME, Base62::encode(self))
+    }
+
+    // Parses fsuid:<encoding>:<value> and urn:fsuid:<encoding>:<value>, the scheme and encoding name being case-insensitive
+    pub fn from_uri(text: &str) -> Result<Self, FsdkUidError> {
+        let remaining = fsdkuid_strip_prefix_ignore_case(text, FSDK_FSUID_URN_PREFIX)
+            .or_else(|| fsdkuid_strip_prefix_ignore_case(text, FSDK_FSUID_URI_SCHEME))
+            .ok_or(FsdkUidError::InvalidUri)?;
+
+        let (encoding, value) = remaining.split_once(':').ok_or(FsdkUidError::InvalidUri)?;
+
+        match encoding.to_ascii_lowercase().as_str() {
+            Base62::NAME => Base62::decode(value),
+            Base58::NAME => Base58::decode(value),
+            CrockfordBase32::NAME => CrockfordBase32::decode(value),
+            Base64Url::NAME => Base64Url::decode(value),
+            Sortable::NAME => Sortable::decode(value),
+            FixedDecimal::NAME => FixedDecimal::decode(value),
+            _ => Err(FsdkUidError::UnknownEncoding),
+        }
+    }
+}
+
+fn fsdkuid_strip_prefix_ignore_case<'a>(text: &'a str, prefix: &str) -> Option<&'a str> {
+    let head = text.get(..prefix.len())?;
+    head.eq_ignore_ascii_case(prefix).then(|| &text[prefix.len()..])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fsdkuid_uri() {
+        let fsuid = FsdkUid::new(113131996488794368);
+        assert_eq!(fsuid.to_uri(), "fsuid:base62:8M92EuiIHw", "[fsuid.to_uri() Error] Canonical FSUID URI must use the Base62 format");
+        assert_eq!(fsuid.to_urn(), "urn:fsuid:base62:8M92EuiIHw");
+        assert_eq!(fsuid.to_uri_with::<CrockfordBase32>(), "fsuid:base32:34FCYE7Q4080");
+
+        assert_eq!(FsdkUid::from_uri("fsuid:base62:8M92EuiIHw"), Ok(fsuid));
+        assert_eq!(FsdkUid::from_uri("URN:FSUID:base62:8M92EuiIHw"), Ok(fsuid), "[FsdkUid::from_uri() Error] URN prefix must be case-insensitive");
+        assert_eq!(FsdkUid::from_uri("fsuid:Base32:34fcye7q4o8o"), Ok(fsuid));
+        assert_eq!(FsdkUid::from_uri(&fsuid.to_uri_with::<Base64Url>()), Ok(fsuid));
+        assert_eq!(FsdkUid::from_uri(&fsuid.to_uri_with::<FixedDecimal>()), Ok(fsuid));
+
+        assert_eq!(FsdkUid::from_uri("8M92EuiIHw"), Err(FsdkUidError::InvalidUri));
+        assert_eq!(FsdkUid::from_uri("fsuid:8M92EuiIHw"), Err(FsdkUidError::InvalidUri));
+        assert_eq!(FsdkUid::from_uri("fsuid:base36:8M92EuiIHw"), Err(FsdkUidError::UnknownEncoding));
+        assert_eq!(FsdkUid::from_uri("fsuid:base62:"), Err(FsdkUidError::Empty));
+    }
+}
@@ -0,0 +1,112 @@
+use crate::{FsdkUid, FsdkUidError};
+
+const FSDK_FSUID_UUID_VERSION: u128 = 7; // UUID version of the UUID's converted from FSUID's (RFC 9562 UUIDv7, time-ordered)
+pub(crate) const FSDK_FSUID_UUID_VARIANT: u128 = 0b10; // UUID variant bits of RFC 9562
+const FSDK_FSUID_UUID_TEXT_LENGTH: usize = 36; // Number of characters of a hyphenated UUID (8-4-4-4-12 hexadecimal digits)
+
+impl FsdkUid {
+    // UUIDv7 (as u128, ex: uuid::Uuid::from_u128()) with the FSUID timestamp delta as unix_ts_ms and the node identifier and node counter at the start of rand_b,
+    // so UUID's sort in the same order as the FSUID's. The other rand_a and rand_b bits are 0, making the conversion reversible
+    pub fn to_uuid(&self) -> u128 {
+        ((self.timestamp_delta() as u128) << 80)
+            | (FSDK_FSUID_UUID_VERSION << 76)
+            | (FSDK_FSUID_UUID_VARIANT << 62)
+            | ((self.node_identifier() as u128) << 54)
+            | ((self.node_counter() as u128) << 46)
+    }
+
+    // Only UUID's converted from FSUID's convert back, other UUID's (other versions, or UUIDv7 with random bits) fail with FsdkUidError::InvalidVersion or FsdkUidError::Overflow
+    pub fn try_from_uuid(uuid: u128) -> Result<Self, FsdkUidError> {
+        if (uuid >> 76) & 0xf != FSDK_FSUID_UUID_VERSION || (uuid >> 62) & 0b11 != FSDK_FSUID_UUID_VARIANT {
+            return Err(FsdkUidError::InvalidVersion);
+        }
+
+        let random_bits = ((uuid >> 64) & 0xfff) | (uuid & ((1 << 46) - 1));
+        if random_bits != 0 {
+            return Err(FsdkUidError::Overflow);
+        }
+
+        Ok(FsdkUid::from_parts((uuid >> 80) as i64, (uuid >> 54) as u8, (uuid >> 46) as u8))
+    }
+
+    // Hyphenated lowercase text of the UUIDv7, ex: 0191ecf3-8f72-7000-8040-000000000000
+    pub fn to_uuid_string(&self) -> String {
+        fsdkuid_format_uuid(self.to_uuid())
+    }
+
+    // Reads a hyphenated UUID in any case, with the same rules as FsdkUid::try_from_uuid()
+    pub fn try_from_uuid_str(text: &str) -> Result<Self, FsdkUidError> {
+        FsdkUid::try_from_uuid(fsdkuid_parse_uuid(text)?)
+    }
+}
+
+pub(crate) fn fsdkuid_format_uuid(uuid: u128) -> String {
+    let hex = format!("{:032x}", uuid);
+    format!("{}-{}-{}-{}-{}", &hex[..8], &hex[8..12], &hex[12..16], &hex[16..20], &hex[20..])
+}
+
+// Reads a hyphenated UUID (8-4-4-4-12 hexadecimal digits) in any case
+pub(crate) fn fsdkuid_parse_uuid(text: &str) -> Result<u128, FsdkUidError> {
+    if text.is_empty() {
+        return Err(FsdkUidError::Empty);
+    }
+
+    if text.len() != FSDK_FSUID_UUID_TEXT_LENGTH {
+        return Err(FsdkUidError::InvalidLength);
+    }
+
+    let mut uuid: u128 = 0;
+
+    for (position, character) in text.chars().enumerate() {
+        if matches!(position, 8 | 13 | 18 | 23) {
+            if character != '-' {
+                return Err(FsdkUidError::InvalidCharacter { character, position });
+            }
+
+            continue;
+        }
+
+        let digit = character.to_digit(16).ok_or(FsdkUidError::InvalidCharacter { character, position })?;
+        uuid = (uuid << 4) | digit as u128;
+    }
+
+    Ok(uuid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fsdkuid_uuid() {
+        let fsuid = FsdkUid::new(113131996488794368);
+
+        assert_eq!(fsuid.to_uuid(), 0x0191ecf3_8f72_7000_8040_000000000000, "[fsuid.to_uuid() Error] UUIDv7 must embed the timestamp delta as unix_ts_ms");
+        assert_eq!(fsuid.to_uuid_string(), "0191ecf3-8f72-7000-8040-000000000000");
+        assert_eq!(FsdkUid::try_from_uuid(fsuid.to_uuid()), Ok(fsuid));
+        assert_eq!(FsdkUid::try_from_uuid_str("0191ECF3-8F72-7000-8040-000000000000"), Ok(fsuid));
+
+        for value in [0, 65535, i64::MAX, i64::MIN, -1] {
+            assert_eq!(FsdkUid::try_from_uuid(FsdkUid::new(value).to_uuid()), Ok(FsdkUid::new(value)), "[FsdkUid::try_from_uuid() Error] FSUID {} didn't round-trip", value);
+        }
+    }
+
+    #[test]
+    fn test_fsdkuid_uuid_preserves_order() {
+        let values = [0, 255, 256, 65535, 65536, 113131996488794368, 113131996488794369, 113131996488794624, i64::MAX];
+
+        for pair in values.windows(2) {
+            assert!(FsdkUid::new(pair[0]).to_uuid() < FsdkUid::new(pair[1]).to_uuid(), "[fsuid.to_uuid() Error] UUID of {} must sort before the UUID of {}", pair[0], pair[1]);
+        }
+    }
+
+    #[test]
+    fn test_fsdkuid_uuid_rejects_foreign_uuids() {
+        assert_eq!(FsdkUid::try_from_uuid(0x8f2c_1e4a_7b3d_4c5e_9a1b_2c3d_4e5f_6a7b), Err(FsdkUidError::InvalidVersion), "[FsdkUid::try_from_uuid() Error] UUIDv4 must be rejected");
+        assert_eq!(FsdkUid::try_from_uuid(0x0191ecf3_8f72_7abc_8040_000000000000), Err(FsdkUidError::Overflow), "[FsdkUid::try_from_uuid() Error] UUIDv7 with random bits must be rejected");
+        assert_eq!(FsdkUid::try_from_uuid(0x0191ecf3_8f72_7000_c040_000000000000), Err(FsdkUidError::InvalidVersion));
+        assert_eq!(FsdkUid::try_from_uuid_str("0191ecf38f72700080400000000000000000"), Err(FsdkUidError::InvalidCharacter { character: '8', position: 8 }));
+        assert_eq!(FsdkUid::try_from_uuid_str("0191ecf3-8f72-7000-8040-00000000000"), Err(FsdkUidError::InvalidLength));
+        assert_eq!(FsdkUid::try_from_uuid_str(""), Err(FsdkUidError::Empty));
+    }
+}
@@ -0,0 +1,115 @@
+use crate::{FsdkUid, FsdkUidError};
+
+pub const FSDK_FSUID_VARINT_MAX_LENGTH: usize = 10; // Max number of bytes of a FSUID in LEB128 format (7 bits per byte, 64 bits)
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FsdkUidVarintMode {
+    Unsigned, // LEB128 of the 64 bits of the FSUID read as unsigned
+    ZigZag, // LEB128 of the zig-zag mapping of the FSUID (0, -1, 1, -2, ...), keeping FSUID's close to zero short whatever their sign
+}
+
+impl FsdkUid {
+    // Writes the FSUID in LEB128 format at the start of the buffer and returns the written bytes
+    pub fn encode_varint<'a>(&self, mode: FsdkUidVarintMode, buffer: &'a mut [u8; FSDK_FSUID_VARINT_MAX_LENGTH]) -> &'a [u8] {
+        let mut remaining = match mode {
+            FsdkUidVarintMode::Unsigned => self.i64() as u64,
+            FsdkUidVarintMode::ZigZag => ((self.i64() << 1) ^ (self.i64() >> 63)) as u64,
+        };
+
+        let mut length = 0;
+        for slot in buffer.iter_mut() {
+            length += 1;
+
+            if remaining < 0x80 {
+                *slot = remaining as u8;
+                break;
+            }
+
+            *slot = (remaining as u8 & 0x7f) | 0x80;
+            remaining >>= 7;
+        }
+
+        &buffer[..length]
+    }
+
+    // Reads a FSUID in LEB128 format from the start of the bytes, returning it with the number of bytes read so the following fields can be framed
+    pub fn decode_varint(bytes: &[u8], mode: FsdkUidVarintMode) -> Result<(FsdkUid, usize), FsdkUidError> {
+        if bytes.is_empty() {
+            return Err(FsdkUidError::Empty);
+        }
+
+        let mut value: u64 = 0;
+
+        for (index, &byte) in bytes.iter().take(FSDK_FSUID_VARINT_MAX_LENGTH).enumerate() {
+            let shift = 7 * index as u32;
+
+            if index == FSDK_FSUID_VARINT_MAX_LENGTH - 1 && byte > 0x01 {
+                return Err(FsdkUidError::Overflow);
+            }
+
+            value |= ((byte & 0x7f) as u64) << shift;
+
+            if byte & 0x80 == 0 {
+                if byte == 0 && index > 0 {
+                    return Err(FsdkUidError::NonCanonical);
+                }
+
+                let fsuid = match mode {
+                    FsdkUidVarintMode::Unsigned => value as i64,
+                    FsdkUidVarintMode::ZigZag => (value >> 1) as i64 ^ -((value & 1) as i64),
+                };
+
+                return Ok((FsdkUid::new(fsuid), index + 1));
+            }
+        }
+
+        Err(if bytes.len() < FSDK_FSUID_VARINT_MAX_LENGTH { FsdkUidError::InvalidLength } else { FsdkUidError::Overflow })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ring::fsdkuid_mix64;
+
+    #[test]
+    fn test_fsdkuid_varint_vectors() {
+        let mut buffer = [0u8; FSDK_FSUID_VARINT_MAX_LENGTH];
+
+        assert_eq!(FsdkUid::new(0).encode_varint(FsdkUidVarintMode::Unsigned, &mut buffer), [0x00]);
+        assert_eq!(FsdkUid::new(300).encode_varint(FsdkUidVarintMode::Unsigned, &mut buffer), [0xac, 0x02]);
+        assert_eq!(FsdkUid::new(-1).encode_varint(FsdkUidVarintMode::Unsigned, &mut buffer).len(), FSDK_FSUID_VARINT_MAX_LENGTH);
+        assert_eq!(FsdkUid::new(-1).encode_varint(FsdkUidVarintMode::ZigZag, &mut buffer), [0x01]);
+        assert_eq!(FsdkUid::new(1).encode_varint(FsdkUidVarintMode::ZigZag, &mut buffer), [0x02]);
+        assert_eq!(FsdkUid::new(113131996488794368).encode_varint(FsdkUidVarintMode::Unsigned, &mut buffer).len(), 9);
+
+        assert_eq!(FsdkUid::decode_varint(&[0xac, 0x02, 0xff], FsdkUidVarintMode::Unsigned), Ok((FsdkUid::new(300), 2)), "[FsdkUid::decode_varint() Error] Bytes after the varint must not be read");
+        assert_eq!(FsdkUid::decode_varint(&[], FsdkUidVarintMode::Unsigned), Err(FsdkUidError::Empty));
+        assert_eq!(FsdkUid::decode_varint(&[0xac], FsdkUidVarintMode::Unsigned), Err(FsdkUidError::InvalidLength));
+        assert_eq!(FsdkUid::decode_varint(&[0x80, 0x00], FsdkUidVarintMode::Unsigned), Err(FsdkUidError::NonCanonical));
+        assert_eq!(FsdkUid::decode_varint(&[0xff; 9].iter().copied().chain([0x02]).collect::<Vec<u8>>(), FsdkUidVarintMode::Unsigned), Err(FsdkUidError::Overflow));
+        assert_eq!(FsdkUid::decode_varint(&[0xff; 11], FsdkUidVarintMode::Unsigned), Err(FsdkUidError::Overflow));
+    }
+
+    #[test]
+    fn test_fsdkuid_varint_fuzz() {
+        let mut buffer = [0u8; FSDK_FSUID_VARINT_MAX_LENGTH];
+
+        for seed in 0..20000u64 {
+            let random = fsdkuid_mix64(seed);
+            let fsuid = FsdkUid::new((random >> (seed % 64)) as i64);
+
+            for mode in [FsdkUidVarintMode::Unsigned, FsdkUidVarintMode::ZigZag] {
+                let encoded = fsuid.encode_varint(mode, &mut buffer).to_vec();
+                assert_eq!(FsdkUid::decode_varint(&encoded, mode), Ok((fsuid, encoded.len())), "[FsdkUid::decode_varint() Error] FSUID {} didn't round-trip in {:?} mode", fsuid.i64(), mode);
+            }
+
+            // Arbitrary bytes must either decode to a FSUID re-encoding to the same bytes or be rejected, never panic
+            let bytes = [random.to_le_bytes(), fsdkuid_mix64(random).to_le_bytes()].concat();
+            let length = (seed % 17) as usize;
+            if let Ok((decoded, read)) = FsdkUid::decode_varint(&bytes[..length], FsdkUidVarintMode::Unsigned) {
+                assert_eq!(decoded.encode_varint(FsdkUidVarintMode::Unsigned, &mut buffer), &bytes[..read]);
+            }
+        }
+    }
+}
@@ -0,0 +1,104 @@
+// Reference FSUID's covering the boundaries of each field, public so other implementations and applications can validate against them
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FsdkUidTestVector {
+    pub description: &'static str,
+    pub fsuid: i64,
+    pub timestamp_delta: i64,
+    pub node_identifier: u8,
+    pub node_counter: u8,
+    pub utc_datetime: &'static str, // RFC 3339 with milliseconds precision
+    pub base62: &'static str,
+}
+
+pub const FSDK_FSUID_TEST_VECTORS: &[FsdkUidTestVector] = &[
+    FsdkUidTestVector {
+        description: "Unix Timestamp (epoch start) with minimum node identifier and counter",
+        fsuid: 0,
+        timestamp_delta: 0,
+        node_identifier: 0,
+        node_counter: 0,
+        utc_datetime: "1970-01-01T00:00:00.000Z",
+        base62: "0",
+    },
+    FsdkUidTestVector {
+        description: "Unix Timestamp (epoch start) with maximum node identifier and counter",
+        fsuid: 65535,
+        timestamp_delta: 0,
+        node_identifier: 255,
+        node_counter: 255,
+        utc_datetime: "1970-01-01T00:00:00.000Z",
+        base62: "H31",
+    },
+    FsdkUidTestVector {
+        description: "Mid-range FSUID generated in production",
+        fsuid: 113131996488794368,
+        timestamp_delta: 1726257270642,
+        node_identifier: 1,
+        node_counter: 0,
+        utc_datetime: "2024-09-13T19:54:30.642Z",
+        base62: "8M92EuiIHw",
+    },
+    FsdkUidTestVector {
+        description: "Largest FSUID keeping the sign bit clear (maximum positive i64)",
+        fsuid: i64::MAX,
+        timestamp_delta: 140737488355327,
+        node_identifier: 255,
+        node_counter: 255,
+        utc_datetime: "6429-10-17T02:45:55.327Z",
+        base62: "AzL8n0Y58m7",
+    },
+    FsdkUidTestVector {
+        description: "Smallest timestamp delta setting the sign bit (minimum i64)",
+        fsuid: i64::MIN,
+        timestamp_delta: 140737488355328,
+        node_identifier: 0,
+        node_counter: 0,
+        utc_datetime: "6429-10-17T02:45:55.328Z",
+        base62: "AzL8n0Y58m8",
+    },
+    FsdkUidTestVector {
+        description: "Maximum representable timestamp delta with maximum node identifier and counter",
+        fsuid: -1,
+        timestamp_delta: 281474976710655,
+        node_identifier: 255,
+        node_counter: 255,
+        utc_datetime: "+10889-08-02T05:31:50.655Z",
+        base62: "LygHa16AHYF",
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FsdkUid;
+    use chrono::SecondsFormat;
+
+    #[test]
+    fn test_fsdkuid_test_vectors() {
+        for vector in FSDK_FSUID_TEST_VECTORS {
+            let fsuid = FsdkUid::new(vector.fsuid);
+            assert_eq!(fsuid.timestamp_delta(), vector.timestamp_delta, "[fsuid.timestamp_delta() Error] {}", vector.description);
+            assert_eq!(fsuid.node_identifier(), vector.node_identifier, "[fsuid.node_identifier() Error] {}", vector.description);
+            assert_eq!(fsuid.node_counter(), vector.node_counter, "[fsuid.node_counter() Error] {}", vector.description);
+            assert_eq!(fsuid.utc_datetime().to_rfc3339_opts(SecondsFormat::Millis, true), vector.utc_datetime, "[fsuid.utc_datetime() Error] {}", vector.description);
+            assert_eq!(fsuid.to_base62(), vector.base62, "[fsuid.to_base62() Error] {}", vector.description);
+            assert_eq!(FsdkUid::from_base62(vector.base62), Ok(fsuid), "[FsdkUid::from_base62() Error] {}", vector.description);
+
+            let composed = FsdkUid::from_parts(vector.timestamp_delta, vector.node_identifier, vector.node_counter);
+            assert_eq!(composed, fsuid, "[FsdkUid::from_parts() Error] {}", vector.description);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_fsdkuid_from_parts_rejects_timestamp_delta_overflow() {
+        FsdkUid::from_parts(281474976710656, 0, 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_fsdkuid_from_parts_rejects_negative_timestamp_delta() {
+        FsdkUid::from_parts(-1, 0, 0);
+    }
+}
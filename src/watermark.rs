@@ -0,0 +1,165 @@
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use crate::{FsdkUid, FsdkUidError, FsdkUidGenerator, FsdkUidLayout};
+
+// Durable storage of the timestamp watermark, in unix milliseconds
+pub trait FsdkUidWatermarkStore: Send + Sync {
+    fn load(&self) -> io::Result<Option<u64>>;
+    fn store(&self, watermark_milliseconds: u64) -> io::Result<()>;
+}
+
+// Watermark kept as decimal text in a file, replaced atomically through a synced temporary file so a crash never leaves a truncated watermark
+pub struct FsdkUidFileWatermarkStore {
+    path: PathBuf,
+}
+
+impl FsdkUidFileWatermarkStore {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        FsdkUidFileWatermarkStore { path: path.into() }
+    }
+}
+
+impl FsdkUidWatermarkStore for FsdkUidFileWatermarkStore {
+    fn load(&self) -> io::Result<Option<u64>> {
+        match fs::read_to_string(&self.path) {
+            Ok(text) => text.trim().parse().map(Some).map_err(|_| io::Error::new(io::ErrorKind::InvalidData, format!("Invalid FSUID timestamp watermark in {}", self.path.display()))),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(error) => Err(error),
+        }
+    }
+
+    fn store(&self, watermark_milliseconds: u64) -> io::Result<()> {
+        let mut temporary = self.path.clone().into_os_string();
+        temporary.push(".tmp");
+
+        let file = fs::File::create(&temporary)?;
+        io::Write::write_all(&mut &file, watermark_milliseconds.to_string().as_bytes())?;
+        file.sync_all()?;
+        fs::rename(&temporary, &self.path)
+    }
+}
+
+// What opening the generator does when the clock is behind the persisted watermark (ex: after a reboot with a skewed RTC)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum FsdkUidWatermarkPolicy {
+    #[default]
+    Wait, // Sleeps until the clock passes the watermark
+    Refuse, // Fails with FsdkUidError::ClockRollback
+}
+
+// Generator persisting a timestamp watermark ahead of every FSUID it returns, so after a restart it never generates FSUID's of timestamp ticks
+// that may have been used before, even if the clock went back in between. The watermark is moved an interval ahead once per interval
+pub struct FsdkUidWatermarkedGenerator<S: FsdkUidWatermarkStore> {
+    generator: FsdkUidGenerator,
+    store: S,
+    interval_milliseconds: u64,
+    reserved: AtomicU64, // Persisted watermark, every returned FSUID's timestamp tick starts before it
+    reserving: Mutex<()>,
+}
+
+impl<S: FsdkUidWatermarkStore> FsdkUidWatermarkedGenerator<S> {
+    // A longer interval writes the watermark less often but makes restarts wait longer, up to the interval
+    pub fn open(generator: FsdkUidGenerator, store: S, interval: Duration, policy: FsdkUidWatermarkPolicy) -> io::Result<Self> {
+        if interval.is_zero() {
+            panic!("[ERROR in FsdkUidWatermarkedGenerator.open()] Watermark interval should be greater than 0!");
+        }
+
+        let layout = generator.layout();
+        let watermark = store.load()?.unwrap_or(0);
+
+        loop {
+            let current = fsdkuid_tick_start_milliseconds(layout, layout.current_timestamp_ticks());
+            if current >= watermark {
+                break;
+            }
+
+            match policy {
+                FsdkUidWatermarkPolicy::Wait => std::thread::sleep(Duration::from_millis(watermark - current)),
+                FsdkUidWatermarkPolicy::Refuse => return Err(io::Error::other(FsdkUidError::ClockRollback)),
+            }
+        }
+
+        Ok(FsdkUidWatermarkedGenerator {
+            generator,
+            store,
+            interval_milliseconds: interval.as_millis().max(1) as u64,
+            reserved: AtomicU64::new(watermark),
+            reserving: Mutex::new(()),
+        })
+    }
+
+    pub fn layout(&self) -> FsdkUidLayout {
+        self.generator.layout()
+    }
+
+    // A FSUID past the watermark is only returned once the next watermark is persisted, so a failing store fails the generation
+    pub fn generate_i64(&self) -> io::Result<i64> {
+        let fsuid = self.generator.generate_i64();
+        let layout = self.generator.layout();
+        let tick_start = fsdkuid_tick_start_milliseconds(layout, layout.timestamp_ticks(&FsdkUid::new(fsuid)));
+
+        if tick_start >= self.reserved.load(Ordering::Acquire) {
+            let _reserving = self.reserving.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+
+            if tick_start >= self.reserved.load(Ordering::Acquire) {
+                let watermark = tick_start + self.interval_milliseconds.max(layout.resolution_milliseconds());
+                self.store.store(watermark)?;
+                self.reserved.store(watermark, Ordering::Release);
+            }
+        }
+
+        Ok(fsuid)
+    }
+
+    pub fn generate_fsuid(&self) -> io::Result<FsdkUid> {
+        self.generate_i64().map(FsdkUid::new)
+    }
+}
+
+fn fsdkuid_tick_start_milliseconds(layout: FsdkUidLayout, timestamp_ticks: u64) -> u64 {
+    layout.epoch_milliseconds() + timestamp_ticks * layout.resolution_milliseconds()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fsdkuid_get_current_unix_timestamp_milliseconds;
+
+    fn watermark_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("fsdk_fsuid_watermark_{}_{}", name, std::process::id()))
+    }
+
+    #[test]
+    fn test_fsdkuid_watermarked_generator_persists_watermark() {
+        let path = watermark_path("persist");
+        let generator = FsdkUidWatermarkedGenerator::open(FsdkUidGenerator::new(9), FsdkUidFileWatermarkStore::new(&path), Duration::from_secs(1), FsdkUidWatermarkPolicy::Refuse).unwrap();
+
+        let fsuid = generator.generate_fsuid().unwrap();
+        let watermark = FsdkUidFileWatermarkStore::new(&path).load().unwrap().unwrap();
+        assert!(watermark > FsdkUidLayout::FSUID.utc_datetime(&fsuid).timestamp_millis() as u64, "[FsdkUidWatermarkedGenerator.generate_fsuid() Error] Watermark must be persisted ahead of the generated FSUID's");
+        drop(generator);
+
+        FsdkUidFileWatermarkStore::new(&path).store(fsdkuid_get_current_unix_timestamp_milliseconds() + 60_000).unwrap();
+        let refused = FsdkUidWatermarkedGenerator::open(FsdkUidGenerator::new(9), FsdkUidFileWatermarkStore::new(&path), Duration::from_secs(1), FsdkUidWatermarkPolicy::Refuse);
+        assert_eq!(refused.err().and_then(|error| error.into_inner()).and_then(|error| error.downcast::<FsdkUidError>().ok()).map(|error| *error), Some(FsdkUidError::ClockRollback), "[FsdkUidWatermarkedGenerator.open() Error] Clock behind the watermark must be refused");
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_fsdkuid_watermarked_generator_waits_for_watermark() {
+        let path = watermark_path("wait");
+        let watermark = fsdkuid_get_current_unix_timestamp_milliseconds() + 100;
+        FsdkUidFileWatermarkStore::new(&path).store(watermark).unwrap();
+
+        let generator = FsdkUidWatermarkedGenerator::open(FsdkUidGenerator::new(9), FsdkUidFileWatermarkStore::new(&path), Duration::from_secs(1), FsdkUidWatermarkPolicy::Wait).unwrap();
+        assert!(FsdkUidLayout::FSUID.utc_datetime(&generator.generate_fsuid().unwrap()).timestamp_millis() as u64 >= watermark, "[FsdkUidWatermarkedGenerator.open() Error] Generator must wait until the clock passes the watermark");
+
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,93 @@
+// Counts the heap allocations of the current thread, so the hot paths latency-sensitive users depend on can be asserted allocation-free.
+// Lives in its own test binary since the global allocator applies to every test of the binary
+
+#![cfg(feature = "std")]
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+use fsdk_uid::{fsdkuid_format_base62, fsdkuid_format_decimal, fsdkuid_parse_base62, fsdkuid_parse_decimal, Base62, Base64Url, CrockfordBase32, Encoding, FixedDecimal, FsdkUid, FsdkUidGenerator, FsdkUidVarintMode, FSDK_FSUID_BASE62_MAX_LENGTH, FSDK_FSUID_DECIMAL_MAX_LENGTH, FSDK_FSUID_VARINT_MAX_LENGTH, Sortable};
+
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|allocations| allocations.set(allocations.get() + 1));
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.with(|allocations| allocations.set(allocations.get() + 1));
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn allocations<T>(operation: impl FnOnce() -> T) -> usize {
+    let before = ALLOCATIONS.with(Cell::get);
+    std::hint::black_box(operation());
+    ALLOCATIONS.with(Cell::get) - before
+}
+
+#[test]
+fn test_fsdkuid_allocation_counter_counts() {
+    assert_eq!(allocations(|| FsdkUid::new(113131996488794368).to_base62()), 1, "[CountingAllocator Error] Allocations of the current thread must be counted");
+}
+
+#[test]
+fn test_fsdkuid_generation_does_not_allocate() {
+    let fsuid_generator = FsdkUidGenerator::new(1);
+
+    // 300 generations cross a node counter overflow, covering the wait for the next millisecond
+    for _ in 0..300 {
+        assert_eq!(allocations(|| fsuid_generator.generate_i64()), 0, "[FsdkUidGenerator.generate_i64() Error] Generating a FSUID must not allocate");
+        assert_eq!(allocations(|| fsuid_generator.generate_fsuid()), 0, "[FsdkUidGenerator.generate_fsuid() Error] Generating a FSUID must not allocate");
+    }
+}
+
+#[test]
+fn test_fsdkuid_fields_do_not_allocate() {
+    let fsuid = FsdkUid::new(113131996488794368);
+
+    assert_eq!(allocations(|| FsdkUid::from_parts(1726257270642, 1, 0)), 0);
+    assert_eq!(allocations(|| (fsuid.timestamp_delta(), fsuid.node_identifier(), fsuid.node_counter())), 0, "[FsdkUid fields Error] Reading the FSUID fields must not allocate");
+    assert_eq!(allocations(|| fsuid.utc_datetime()), 0, "[fsuid.utc_datetime() Error] Converting the FSUID to a datetime must not allocate");
+}
+
+#[test]
+fn test_fsdkuid_raw_encodings_do_not_allocate() {
+    let fsuid = FsdkUid::new(113131996488794368);
+
+    let mut decimal = [0u8; FSDK_FSUID_DECIMAL_MAX_LENGTH];
+    assert_eq!(allocations(|| fsdkuid_format_decimal(fsuid.i64(), &mut decimal).len()), 0, "[fsdkuid_format_decimal() Error] Formatting must not allocate");
+    assert_eq!(allocations(|| fsdkuid_parse_decimal(b"113131996488794368")), 0, "[fsdkuid_parse_decimal() Error] Parsing must not allocate");
+
+    let mut base62 = [0u8; FSDK_FSUID_BASE62_MAX_LENGTH];
+    assert_eq!(allocations(|| fsdkuid_format_base62(fsuid.i64(), &mut base62).len()), 0, "[fsdkuid_format_base62() Error] Formatting must not allocate");
+    assert_eq!(allocations(|| fsdkuid_parse_base62(b"8M92EuiIHw")), 0, "[fsdkuid_parse_base62() Error] Parsing must not allocate");
+
+    assert_eq!(allocations(|| FsdkUid::from_be_bytes(fsuid.to_be_bytes())), 0, "[fsuid.to_be_bytes() Error] Byte conversions must not allocate");
+    assert_eq!(allocations(|| FsdkUid::from_le_bytes(fsuid.to_le_bytes())), 0);
+    assert_eq!(allocations(|| FsdkUid::from_key_bytes(fsuid.to_key_bytes())), 0);
+
+    let mut text = [0u8; FixedDecimal::MAX_LENGTH];
+    assert_eq!(allocations(|| fsuid.encode_into::<Base62>(&mut text).map(str::len)), 0, "[fsuid.encode_into() Error] Encoding into a buffer must not allocate");
+    assert_eq!(allocations(|| fsuid.encode_into::<CrockfordBase32>(&mut text).map(str::len)), 0);
+    assert_eq!(allocations(|| fsuid.encode_into::<Base64Url>(&mut text).map(str::len)), 0);
+    assert_eq!(allocations(|| fsuid.encode_into::<Sortable>(&mut text).map(str::len)), 0);
+    assert_eq!(allocations(|| fsuid.encode_into::<FixedDecimal>(&mut text).map(str::len)), 0);
+
+    let mut varint = [0u8; FSDK_FSUID_VARINT_MAX_LENGTH];
+    assert_eq!(allocations(|| fsuid.encode_varint(FsdkUidVarintMode::Unsigned, &mut varint).len()), 0, "[fsuid.encode_varint() Error] Encoding must not allocate");
+    assert_eq!(allocations(|| FsdkUid::decode_varint(&[0xac, 0x02], FsdkUidVarintMode::Unsigned)), 0, "[FsdkUid::decode_varint() Error] Decoding must not allocate");
+}
@@ -0,0 +1,58 @@
+// No_std test harness of the embedded routines, built without libtest (harness = false): on bare metal targets (ex: thumbv7em-none-eabihf)
+// it links without the standard library with its own entry point and panic handler, on hosted targets it runs as a plain test binary
+
+#![no_std]
+#![cfg_attr(target_os = "none", no_main)]
+
+#[cfg(not(target_os = "none"))]
+extern crate std;
+
+use fsdk_uid::{fsdkuid_format_base62, fsdkuid_format_decimal, fsdkuid_parse_base62, fsdkuid_parse_decimal, FsdkUidError, FSDK_FSUID_BASE62_MAX_LENGTH, FSDK_FSUID_DECIMAL_MAX_LENGTH};
+
+fn fsdkuid_embedded_checks() {
+    let mut decimal = [0u8; FSDK_FSUID_DECIMAL_MAX_LENGTH];
+    let mut base62 = [0u8; FSDK_FSUID_BASE62_MAX_LENGTH];
+
+    let cases: [(i64, &[u8], &[u8]); 5] = [
+        (0, b"0", b"0"),
+        (-65277, b"-65277", b"LygHa16A0ZP"),
+        (113131996488794368, b"113131996488794368", b"8M92EuiIHw"),
+        (i64::MAX, b"9223372036854775807", b"AzL8n0Y58m7"),
+        (i64::MIN, b"-9223372036854775808", b"AzL8n0Y58m8"),
+    ];
+
+    for (value, decimal_text, base62_text) in cases {
+        assert_eq!(fsdkuid_format_decimal(value, &mut decimal).as_bytes(), decimal_text);
+        assert_eq!(fsdkuid_parse_decimal(decimal_text), Ok(value));
+        assert_eq!(fsdkuid_format_base62(value, &mut base62).as_bytes(), base62_text);
+        assert_eq!(fsdkuid_parse_base62(base62_text), Ok(value));
+    }
+
+    assert_eq!(fsdkuid_parse_decimal(b"007"), Err(FsdkUidError::NonCanonical));
+    assert_eq!(fsdkuid_parse_decimal("12é".as_bytes()), Err(FsdkUidError::InvalidCharacter { character: 'é', position: 2 }));
+    assert_eq!(fsdkuid_parse_base62(b"LygHa16AHYG"), Err(FsdkUidError::Overflow));
+    assert_eq!(fsdkuid_parse_base62(b"8M92\xffuiIHw"), Err(FsdkUidError::InvalidCharacter { character: char::REPLACEMENT_CHARACTER, position: 4 }));
+}
+
+#[cfg(not(target_os = "none"))]
+fn main() {
+    fsdkuid_embedded_checks();
+}
+
+#[cfg(target_os = "none")]
+#[no_mangle]
+pub extern "C" fn _start() -> ! {
+    fsdkuid_embedded_checks();
+
+    loop {
+        core::hint::spin_loop();
+    }
+}
+
+#[cfg(target_os = "none")]
+#[panic_handler]
+fn fsdkuid_panic(_: &core::panic::PanicInfo) -> ! {
+    loop {
+        core::hint::spin_loop();
+    }
+}